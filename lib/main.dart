@@ -28,6 +28,7 @@ import 'dart:ui' as ui;
 
 import 'package:flutter/material.dart';
 import 'package:flutter/widgets.dart';
+import 'package:flutter_vello/src/rust/api/error.dart';
 import 'package:flutter_vello/src/rust/api/simple.dart';
 import 'package:flutter_vello/src/rust/frb_generated.dart';
 
@@ -46,8 +47,6 @@ class MyApp extends StatefulWidget {
 class _MyAppState extends State<MyApp> {
   ui.Image? image;
 
-  bool isRendering = false;
-
   @override
   Widget build(BuildContext context) {
     return MaterialApp(
@@ -60,42 +59,56 @@ class _MyAppState extends State<MyApp> {
             children: [
               Text(
                   'Action: Call Rust `greet("Tom")`\nResult: `${greet(name: "Tom")}`'),
-              Listener(
-                onPointerHover: (event) async {
-                  if (isRendering) {
-                    return;
-                  }
-                  isRendering = true;
-                  final bytes = await testRender(
-                    x: event.localPosition.dx,
-                    y: event.localPosition.dy,
-                  );
-                  ui.decodeImageFromPixels(
-                    bytes,
-                    800,
-                    600,
-                    ui.PixelFormat.rgba8888,
-                    (result) {
-                      isRendering = false;
-                      setState(() {
-                        image = result;
-                      });
+              LayoutBuilder(
+                builder: (context, constraints) {
+                  final width = constraints.maxWidth.floor();
+                  const height = 600;
+                  return Listener(
+                    onPointerHover: (event) async {
+                      final Uint8List? bytes;
+                      try {
+                        bytes = await testRender(
+                          x: event.localPosition.dx,
+                          y: event.localPosition.dy,
+                          width: width,
+                          height: height,
+                        );
+                      } on RendererError catch (e) {
+                        debugPrint('Render failed: $e');
+                        return;
+                      }
+                      // The renderer skips frames that newer hover events
+                      // made stale.
+                      if (bytes == null) {
+                        return;
+                      }
+                      ui.decodeImageFromPixels(
+                        bytes,
+                        width,
+                        height,
+                        ui.PixelFormat.rgba8888,
+                        (result) {
+                          setState(() {
+                            image = result;
+                          });
+                        },
+                      );
                     },
+                    child: Container(
+                      width: width.toDouble(),
+                      height: height.toDouble(),
+                      decoration: BoxDecoration(
+                        border: Border.all(
+                          color: Colors.black,
+                          width: 1,
+                        ),
+                      ),
+                      child: image == null
+                          ? const SizedBox.shrink()
+                          : RawImage(image: image!),
+                    ),
                   );
                 },
-                child: Container(
-                  width: 800,
-                  height: 600,
-                  decoration: BoxDecoration(
-                    border: Border.all(
-                      color: Colors.black,
-                      width: 1,
-                    ),
-                  ),
-                  child: image == null
-                      ? const SizedBox.shrink()
-                      : RawImage(image: image!),
-                ),
               ),
             ],
           ),
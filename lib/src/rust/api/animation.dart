@@ -0,0 +1,188 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'scene.dart';
+import 'simple.dart';
+part 'animation.freezed.dart';
+
+        
+
+        /// Sets the timeline animating the scene graph of the renderer registered
+/// under `id`, paused at its start. Replaces the previous timeline.
+///
+/// Fails with `RendererError::InvalidTimeline` if a track has no keyframes,
+/// keyframes out of order, values that don't fit its property or invalid
+/// easing parameters.
+Future<void> setTimeline({required String id , required Timeline timeline , dynamic hint}) => RustLib.instance.api.setTimeline(id: id, timeline: timeline, hint: hint);
+
+/// Removes the timeline, which returns every node to its own values.
+Future<void> clearTimeline({required String id , dynamic hint}) => RustLib.instance.api.clearTimeline(id: id, hint: hint);
+
+/// Plays the timeline from its current position, starting with the next
+/// frame.
+Future<void> playTimeline({required String id , dynamic hint}) => RustLib.instance.api.playTimeline(id: id, hint: hint);
+
+/// Stops the timeline at the position of the last frame drawn.
+Future<void> pauseTimeline({required String id , dynamic hint}) => RustLib.instance.api.pauseTimeline(id: id, hint: hint);
+
+/// Moves the timeline to `position` seconds from its start. A playing
+/// timeline continues from there with the next frame.
+Future<void> seekTimeline({required String id , required double position , dynamic hint}) => RustLib.instance.api.seekTimeline(id: id, position: position, hint: hint);
+
+/// Like `render_graph`, but evaluates the timeline at `timestamp_micros`
+/// instead of the renderer's own clock, for example at the vsync time of a
+/// Flutter `Ticker`. Timestamps only need to be consistent with each other.
+/// Mixing frames of both clocks is fine, playback continues from the last
+/// frame whenever the clock changes.
+Future<Frame?> renderGraphAt({required String id , required int timestampMicros , required int width , required int height , required RenderOptions options , dynamic hint}) => RustLib.instance.api.renderGraphAt(id: id, timestampMicros: timestampMicros, width: width, height: height, options: options, hint: hint);
+
+        @freezed
+                sealed class AnimatedProperty with _$AnimatedProperty  {
+                    const AnimatedProperty._();
+
+                     /// The node's transform, interpolated as translation, rotation, scale and
+/// skew. Takes `KeyframeValue::Transform`s.
+const factory AnimatedProperty.transform() = AnimatedProperty_Transform;
+ /// Multiplies the alpha of everything the node's own commands draw, not
+/// that of its children. Takes `KeyframeValue::Number`s from 0 to 1.
+///
+/// The commands are drawn in a layer with that alpha, so they can nest
+/// one layer less.
+const factory AnimatedProperty.opacity() = AnimatedProperty_Opacity;
+ /// Paints the fill, stroke or glyph run at index `command` of the node's
+/// commands in a solid color. Takes `KeyframeValue::Color`s.
+const factory AnimatedProperty.color({   required int command , }) = AnimatedProperty_Color;
+ /// The width of the stroke at index `command` of the node's commands.
+/// Takes `KeyframeValue::Number`s.
+const factory AnimatedProperty.strokeWidth({   required int command , }) = AnimatedProperty_StrokeWidth;
+ /// Morphs the shape of the fill or stroke at index `command` of the
+/// node's commands. Takes `KeyframeValue::Path`s, which must all have the
+/// same kinds of elements in the same order.
+const factory AnimatedProperty.path({   required int command , }) = AnimatedProperty_Path;
+
+                    
+                    
+                }
+
+@freezed
+                sealed class Easing with _$Easing  {
+                    const Easing._();
+
+                     /// Keeps the previous value until the keyframe's time.
+const factory Easing.hold() = Easing_Hold;
+ const factory Easing.linear() = Easing_Linear;
+ /// Like CSS's `cubic-bezier(x1, y1, x2, y2)`. `x1` and `x2` must be
+/// between 0 and 1.
+const factory Easing.cubicBezier({   required double x1 ,  required double y1 ,  required double x2 ,  required double y2 , }) = Easing_CubicBezier;
+ /// Follows a spring that is released at the previous keyframe's value,
+/// and is cut off at this keyframe's time. Springs damped less than
+/// `2 * sqrt(stiffness * mass)` overshoot.
+const factory Easing.spring({   required double stiffness ,  required double damping ,  required double mass , }) = Easing_Spring;
+
+                    
+                    
+                }
+
+class Keyframe  {
+                /// Seconds from the start of the timeline.
+final double time;
+final KeyframeValue value;
+/// How the track gets from the previous keyframe to this one. Unused on
+/// the first keyframe.
+final Easing easing;
+
+                const Keyframe({required this.time ,required this.value ,required this.easing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => time.hashCode^value.hashCode^easing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Keyframe &&
+                runtimeType == other.runtimeType
+                && time == other.time&& value == other.value&& easing == other.easing;
+        
+            }
+
+@freezed
+                sealed class KeyframeValue with _$KeyframeValue  {
+                    const KeyframeValue._();
+
+                     const factory KeyframeValue.transform(  Transform field0,) = KeyframeValue_Transform;
+ const factory KeyframeValue.number(  double field0,) = KeyframeValue_Number;
+ const factory KeyframeValue.color(  Color field0,) = KeyframeValue_Color;
+ const factory KeyframeValue.path(  List<PathElement> field0,) = KeyframeValue_Path;
+
+                    
+                    
+                }
+
+class Timeline  {
+                final List<Track> tracks;
+/// Starts over after the last keyframe of any track, instead of staying
+/// there.
+final bool looping;
+
+                const Timeline({required this.tracks ,required this.looping ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tracks.hashCode^looping.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Timeline &&
+                runtimeType == other.runtimeType
+                && tracks == other.tracks&& looping == other.looping;
+        
+            }
+
+/// The values of one property of one node over time. Before its first
+/// keyframe and after its last one, the track keeps their values.
+class Track  {
+                /// Tracks of nodes that aren't in the scene graph do nothing.
+final int node;
+final AnimatedProperty property;
+/// In increasing order of time.
+final List<Keyframe> keyframes;
+
+                const Track({required this.node ,required this.property ,required this.keyframes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => node.hashCode^property.hashCode^keyframes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Track &&
+                runtimeType == other.runtimeType
+                && node == other.node&& property == other.property&& keyframes == other.keyframes;
+        
+            }
+        
\ No newline at end of file
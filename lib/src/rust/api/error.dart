@@ -0,0 +1,79 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'simple.dart';
+part 'error.freezed.dart';
+
+        
+
+        
+
+        @freezed
+                sealed class RendererError with _$RendererError implements FrbException {
+                    const RendererError._();
+
+                     /// No wgpu adapter is available for the requested backend.
+const factory RendererError.adapterNotFound() = RendererError_AdapterNotFound;
+ /// The adapter was found but refused to create a device.
+const factory RendererError.deviceCreation(  String field0,) = RendererError_DeviceCreation;
+ /// vello couldn't build its render pipelines.
+const factory RendererError.rendererCreation(  String field0,) = RendererError_RendererCreation;
+ /// vello or wgpu failed while rendering a frame.
+const factory RendererError.render(  String field0,) = RendererError_Render;
+ /// The frame asked for an antialiasing method that the renderer wasn't
+/// created with.
+const factory RendererError.unsupportedAntialiasing(  Antialiasing field0,) = RendererError_UnsupportedAntialiasing;
+ /// The frame uses something the renderer's backend can't draw.
+const factory RendererError.unsupported(  String field0,) = RendererError_Unsupported;
+ /// The rendered frame couldn't be copied back from the GPU.
+const factory RendererError.readback(  String field0,) = RendererError_Readback;
+ /// The frame couldn't be encoded into an image file.
+const factory RendererError.encoding(  String field0,) = RendererError_Encoding;
+ /// No renderer is registered under the given id.
+const factory RendererError.rendererNotFound(  String field0,) = RendererError_RendererNotFound;
+ /// The scene graph has no node with the given id.
+const factory RendererError.nodeNotFound(  int field0,) = RendererError_NodeNotFound;
+ /// A node with the given id is already in the scene graph.
+const factory RendererError.nodeExists(  int field0,) = RendererError_NodeExists;
+ /// The node can't be moved below one of its own descendants.
+const factory RendererError.nodeCycle(  int field0,) = RendererError_NodeCycle;
+ /// The renderer has no image with the given handle, or it was released.
+const factory RendererError.imageNotFound(  int field0,) = RendererError_ImageNotFound;
+ /// Uploaded image data couldn't be decoded.
+const factory RendererError.decoding(  String field0,) = RendererError_Decoding;
+ /// No font is registered under the given handle, or it was released.
+const factory RendererError.fontNotFound(  int field0,) = RendererError_FontNotFound;
+ /// Registered font data isn't a TrueType or OpenType font.
+const factory RendererError.invalidFont(  String field0,) = RendererError_InvalidFont;
+ /// A glyph run has a glyph id above 65535, which no font can have.
+const factory RendererError.invalidGlyph(  int field0,) = RendererError_InvalidGlyph;
+ /// `PushLayer` and `PopLayer` commands don't pair up, or are nested
+/// deeper than vello can draw.
+const factory RendererError.invalidLayers(  String field0,) = RendererError_InvalidLayers;
+ /// An SVG document couldn't be parsed.
+const factory RendererError.invalidSvg(  String field0,) = RendererError_InvalidSvg;
+ /// No Lottie animation is loaded under the given handle, or it was
+/// released.
+const factory RendererError.lottieNotFound(  int field0,) = RendererError_LottieNotFound;
+ /// A Lottie animation couldn't be parsed.
+const factory RendererError.invalidLottie(  String field0,) = RendererError_InvalidLottie;
+ /// A timeline passed to `set_timeline` can't be played.
+const factory RendererError.invalidTimeline(  String field0,) = RendererError_InvalidTimeline;
+ /// Timeline playback was controlled before a timeline was set.
+const factory RendererError.timelineNotSet() = RendererError_TimelineNotSet;
+ /// A scene was exported before `set_scene` or `stream_graph` gave the
+/// renderer a current scene.
+const factory RendererError.sceneNotSet() = RendererError_SceneNotSet;
+ /// The render thread couldn't be started or has stopped.
+const factory RendererError.disconnected() = RendererError_Disconnected;
+
+                    
+                    
+                }
+        
\ No newline at end of file
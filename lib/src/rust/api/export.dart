@@ -0,0 +1,52 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scene.dart';
+import 'simple.dart';
+
+
+        
+
+        /// Encodes `frame` as an 8 bit RGBA PNG. `None` uses
+/// `PngCompression::Default`.
+///
+/// PNG stores `ChannelOrder::Rgba` and `AlphaMode::Straight` pixels, frames
+/// rendered otherwise are converted first. Translucent pixels of
+/// premultiplied frames lose some precision on the way.
+Future<Uint8List> encodePng({required Frame frame , PngCompression? compression , dynamic hint}) => RustLib.instance.api.encodePng(frame: frame, compression: compression, hint: hint);
+
+/// Renders `scene` with the renderer registered under `id` and returns it as
+/// a PNG, which unlike `Frame::data` carries its own dimensions.
+///
+/// Returns `None` if the frame was skipped, see `render_scene`.
+Future<Uint8List?> renderPng({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , PngCompression? compression , dynamic hint}) => RustLib.instance.api.renderPng(id: id, scene: scene, width: width, height: height, options: options, compression: compression, hint: hint);
+
+/// Writes the current scene of the renderer registered under `id` as an SVG
+/// document: the scene set with `set_scene`, or the scene graph after
+/// `graph::stream_graph`, at the size and on the background they are
+/// streamed with. Fails with `RendererError::SceneNotSet` before either.
+///
+/// Paths, strokes, gradients and layers stay vectors. Glyph runs become
+/// paths, so the document doesn't depend on the fonts, and images are
+/// embedded as PNGs. Sweep gradients are drawn as wedges, like the renderer
+/// does. Strokes with different caps at their two ends are written as their
+/// filled outlines. Image brushes and layers with a `Compose` other than
+/// `SrcOver` have no SVG equivalent and fail with
+/// `RendererError::Unsupported`.
+Future<String> exportSvg({required String id , dynamic hint}) => RustLib.instance.api.exportSvg(id: id, hint: hint);
+
+        /// How hard the PNG encoder tries to shrink the file.
+enum PngCompression {
+                    /// Larger files, encoded quickly. Good for screenshots taken every frame.
+fast,
+Default,
+/// Smallest files, encoded slowly. Good for exports that are kept.
+best,
+                }
+        
\ No newline at end of file
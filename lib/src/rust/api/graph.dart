@@ -0,0 +1,74 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'scene.dart';
+import 'simple.dart';
+part 'graph.freezed.dart';
+
+        
+
+        /// Applies `updates` in order to the scene graph of the renderer registered
+/// under `id`.
+///
+/// Stops at the first update that fails, for example because it refers to a
+/// node that doesn't exist. Earlier updates in the batch stay applied.
+Future<void> updateGraph({required String id , required List<GraphUpdate> updates , dynamic hint}) => RustLib.instance.api.updateGraph(id: id, updates: updates, hint: hint);
+
+/// Renders the scene graph of the renderer registered under `id`, animated
+/// by its timeline at the renderer's own clock if one is set.
+///
+/// Returns `None` if the renderer uses `RenderQueuePolicy::LatestWins` and a
+/// newer request arrived before this one was rendered.
+Future<Frame?> renderGraph({required String id , required int width , required int height , required RenderOptions options , dynamic hint}) => RustLib.instance.api.renderGraph(id: id, width: width, height: height, options: options, hint: hint);
+
+/// Makes the renderer registered under `id` stream its scene graph to
+/// `simple::subscribe_frames` instead of the scene set with `simple::set_scene`,
+/// until the next `set_scene`.
+///
+/// A frame is pushed right away, after every `update_graph` and timeline
+/// change, and while a timeline plays, at up to 60 frames per second.
+Future<void> streamGraph({required String id , required int width , required int height , required RenderOptions options , dynamic hint}) => RustLib.instance.api.streamGraph(id: id, width: width, height: height, options: options, hint: hint);
+
+/// Finds the scene graph nodes of the renderer registered under `id` that
+/// paint the point `(x, y)` in scene coordinates, topmost first.
+///
+/// Only a node's own commands count, not its children's, and hidden nodes are
+/// never hit. Fills respect their fill rule and strokes their width, caps,
+/// joins and dashes. Colors are ignored, so fully transparent shapes are hit
+/// too.
+Future<Uint64List> hitTest({required String id , required double x , required double y , dynamic hint}) => RustLib.instance.api.hitTest(id: id, x: x, y: y, hint: hint);
+
+        @freezed
+                sealed class GraphUpdate with _$GraphUpdate  {
+                    const GraphUpdate._();
+
+                     /// Adds a node as the last child of `parent`, or at the top level if
+/// `parent` is `None`.
+const factory GraphUpdate.insert({   required int id ,  int? parent ,/// Drawn in the node's own coordinates, below its children.
+  required List<SceneCommand> commands ,/// Maps the node's coordinates to its parent's.
+  required Transform transform ,/// Siblings are drawn in increasing `z_index`, and in insertion order
+/// when equal.
+  required int zIndex ,/// Hidden nodes are skipped together with their children.
+  required bool visible , }) = GraphUpdate_Insert;
+ const factory GraphUpdate.setCommands({   required int id ,  required List<SceneCommand> commands , }) = GraphUpdate_SetCommands;
+ const factory GraphUpdate.setTransform({   required int id ,  required Transform transform , }) = GraphUpdate_SetTransform;
+ const factory GraphUpdate.setZIndex({   required int id ,  required int zIndex , }) = GraphUpdate_SetZIndex;
+ const factory GraphUpdate.setVisible({   required int id ,  required bool visible , }) = GraphUpdate_SetVisible;
+ /// Moves a node with its children to the end of `parent`'s children.
+const factory GraphUpdate.reparent({   required int id ,  int? parent , }) = GraphUpdate_Reparent;
+ /// Removes a node together with its children.
+const factory GraphUpdate.remove({   required int id , }) = GraphUpdate_Remove;
+ /// Removes every node.
+const factory GraphUpdate.clear() = GraphUpdate_Clear;
+
+                    
+                    
+                }
+        
\ No newline at end of file
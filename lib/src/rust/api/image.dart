@@ -0,0 +1,31 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+
+        
+
+        /// Decodes a PNG or JPEG file and adds it to the images of the renderer
+/// registered under `id`. Returns the handle scenes draw it with.
+Future<int> uploadImage({required String id , required List<int> bytes , dynamic hint}) => RustLib.instance.api.uploadImage(id: id, bytes: bytes, hint: hint);
+
+/// Adds `width` x `height` straight-alpha RGBA pixels, row by row without
+/// padding, to the images of the renderer registered under `id`. Returns the
+/// handle scenes draw them with.
+Future<int> uploadRgbaImage({required String id , required List<int> data , required int width , required int height , dynamic hint}) => RustLib.instance.api.uploadRgbaImage(id: id, data: data, width: width, height: height, hint: hint);
+
+/// Frees an image of the renderer registered under `id`. Frames drawing it
+/// afterwards fail with `RendererError::ImageNotFound`, except for scene
+/// graph nodes that were encoded before and haven't changed since, which
+/// keep the image alive until they do.
+Future<void> releaseImage({required String id , required int image , dynamic hint}) => RustLib.instance.api.releaseImage(id: id, image: image, hint: hint);
+
+        
+        
\ No newline at end of file
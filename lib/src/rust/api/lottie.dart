@@ -0,0 +1,80 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scene.dart';
+import 'simple.dart';
+
+
+        
+
+        /// Loads a Lottie animation from its JSON.
+///
+/// Shape, solid, null and precomposition layers are drawn, with parenting,
+/// transforms and masks. Shapes can be rects, ellipses, stars, polygons and
+/// paths, painted with solid or gradient fills and strokes and cut with trim
+/// paths. Everything else is left out with a warning.
+Future<LottieInfo> loadLottie({required String json , dynamic hint}) => RustLib.instance.api.loadLottie(json: json, hint: hint);
+
+/// Frees an animation.
+Future<void> releaseLottie({required int animation , dynamic hint}) => RustLib.instance.api.releaseLottie(animation: animation, hint: hint);
+
+/// What `load_lottie` returned for an animation.
+Future<LottieInfo> lottieInfo({required int animation , dynamic hint}) => RustLib.instance.api.lottieInfo(animation: animation, hint: hint);
+
+/// The frame of an animation `time` seconds after its start, in the
+/// coordinates of its composition, for example to draw it in a scene graph
+/// node. Times beyond its duration show its last frame.
+///
+/// Masks and layer opacity use layers, and are left out, or turned into the
+/// opacity of every paint, where that would nest layers deeper than vello
+/// can draw.
+Future<List<SceneCommand>> lottieFrame({required int animation , required double time , dynamic hint}) => RustLib.instance.api.lottieFrame(animation: animation, time: time, hint: hint);
+
+/// Renders the frame of an animation `time` seconds after its start with the
+/// renderer registered under `id`, scaled to fit and centered in `width` x
+/// `height`.
+///
+/// Returns `None` if the frame was skipped, see `render_scene`.
+Future<Frame?> renderLottie({required String id , required int animation , required double time , required int width , required int height , required RenderOptions options , dynamic hint}) => RustLib.instance.api.renderLottie(id: id, animation: animation, time: time, width: width, height: height, options: options, hint: hint);
+
+        /// A loaded animation.
+class LottieInfo  {
+                /// The handle the animation is drawn and released by.
+final int animation;
+/// Size of the composition, which its frames are drawn in.
+final double width;
+final double height;
+/// Frames per second.
+final double frameRate;
+/// In seconds.
+final double duration;
+/// One message for every feature of the animation that is left out or is
+/// drawn differently than in After Effects.
+final List<String> warnings;
+
+                const LottieInfo({required this.animation ,required this.width ,required this.height ,required this.frameRate ,required this.duration ,required this.warnings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => animation.hashCode^width.hashCode^height.hashCode^frameRate.hashCode^duration.hashCode^warnings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LottieInfo &&
+                runtimeType == other.runtimeType
+                && animation == other.animation&& width == other.width&& height == other.height&& frameRate == other.frameRate&& duration == other.duration&& warnings == other.warnings;
+        
+            }
+        
\ No newline at end of file
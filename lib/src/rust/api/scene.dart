@@ -0,0 +1,350 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scene.freezed.dart';
+
+        
+
+        Transform identityTransform({dynamic hint}) => RustLib.instance.api.identityTransform(hint: hint);
+
+/// The shapes the demo used to draw, as a scene description.
+List<SceneCommand> demoScene({dynamic hint}) => RustLib.instance.api.demoScene(hint: hint);
+
+        class BlendMode  {
+                final Mix mix;
+final Compose compose;
+
+                const BlendMode({required this.mix ,required this.compose ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => mix.hashCode^compose.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BlendMode &&
+                runtimeType == other.runtimeType
+                && mix == other.mix&& compose == other.compose;
+        
+            }
+
+@freezed
+                sealed class Brush with _$Brush  {
+                    const Brush._();
+
+                     const factory Brush.solid({   required Color color , }) = Brush_Solid;
+ /// Varies along the line from `(x0, y0)` to `(x1, y1)`.
+const factory Brush.linearGradient({   required double x0 ,  required double y0 ,  required double x1 ,  required double y1 ,  required List<ColorStop> stops ,  required Extend extend , }) = Brush_LinearGradient;
+ /// Varies from the circle around `(x0, y0)` with radius `r0` to the one
+/// around `(x1, y1)` with radius `r1`.
+///
+/// Only drawn by `RendererBackend::Gpu` renderers, the `Cpu` backend
+/// fails frames that use it with `RendererError::Unsupported`.
+const factory Brush.radialGradient({   required double x0 ,  required double y0 ,  required double r0 ,  required double x1 ,  required double y1 ,  required double r1 ,  required List<ColorStop> stops ,  required Extend extend , }) = Brush_RadialGradient;
+ /// Varies with the angle around `(cx, cy)`, from `start_angle` to
+/// `end_angle` in radians. Angles grow from the positive x axis towards the
+/// positive y axis.
+const factory Brush.sweepGradient({   required double cx ,  required double cy ,  required double startAngle ,  required double endAngle ,  required List<ColorStop> stops ,  required Extend extend , }) = Brush_SweepGradient;
+ /// An image uploaded with `upload_image`, with its top left corner at
+/// the origin and one pixel per unit. Transparent beyond its edges.
+const factory Brush.image({   required int image , }) = Brush_Image;
+
+                    
+                    
+                }
+
+/// A color with straight (non-premultiplied) 8 bit components.
+class Color  {
+                final int r;
+final int g;
+final int b;
+final int a;
+
+                const Color({required this.r ,required this.g ,required this.b ,required this.a ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => r.hashCode^g.hashCode^b.hashCode^a.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Color &&
+                runtimeType == other.runtimeType
+                && r == other.r&& g == other.g&& b == other.b&& a == other.a;
+        
+            }
+
+/// A color at a position along a gradient, from 0 at its start to 1 at its
+/// end.
+class ColorStop  {
+                final double offset;
+final Color color;
+
+                const ColorStop({required this.offset ,required this.color ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => offset.hashCode^color.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ColorStop &&
+                runtimeType == other.runtimeType
+                && offset == other.offset&& color == other.color;
+        
+            }
+
+/// Porter-Duff operator combining a layer with the content below it.
+enum Compose {
+                    clear,
+copy,
+dest,
+srcOver,
+destOver,
+srcIn,
+destIn,
+srcOut,
+destOut,
+srcAtop,
+destAtop,
+xor,
+plus,
+plusLighter,
+                }
+
+/// How a gradient continues beyond its first and last stop.
+enum Extend {
+                    /// Keeps the color of the nearest end.
+pad,
+repeat,
+/// Repeats, mirroring every other repetition.
+reflect,
+                }
+
+enum FillRule {
+                    nonZero,
+evenOdd,
+                }
+
+/// A glyph of a font, with the position of its origin on the baseline.
+class Glyph  {
+                final int id;
+final double x;
+final double y;
+
+                const Glyph({required this.id ,required this.x ,required this.y ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^x.hashCode^y.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Glyph &&
+                runtimeType == other.runtimeType
+                && id == other.id&& x == other.x&& y == other.y;
+        
+            }
+
+/// How smoothly scaled images are sampled.
+///
+/// Currently ignored: vello 0.1 samples every image bilinearly, whatever the
+/// quality. It is kept in scenes so that they draw as asked once vello
+/// supports it.
+enum ImageQuality {
+                    /// Nearest neighbor.
+low,
+medium,
+high,
+                }
+
+enum LineCap {
+                    butt,
+square,
+round,
+                }
+
+enum LineJoin {
+                    bevel,
+miter,
+round,
+                }
+
+/// How a layer's colors are mixed with the ones below it.
+enum Mix {
+                    normal,
+multiply,
+screen,
+overlay,
+darken,
+lighten,
+colorDodge,
+colorBurn,
+hardLight,
+softLight,
+difference,
+exclusion,
+hue,
+saturation,
+color,
+luminosity,
+/// Like `Normal`, but lets vello skip the layer wherever it is fully
+/// inside its clip and opaque.
+clip,
+                }
+
+@freezed
+                sealed class PathElement with _$PathElement  {
+                    const PathElement._();
+
+                     const factory PathElement.moveTo({   required double x ,  required double y , }) = PathElement_MoveTo;
+ const factory PathElement.lineTo({   required double x ,  required double y , }) = PathElement_LineTo;
+ const factory PathElement.quadTo({   required double x1 ,  required double y1 ,  required double x ,  required double y , }) = PathElement_QuadTo;
+ const factory PathElement.curveTo({   required double x1 ,  required double y1 ,  required double x2 ,  required double y2 ,  required double x ,  required double y , }) = PathElement_CurveTo;
+ const factory PathElement.close() = PathElement_Close;
+
+                    
+                    
+                }
+
+@freezed
+                sealed class SceneCommand with _$SceneCommand  {
+                    const SceneCommand._();
+
+                     const factory SceneCommand.fill({   required Shape shape ,  required Brush brush ,  required FillRule fillRule ,  required Transform transform ,/// Applied to the brush on top of `transform`.
+  Transform? brushTransform , }) = SceneCommand_Fill;
+ const factory SceneCommand.stroke({   required Shape shape ,  required Brush brush ,  required StrokeStyle style ,  required Transform transform ,/// Applied to the brush on top of `transform`.
+  Transform? brushTransform , }) = SceneCommand_Stroke;
+ /// Draws an image uploaded with `upload_image`, with its top left corner
+/// at the origin and one pixel per unit.
+const factory SceneCommand.image({   required int image ,  required Transform transform ,/// From 0 for invisible to 1 for opaque.
+  required double opacity ,/// Ignored for now, see `ImageQuality`.
+  required ImageQuality quality , }) = SceneCommand_Image;
+ /// Fills glyphs of a font registered with `register_font`, for example
+/// the ones `layout_unshaped_text` placed.
+const factory SceneCommand.glyphRun({   required int font ,/// Pixels per em.
+  required double size ,  required List<Glyph> glyphs ,  required Brush brush ,  required Transform transform , }) = SceneCommand_GlyphRun;
+ /// Starts a group of commands that is clipped to `clip` and then blended
+/// onto what's below as a whole, until the matching `PopLayer`.
+///
+/// Layers must be balanced within a scene or scene graph node. vello
+/// draws at most four nested layers, including the ones sweep
+/// gradients, translucent images and glyph runs not painted in a solid
+/// color use internally.
+const factory SceneCommand.pushLayer({ /// Filled with the non-zero rule.
+  required Shape clip ,  required BlendMode blend ,/// Opacity of the whole group, from 0 to 1.
+  required double alpha ,/// Applies to `clip` only, not to the commands in the layer.
+  required Transform transform , }) = SceneCommand_PushLayer;
+ const factory SceneCommand.popLayer() = SceneCommand_PopLayer;
+
+                    
+                    
+                }
+
+@freezed
+                sealed class Shape with _$Shape  {
+                    const Shape._();
+
+                     const factory Shape.rect({   required double x0 ,  required double y0 ,  required double x1 ,  required double y1 , }) = Shape_Rect;
+ const factory Shape.roundedRect({   required double x0 ,  required double y0 ,  required double x1 ,  required double y1 ,  required double radius , }) = Shape_RoundedRect;
+ const factory Shape.circle({   required double cx ,  required double cy ,  required double radius , }) = Shape_Circle;
+ /// Ellipse with radii `rx`/`ry`, rotated by `rotation` radians.
+const factory Shape.ellipse({   required double cx ,  required double cy ,  required double rx ,  required double ry ,  required double rotation , }) = Shape_Ellipse;
+ const factory Shape.line({   required double x0 ,  required double y0 ,  required double x1 ,  required double y1 , }) = Shape_Line;
+ const factory Shape.path({   required List<PathElement> elements , }) = Shape_Path;
+
+                    
+                    
+                }
+
+/// How a shape is outlined by `SceneCommand::Stroke`.
+class StrokeStyle  {
+                final double width;
+final LineJoin join;
+final double miterLimit;
+final LineCap startCap;
+final LineCap endCap;
+/// Alternating dash and gap lengths. Empty for a solid line.
+final Float64List dashPattern;
+final double dashOffset;
+
+                const StrokeStyle({required this.width ,required this.join ,required this.miterLimit ,required this.startCap ,required this.endCap ,required this.dashPattern ,required this.dashOffset ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^join.hashCode^miterLimit.hashCode^startCap.hashCode^endCap.hashCode^dashPattern.hashCode^dashOffset.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StrokeStyle &&
+                runtimeType == other.runtimeType
+                && width == other.width&& join == other.join&& miterLimit == other.miterLimit&& startCap == other.startCap&& endCap == other.endCap&& dashPattern == other.dashPattern&& dashOffset == other.dashOffset;
+        
+            }
+
+/// A 2D affine transform, using the same coefficient order as
+/// `kurbo::Affine`: `x' = xx * x + xy * y + dx`, `y' = yx * x + yy * y + dy`.
+class Transform  {
+                final double xx;
+final double yx;
+final double xy;
+final double yy;
+final double dx;
+final double dy;
+
+                const Transform({required this.xx ,required this.yx ,required this.xy ,required this.yy ,required this.dx ,required this.dy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => xx.hashCode^yx.hashCode^xy.hashCode^yy.hashCode^dx.hashCode^dy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Transform &&
+                runtimeType == other.runtimeType
+                && xx == other.xx&& yx == other.yx&& xy == other.xy&& yy == other.yy&& dx == other.dx&& dy == other.dy;
+        
+            }
+        
\ No newline at end of file
@@ -1,22 +1,228 @@
-// This file is automatically generated, so please do not edit it.
+
+        // This file is automatically generated, so please do not edit it.
 // Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'error.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scene.dart';
+
 
-// The type `Pos` is not used by any `pub` functions, thus it is ignored.
-// The type `RENDERERS` is not used by any `pub` functions, thus it is ignored.
-// The type `RenderCommand` is not used by any `pub` functions, thus it is ignored.
-// The type `RenderInfo` is not used by any `pub` functions, thus it is ignored.
-// The type `RenderRequest` is not used by any `pub` functions, thus it is ignored.
-// The type `RenderResponse` is not used by any `pub` functions, thus it is ignored.
+        // The type `RENDERERS` is not used by any `pub` functions, thus it is ignored.
 // The type `SimpleContext` is not used by any `pub` functions, thus it is ignored.
 
-String greet({required String name, dynamic hint}) =>
-    RustLib.instance.api.greet(name: name, hint: hint);
 
-Future<Uint8List> testRender(
-        {required double x, required double y, dynamic hint}) =>
-    RustLib.instance.api.testRender(x: x, y: y, hint: hint);
+        String greet({required String name , dynamic hint}) => RustLib.instance.api.greet(name: name, hint: hint);
+
+/// Starts a renderer with its own render thread and wgpu device under `id`,
+/// returning once the device is ready. Does nothing if a renderer with that id
+/// already exists.
+Future<void> createRenderer({required String id , required RendererConfig config , dynamic hint}) => RustLib.instance.api.createRenderer(id: id, config: config, hint: hint);
+
+/// Stops the renderer registered under `id`, waiting for its render thread to
+/// exit so that its wgpu device is released before this returns.
+Future<void> destroyRenderer({required String id , dynamic hint}) => RustLib.instance.api.destroyRenderer(id: id, hint: hint);
+
+/// Renders the pointer demo scene with the renderer created by `init_app`,
+/// returning the pixels of the frame, or `None` if it was skipped.
+Future<Uint8List?> testRender({required double x , required double y , required int width , required int height , dynamic hint}) => RustLib.instance.api.testRender(x: x, y: y, width: width, height: height, hint: hint);
+
+/// Renders the pointer demo scene with the renderer registered under `id`.
+Future<Frame?> render({required String id , required double x , required double y , required int width , required int height , dynamic hint}) => RustLib.instance.api.render(id: id, x: x, y: y, width: width, height: height, hint: hint);
+
+/// Renders `scene` with the renderer registered under `id`.
+///
+/// Returns `None` if the renderer uses `RenderQueuePolicy::LatestWins` and a
+/// newer request arrived before this one was rendered.
+Future<Frame?> renderScene({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , dynamic hint}) => RustLib.instance.api.renderScene(id: id, scene: scene, width: width, height: height, options: options, hint: hint);
+
+/// Streams the frames of the scene set with `set_scene`, or of the scene
+/// graph after `graph::stream_graph`, from the renderer registered under `id`.
+/// Closing the stream on the Dart side unsubscribes.
+Future<Stream<Frame>> subscribeFrames({required String id , dynamic hint}) => RustLib.instance.api.subscribeFrames(id: id, hint: hint);
+
+/// Replaces the scene of the renderer registered under `id` and pushes a
+/// frame of it to every `subscribe_frames` stream, which stop receiving frames
+/// of the scene graph if `graph::stream_graph` was called. New subscribers get
+/// a frame rendered with the same `options`.
+///
+/// With `RenderQueuePolicy::LatestWins` the frame is skipped if a newer scene
+/// or render request is already queued.
+Future<void> setScene({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , dynamic hint}) => RustLib.instance.api.setScene(id: id, scene: scene, width: width, height: height, options: options, hint: hint);
+
+RenderOptions defaultRenderOptions({dynamic hint}) => RustLib.instance.api.defaultRenderOptions(hint: hint);
+
+        /// How the color channels of `Frame::data` relate to its alpha channel.
+enum AlphaMode {
+                    /// Color channels are independent of alpha, as in PNG files.
+straight,
+/// Color channels are already multiplied by alpha, as most compositors
+/// expect.
+premultiplied,
+                }
+
+/// How the edges of shapes are anti-aliased.
+enum Antialiasing {
+                    /// Analytic area coverage. Fastest, but conflates adjacent edges.
+area,
+/// 8 samples per pixel.
+msaa8,
+/// 16 samples per pixel.
+msaa16,
+                }
+
+/// A set of `Antialiasing` methods.
+class AntialiasingSupport  {
+                final bool area;
+final bool msaa8;
+final bool msaa16;
+
+                const AntialiasingSupport({required this.area ,required this.msaa8 ,required this.msaa16 ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => area.hashCode^msaa8.hashCode^msaa16.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AntialiasingSupport &&
+                runtimeType == other.runtimeType
+                && area == other.area&& msaa8 == other.msaa8&& msaa16 == other.msaa16;
+        
+            }
+
+/// Byte order of the pixels in `Frame::data`.
+enum ChannelOrder {
+                    /// Red, green, blue, alpha. Flutter's `PixelFormat.rgba8888`.
+rgba,
+/// Blue, green, red, alpha. Flutter's `PixelFormat.bgra8888`, and the
+/// native layout of many platform textures.
+bgra,
+                }
+
+/// A rendered frame.
+class Frame  {
+                /// Tightly packed rows of 4 byte pixels, laid out as `channel_order` and
+/// `alpha_mode` say.
+final Uint8List data;
+final int width;
+final int height;
+/// The `RenderOptions::channel_order` the frame was rendered with.
+final ChannelOrder channelOrder;
+/// The `RenderOptions::alpha_mode` the frame was rendered with.
+final AlphaMode alphaMode;
+/// Number of older requests dropped in favour of this frame.
+final int skippedFrames;
+/// Counts the frames drawn by a renderer, starting at 0.
+final int frameNumber;
+/// When the frame was drawn, in microseconds since the renderer was
+/// created.
+final int timestampMicros;
+
+                const Frame({required this.data ,required this.width ,required this.height ,required this.channelOrder ,required this.alphaMode ,required this.skippedFrames ,required this.frameNumber ,required this.timestampMicros ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^width.hashCode^height.hashCode^channelOrder.hashCode^alphaMode.hashCode^skippedFrames.hashCode^frameNumber.hashCode^timestampMicros.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Frame &&
+                runtimeType == other.runtimeType
+                && data == other.data&& width == other.width&& height == other.height&& channelOrder == other.channelOrder&& alphaMode == other.alphaMode&& skippedFrames == other.skippedFrames&& frameNumber == other.frameNumber&& timestampMicros == other.timestampMicros;
+        
+            }
+
+/// Settings for a single frame.
+class RenderOptions  {
+                final Antialiasing antialiasing;
+/// Color the frame is cleared to before the scene is drawn. Use a zero
+/// alpha to composite the frame over other widgets.
+final Color background;
+final AlphaMode alphaMode;
+final ChannelOrder channelOrder;
+
+                const RenderOptions({required this.antialiasing ,required this.background ,required this.alphaMode ,required this.channelOrder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => antialiasing.hashCode^background.hashCode^alphaMode.hashCode^channelOrder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RenderOptions &&
+                runtimeType == other.runtimeType
+                && antialiasing == other.antialiasing&& background == other.background&& alphaMode == other.alphaMode&& channelOrder == other.channelOrder;
+        
+            }
+
+/// What a renderer does with render requests that queue up while it is busy.
+enum RenderQueuePolicy {
+                    /// Render every request, in order.
+fifo,
+/// Only render the newest pending request and answer the older ones
+/// with `None`. Suited to frames driven by pointer input.
+latestWins,
+                }
+
+/// Where a renderer runs vello's pipeline.
+enum RendererBackend {
+                    /// Hardware adapter, all stages in GPU compute shaders.
+gpu,
+/// Software fallback adapter with vello's CPU shaders, for machines
+/// without a GPU such as headless CI boxes.
+///
+/// Draws the same pixels as `Gpu`, except that vello 0.1's CPU shaders
+/// garble radial gradients. Frames with a `Brush::RadialGradient` fail
+/// with `RendererError::Unsupported` instead.
+cpu,
+                }
+
+/// Settings a renderer is created with.
+class RendererConfig  {
+                final RendererBackend backend;
+final RenderQueuePolicy queuePolicy;
+/// Which `Antialiasing` methods frames can use. Every enabled method adds
+/// a pipeline that is compiled when the renderer is created.
+final AntialiasingSupport antialiasingSupport;
+
+                const RendererConfig({required this.backend ,required this.queuePolicy ,required this.antialiasingSupport ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => backend.hashCode^queuePolicy.hashCode^antialiasingSupport.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RendererConfig &&
+                runtimeType == other.runtimeType
+                && backend == other.backend&& queuePolicy == other.queuePolicy&& antialiasingSupport == other.antialiasingSupport;
+        
+            }
+        
\ No newline at end of file
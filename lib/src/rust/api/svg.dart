@@ -0,0 +1,80 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scene.dart';
+import 'simple.dart';
+
+
+        
+
+        /// Converts an SVG document into scene commands.
+///
+/// Paths, basic shapes, transforms, solid and gradient fills and strokes,
+/// and groups with opacity, blend modes or clip paths are supported. Text,
+/// images, patterns, masks and filters are left out with a warning.
+Future<SvgScene> importSvg({required String svg , dynamic hint}) => RustLib.instance.api.importSvg(svg: svg, hint: hint);
+
+        /// An SVG document converted to scene commands.
+class SvgScene  {
+                /// In the coordinates of `view_box`. Drawing them with a transform, as
+/// part of a scene or in a scene graph node, places the document.
+final List<SceneCommand> commands;
+/// The document's `viewBox`, or its `width` and `height` if it has none.
+final ViewBox viewBox;
+/// One message for every feature of the document that was left out or is
+/// drawn differently than SVG specifies.
+final List<String> warnings;
+
+                const SvgScene({required this.commands ,required this.viewBox ,required this.warnings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => commands.hashCode^viewBox.hashCode^warnings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SvgScene &&
+                runtimeType == other.runtimeType
+                && commands == other.commands&& viewBox == other.viewBox&& warnings == other.warnings;
+        
+            }
+
+/// The area of an SVG document's coordinates that is meant to be shown.
+class ViewBox  {
+                final double x;
+final double y;
+final double width;
+final double height;
+
+                const ViewBox({required this.x ,required this.y ,required this.width ,required this.height ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => x.hashCode^y.hashCode^width.hashCode^height.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ViewBox &&
+                runtimeType == other.runtimeType
+                && x == other.x&& y == other.y&& width == other.width&& height == other.height;
+        
+            }
+        
\ No newline at end of file
@@ -0,0 +1,65 @@
+
+        // This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.31.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'scene.dart';
+import 'simple.dart';
+
+
+        
+
+        /// Registers a TrueType or OpenType font. Returns the handle that layouts and
+/// glyph runs use it by.
+Future<int> registerFont({required List<int> bytes , dynamic hint}) => RustLib.instance.api.registerFont(bytes: bytes, hint: hint);
+
+/// Frees a font. Frames with glyph runs in it fail with
+/// `RendererError::FontNotFound` afterwards, except for scene graph nodes that
+/// were encoded before and haven't changed since.
+Future<void> releaseFont({required int font , dynamic hint}) => RustLib.instance.api.releaseFont(font: font, hint: hint);
+
+/// Lays out `text` in a single font at `size` pixels per em, without shaping.
+///
+/// Each character maps to one glyph through the font's character map, placed
+/// by its advance width. There is no kerning, no ligatures, no bidirectional
+/// text and no line wrapping; every `\n` starts a new line. Scripts that need
+/// shaping, such as Arabic or Devanagari, come out wrong.
+Future<TextLayout> layoutUnshapedText({required int font , required String text , required double size , dynamic hint}) => RustLib.instance.api.layoutUnshapedText(font: font, text: text, size: size, hint: hint);
+
+        /// Glyphs of a laid out string, in a box whose top left corner is the origin.
+class TextLayout  {
+                /// Positioned on their baselines.
+final List<Glyph> glyphs;
+/// Advance of the longest line.
+final double width;
+/// From the top of the first line to the bottom of the last one.
+final double height;
+/// Distance from the top of a line to its baseline.
+final double ascent;
+/// Distance from the baseline of a line to its bottom.
+final double descent;
+
+                const TextLayout({required this.glyphs ,required this.width ,required this.height ,required this.ascent ,required this.descent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => glyphs.hashCode^width.hashCode^height.hashCode^ascent.hashCode^descent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextLayout &&
+                runtimeType == other.runtimeType
+                && glyphs == other.glyphs&& width == other.width&& height == other.height&& ascent == other.ascent&& descent == other.descent;
+        
+            }
+        
\ No newline at end of file
@@ -3,271 +3,2605 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/animation.dart';
+import 'api/error.dart';
+import 'api/export.dart';
+import 'api/graph.dart';
+import 'api/image.dart';
+import 'api/lottie.dart';
+import 'api/scene.dart';
 import 'api/simple.dart';
+import 'api/svg.dart';
+import 'api/text.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.io.dart' if (dart.library.html) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.initApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.0.0-dev.31';
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_flutter_vello',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String greet({required String name, dynamic hint});
-
-  Future<void> initApp({dynamic hint});
-
-  Future<Uint8List> testRender(
-      {required double x, required double y, dynamic hint});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String greet({required String name, dynamic hint}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(name, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kGreetConstMeta,
-      argValues: [name],
-      apiImpl: this,
-      hint: hint,
-    ));
-  }
-
-  TaskConstMeta get kGreetConstMeta => const TaskConstMeta(
-        debugName: "greet",
-        argNames: ["name"],
-      );
-
-  @override
-  Future<void> initApp({dynamic hint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-      hint: hint,
-    ));
-  }
-
-  TaskConstMeta get kInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  @override
-  Future<Uint8List> testRender(
-      {required double x, required double y, dynamic hint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_f_32(x, serializer);
-        sse_encode_f_32(y, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kTestRenderConstMeta,
-      argValues: [x, y],
-      apiImpl: this,
-      hint: hint,
-    ));
-  }
-
-  TaskConstMeta get kTestRenderConstMeta => const TaskConstMeta(
-        debugName: "test_render",
-        argNames: ["x", "y"],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
+                  
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+                  
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.initApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.0.0-dev.31';
+                  
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_flutter_vello',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> clearTimeline({required String id , dynamic hint});
+
+Future<void> pauseTimeline({required String id , dynamic hint});
+
+Future<void> playTimeline({required String id , dynamic hint});
+
+Future<Frame?> renderGraphAt({required String id , required int timestampMicros , required int width , required int height , required RenderOptions options , dynamic hint});
+
+Future<void> seekTimeline({required String id , required double position , dynamic hint});
+
+Future<void> setTimeline({required String id , required Timeline timeline , dynamic hint});
+
+Future<Uint8List> encodePng({required Frame frame , PngCompression? compression , dynamic hint});
+
+Future<String> exportSvg({required String id , dynamic hint});
+
+Future<Uint8List?> renderPng({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , PngCompression? compression , dynamic hint});
+
+Future<Uint64List> hitTest({required String id , required double x , required double y , dynamic hint});
+
+Future<Frame?> renderGraph({required String id , required int width , required int height , required RenderOptions options , dynamic hint});
+
+Future<void> streamGraph({required String id , required int width , required int height , required RenderOptions options , dynamic hint});
+
+Future<void> updateGraph({required String id , required List<GraphUpdate> updates , dynamic hint});
+
+Future<void> releaseImage({required String id , required int image , dynamic hint});
+
+Future<int> uploadImage({required String id , required List<int> bytes , dynamic hint});
+
+Future<int> uploadRgbaImage({required String id , required List<int> data , required int width , required int height , dynamic hint});
+
+Future<LottieInfo> loadLottie({required String json , dynamic hint});
+
+Future<List<SceneCommand>> lottieFrame({required int animation , required double time , dynamic hint});
+
+Future<LottieInfo> lottieInfo({required int animation , dynamic hint});
+
+Future<void> releaseLottie({required int animation , dynamic hint});
+
+Future<Frame?> renderLottie({required String id , required int animation , required double time , required int width , required int height , required RenderOptions options , dynamic hint});
+
+List<SceneCommand> demoScene({dynamic hint});
+
+Transform identityTransform({dynamic hint});
+
+Future<void> createRenderer({required String id , required RendererConfig config , dynamic hint});
+
+RenderOptions defaultRenderOptions({dynamic hint});
+
+Future<void> destroyRenderer({required String id , dynamic hint});
+
+String greet({required String name , dynamic hint});
+
+Future<void> initApp({dynamic hint});
+
+Future<Frame?> render({required String id , required double x , required double y , required int width , required int height , dynamic hint});
+
+Future<Frame?> renderScene({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , dynamic hint});
+
+Future<void> setScene({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , dynamic hint});
+
+Future<Stream<Frame>> subscribeFrames({required String id , dynamic hint});
+
+Future<Uint8List?> testRender({required double x , required double y , required int width , required int height , dynamic hint});
+
+Future<SvgScene> importSvg({required String svg , dynamic hint});
+
+Future<TextLayout> layoutUnshapedText({required int font , required String text , required double size , dynamic hint});
+
+Future<int> registerFont({required List<int> bytes , dynamic hint});
+
+Future<void> releaseFont({required int font , dynamic hint});
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> clearTimeline({required String id , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kClearTimelineConstMeta,
+            argValues: [id],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kClearTimelineConstMeta => const TaskConstMeta(
+            debugName: "clear_timeline",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> pauseTimeline({required String id , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kPauseTimelineConstMeta,
+            argValues: [id],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kPauseTimelineConstMeta => const TaskConstMeta(
+            debugName: "pause_timeline",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> playTimeline({required String id , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kPlayTimelineConstMeta,
+            argValues: [id],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kPlayTimelineConstMeta => const TaskConstMeta(
+            debugName: "play_timeline",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Frame?> renderGraphAt({required String id , required int timestampMicros , required int width , required int height , required RenderOptions options , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_64(timestampMicros, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_frame,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRenderGraphAtConstMeta,
+            argValues: [id, timestampMicros, width, height, options],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRenderGraphAtConstMeta => const TaskConstMeta(
+            debugName: "render_graph_at",
+            argNames: ["id", "timestampMicros", "width", "height", "options"],
+        );
+        
+
+@override Future<void> seekTimeline({required String id , required double position , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_f_64(position, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kSeekTimelineConstMeta,
+            argValues: [id, position],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kSeekTimelineConstMeta => const TaskConstMeta(
+            debugName: "seek_timeline",
+            argNames: ["id", "position"],
+        );
+        
+
+@override Future<void> setTimeline({required String id , required Timeline timeline , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_box_autoadd_timeline(timeline, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kSetTimelineConstMeta,
+            argValues: [id, timeline],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kSetTimelineConstMeta => const TaskConstMeta(
+            debugName: "set_timeline",
+            argNames: ["id", "timeline"],
+        );
+        
+
+@override Future<Uint8List> encodePng({required Frame frame , PngCompression? compression , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_frame(frame, serializer);
+sse_encode_opt_box_autoadd_png_compression(compression, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kEncodePngConstMeta,
+            argValues: [frame, compression],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kEncodePngConstMeta => const TaskConstMeta(
+            debugName: "encode_png",
+            argNames: ["frame", "compression"],
+        );
+        
+
+@override Future<String> exportSvg({required String id , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kExportSvgConstMeta,
+            argValues: [id],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kExportSvgConstMeta => const TaskConstMeta(
+            debugName: "export_svg",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Uint8List?> renderPng({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , PngCompression? compression , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_list_scene_command(scene, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+sse_encode_opt_box_autoadd_png_compression(compression, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRenderPngConstMeta,
+            argValues: [id, scene, width, height, options, compression],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRenderPngConstMeta => const TaskConstMeta(
+            debugName: "render_png",
+            argNames: ["id", "scene", "width", "height", "options", "compression"],
+        );
+        
+
+@override Future<Uint64List> hitTest({required String id , required double x , required double y , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_64_strict,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kHitTestConstMeta,
+            argValues: [id, x, y],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kHitTestConstMeta => const TaskConstMeta(
+            debugName: "hit_test",
+            argNames: ["id", "x", "y"],
+        );
+        
+
+@override Future<Frame?> renderGraph({required String id , required int width , required int height , required RenderOptions options , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_frame,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRenderGraphConstMeta,
+            argValues: [id, width, height, options],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRenderGraphConstMeta => const TaskConstMeta(
+            debugName: "render_graph",
+            argNames: ["id", "width", "height", "options"],
+        );
+        
+
+@override Future<void> streamGraph({required String id , required int width , required int height , required RenderOptions options , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kStreamGraphConstMeta,
+            argValues: [id, width, height, options],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kStreamGraphConstMeta => const TaskConstMeta(
+            debugName: "stream_graph",
+            argNames: ["id", "width", "height", "options"],
+        );
+        
+
+@override Future<void> updateGraph({required String id , required List<GraphUpdate> updates , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_list_graph_update(updates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kUpdateGraphConstMeta,
+            argValues: [id, updates],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kUpdateGraphConstMeta => const TaskConstMeta(
+            debugName: "update_graph",
+            argNames: ["id", "updates"],
+        );
+        
+
+@override Future<void> releaseImage({required String id , required int image , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_64(image, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kReleaseImageConstMeta,
+            argValues: [id, image],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kReleaseImageConstMeta => const TaskConstMeta(
+            debugName: "release_image",
+            argNames: ["id", "image"],
+        );
+        
+
+@override Future<int> uploadImage({required String id , required List<int> bytes , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_list_prim_u_8_loose(bytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kUploadImageConstMeta,
+            argValues: [id, bytes],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kUploadImageConstMeta => const TaskConstMeta(
+            debugName: "upload_image",
+            argNames: ["id", "bytes"],
+        );
+        
+
+@override Future<int> uploadRgbaImage({required String id , required List<int> data , required int width , required int height , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kUploadRgbaImageConstMeta,
+            argValues: [id, data, width, height],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kUploadRgbaImageConstMeta => const TaskConstMeta(
+            debugName: "upload_rgba_image",
+            argNames: ["id", "data", "width", "height"],
+        );
+        
+
+@override Future<LottieInfo> loadLottie({required String json , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(json, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lottie_info,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kLoadLottieConstMeta,
+            argValues: [json],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kLoadLottieConstMeta => const TaskConstMeta(
+            debugName: "load_lottie",
+            argNames: ["json"],
+        );
+        
+
+@override Future<List<SceneCommand>> lottieFrame({required int animation , required double time , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(animation, serializer);
+sse_encode_f_64(time, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_scene_command,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kLottieFrameConstMeta,
+            argValues: [animation, time],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kLottieFrameConstMeta => const TaskConstMeta(
+            debugName: "lottie_frame",
+            argNames: ["animation", "time"],
+        );
+        
+
+@override Future<LottieInfo> lottieInfo({required int animation , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(animation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lottie_info,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kLottieInfoConstMeta,
+            argValues: [animation],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kLottieInfoConstMeta => const TaskConstMeta(
+            debugName: "lottie_info",
+            argNames: ["animation"],
+        );
+        
+
+@override Future<void> releaseLottie({required int animation , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(animation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kReleaseLottieConstMeta,
+            argValues: [animation],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kReleaseLottieConstMeta => const TaskConstMeta(
+            debugName: "release_lottie",
+            argNames: ["animation"],
+        );
+        
+
+@override Future<Frame?> renderLottie({required String id , required int animation , required double time , required int width , required int height , required RenderOptions options , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_64(animation, serializer);
+sse_encode_f_64(time, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_frame,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRenderLottieConstMeta,
+            argValues: [id, animation, time, width, height, options],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRenderLottieConstMeta => const TaskConstMeta(
+            debugName: "render_lottie",
+            argNames: ["id", "animation", "time", "width", "height", "options"],
+        );
+        
+
+@override List<SceneCommand> demoScene({dynamic hint})  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_scene_command,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kDemoSceneConstMeta,
+            argValues: [],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kDemoSceneConstMeta => const TaskConstMeta(
+            debugName: "demo_scene",
+            argNames: [],
+        );
+        
+
+@override Transform identityTransform({dynamic hint})  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_transform,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kIdentityTransformConstMeta,
+            argValues: [],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kIdentityTransformConstMeta => const TaskConstMeta(
+            debugName: "identity_transform",
+            argNames: [],
+        );
+        
+
+@override Future<void> createRenderer({required String id , required RendererConfig config , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_box_autoadd_renderer_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kCreateRendererConstMeta,
+            argValues: [id, config],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kCreateRendererConstMeta => const TaskConstMeta(
+            debugName: "create_renderer",
+            argNames: ["id", "config"],
+        );
+        
+
+@override RenderOptions defaultRenderOptions({dynamic hint})  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_render_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kDefaultRenderOptionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kDefaultRenderOptionsConstMeta => const TaskConstMeta(
+            debugName: "default_render_options",
+            argNames: [],
+        );
+        
+
+@override Future<void> destroyRenderer({required String id , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kDestroyRendererConstMeta,
+            argValues: [id],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kDestroyRendererConstMeta => const TaskConstMeta(
+            debugName: "destroy_renderer",
+            argNames: ["id"],
+        );
+        
+
+@override String greet({required String name , dynamic hint})  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> initApp({dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<Frame?> render({required String id , required double x , required double y , required int width , required int height , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_frame,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRenderConstMeta,
+            argValues: [id, x, y, width, height],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRenderConstMeta => const TaskConstMeta(
+            debugName: "render",
+            argNames: ["id", "x", "y", "width", "height"],
+        );
+        
+
+@override Future<Frame?> renderScene({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_list_scene_command(scene, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_frame,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRenderSceneConstMeta,
+            argValues: [id, scene, width, height, options],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRenderSceneConstMeta => const TaskConstMeta(
+            debugName: "render_scene",
+            argNames: ["id", "scene", "width", "height", "options"],
+        );
+        
+
+@override Future<void> setScene({required String id , required List<SceneCommand> scene , required int width , required int height , required RenderOptions options , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_list_scene_command(scene, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_box_autoadd_render_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kSetSceneConstMeta,
+            argValues: [id, scene, width, height, options],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kSetSceneConstMeta => const TaskConstMeta(
+            debugName: "set_scene",
+            argNames: ["id", "scene", "width", "height", "options"],
+        );
+        
+
+@override Future<Stream<Frame>> subscribeFrames({required String id , dynamic hint}) async  { 
+            final sink = RustStreamSink<Frame>();
+            await handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_StreamSink_frame_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kSubscribeFramesConstMeta,
+            argValues: [id, sink],
+            apiImpl: this,
+            hint: hint,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kSubscribeFramesConstMeta => const TaskConstMeta(
+            debugName: "subscribe_frames",
+            argNames: ["id", "sink"],
+        );
+        
+
+@override Future<Uint8List?> testRender({required double x , required double y , required int width , required int height , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kTestRenderConstMeta,
+            argValues: [x, y, width, height],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kTestRenderConstMeta => const TaskConstMeta(
+            debugName: "test_render",
+            argNames: ["x", "y", "width", "height"],
+        );
+        
+
+@override Future<SvgScene> importSvg({required String svg , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(svg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_svg_scene,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kImportSvgConstMeta,
+            argValues: [svg],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kImportSvgConstMeta => const TaskConstMeta(
+            debugName: "import_svg",
+            argNames: ["svg"],
+        );
+        
+
+@override Future<TextLayout> layoutUnshapedText({required int font , required String text , required double size , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(font, serializer);
+sse_encode_String(text, serializer);
+sse_encode_f_64(size, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_text_layout,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kLayoutUnshapedTextConstMeta,
+            argValues: [font, text, size],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kLayoutUnshapedTextConstMeta => const TaskConstMeta(
+            debugName: "layout_unshaped_text",
+            argNames: ["font", "text", "size"],
+        );
+        
+
+@override Future<int> registerFont({required List<int> bytes , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kRegisterFontConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kRegisterFontConstMeta => const TaskConstMeta(
+            debugName: "register_font",
+            argNames: ["bytes"],
+        );
+        
+
+@override Future<void> releaseFont({required int font , dynamic hint})  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(font, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_renderer_error,
+        )
+        ,
+            constMeta: kReleaseFontConstMeta,
+            argValues: [font],
+            apiImpl: this,
+            hint: hint,
+        )); }
+
+
+        TaskConstMeta get kReleaseFontConstMeta => const TaskConstMeta(
+            debugName: "release_font",
+            argNames: ["font"],
+        );
+        
+
+
+
+                  @protected RustStreamSink<Frame> dco_decode_StreamSink_frame_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AlphaMode dco_decode_alpha_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AlphaMode.values[raw as int]; }
+
+@protected AnimatedProperty dco_decode_animated_property(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return AnimatedProperty_Transform();
+case 1: return AnimatedProperty_Opacity();
+case 2: return AnimatedProperty_Color(command: dco_decode_u_32(raw[1]),);
+case 3: return AnimatedProperty_StrokeWidth(command: dco_decode_u_32(raw[1]),);
+case 4: return AnimatedProperty_Path(command: dco_decode_u_32(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected Antialiasing dco_decode_antialiasing(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Antialiasing.values[raw as int]; }
+
+@protected AntialiasingSupport dco_decode_antialiasing_support(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return AntialiasingSupport(area: dco_decode_bool(arr[0]),
+msaa8: dco_decode_bool(arr[1]),
+msaa16: dco_decode_bool(arr[2]),); }
+
+@protected BlendMode dco_decode_blend_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BlendMode(mix: dco_decode_mix(arr[0]),
+compose: dco_decode_compose(arr[1]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected BlendMode dco_decode_box_autoadd_blend_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blend_mode(raw); }
+
+@protected Brush dco_decode_box_autoadd_brush(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_brush(raw); }
+
+@protected Color dco_decode_box_autoadd_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_color(raw); }
+
+@protected Frame dco_decode_box_autoadd_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_frame(raw); }
+
+@protected PngCompression dco_decode_box_autoadd_png_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_png_compression(raw); }
+
+@protected RenderOptions dco_decode_box_autoadd_render_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_render_options(raw); }
+
+@protected RendererConfig dco_decode_box_autoadd_renderer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_renderer_config(raw); }
+
+@protected Shape dco_decode_box_autoadd_shape(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_shape(raw); }
+
+@protected StrokeStyle dco_decode_box_autoadd_stroke_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stroke_style(raw); }
+
+@protected Timeline dco_decode_box_autoadd_timeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_timeline(raw); }
+
+@protected Transform dco_decode_box_autoadd_transform(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_transform(raw); }
+
+@protected int dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected Brush dco_decode_brush(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return Brush_Solid(color: dco_decode_box_autoadd_color(raw[1]),);
+case 1: return Brush_LinearGradient(x0: dco_decode_f_64(raw[1]),y0: dco_decode_f_64(raw[2]),x1: dco_decode_f_64(raw[3]),y1: dco_decode_f_64(raw[4]),stops: dco_decode_list_color_stop(raw[5]),extend: dco_decode_extend(raw[6]),);
+case 2: return Brush_RadialGradient(x0: dco_decode_f_64(raw[1]),y0: dco_decode_f_64(raw[2]),r0: dco_decode_f_64(raw[3]),x1: dco_decode_f_64(raw[4]),y1: dco_decode_f_64(raw[5]),r1: dco_decode_f_64(raw[6]),stops: dco_decode_list_color_stop(raw[7]),extend: dco_decode_extend(raw[8]),);
+case 3: return Brush_SweepGradient(cx: dco_decode_f_64(raw[1]),cy: dco_decode_f_64(raw[2]),startAngle: dco_decode_f_64(raw[3]),endAngle: dco_decode_f_64(raw[4]),stops: dco_decode_list_color_stop(raw[5]),extend: dco_decode_extend(raw[6]),);
+case 4: return Brush_Image(image: dco_decode_u_64(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ChannelOrder dco_decode_channel_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChannelOrder.values[raw as int]; }
+
+@protected Color dco_decode_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Color(r: dco_decode_u_8(arr[0]),
+g: dco_decode_u_8(arr[1]),
+b: dco_decode_u_8(arr[2]),
+a: dco_decode_u_8(arr[3]),); }
+
+@protected ColorStop dco_decode_color_stop(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ColorStop(offset: dco_decode_f_64(arr[0]),
+color: dco_decode_color(arr[1]),); }
+
+@protected Compose dco_decode_compose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Compose.values[raw as int]; }
+
+@protected Easing dco_decode_easing(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return Easing_Hold();
+case 1: return Easing_Linear();
+case 2: return Easing_CubicBezier(x1: dco_decode_f_64(raw[1]),y1: dco_decode_f_64(raw[2]),x2: dco_decode_f_64(raw[3]),y2: dco_decode_f_64(raw[4]),);
+case 3: return Easing_Spring(stiffness: dco_decode_f_64(raw[1]),damping: dco_decode_f_64(raw[2]),mass: dco_decode_f_64(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected Extend dco_decode_extend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Extend.values[raw as int]; }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FillRule dco_decode_fill_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FillRule.values[raw as int]; }
+
+@protected Frame dco_decode_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return Frame(data: dco_decode_list_prim_u_8_strict(arr[0]),
+width: dco_decode_u_32(arr[1]),
+height: dco_decode_u_32(arr[2]),
+channelOrder: dco_decode_channel_order(arr[3]),
+alphaMode: dco_decode_alpha_mode(arr[4]),
+skippedFrames: dco_decode_u_32(arr[5]),
+frameNumber: dco_decode_u_64(arr[6]),
+timestampMicros: dco_decode_u_64(arr[7]),); }
+
+@protected Glyph dco_decode_glyph(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Glyph(id: dco_decode_u_32(arr[0]),
+x: dco_decode_f_64(arr[1]),
+y: dco_decode_f_64(arr[2]),); }
+
+@protected GraphUpdate dco_decode_graph_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return GraphUpdate_Insert(id: dco_decode_u_64(raw[1]),parent: dco_decode_opt_box_autoadd_u_64(raw[2]),commands: dco_decode_list_scene_command(raw[3]),transform: dco_decode_box_autoadd_transform(raw[4]),zIndex: dco_decode_i_32(raw[5]),visible: dco_decode_bool(raw[6]),);
+case 1: return GraphUpdate_SetCommands(id: dco_decode_u_64(raw[1]),commands: dco_decode_list_scene_command(raw[2]),);
+case 2: return GraphUpdate_SetTransform(id: dco_decode_u_64(raw[1]),transform: dco_decode_box_autoadd_transform(raw[2]),);
+case 3: return GraphUpdate_SetZIndex(id: dco_decode_u_64(raw[1]),zIndex: dco_decode_i_32(raw[2]),);
+case 4: return GraphUpdate_SetVisible(id: dco_decode_u_64(raw[1]),visible: dco_decode_bool(raw[2]),);
+case 5: return GraphUpdate_Reparent(id: dco_decode_u_64(raw[1]),parent: dco_decode_opt_box_autoadd_u_64(raw[2]),);
+case 6: return GraphUpdate_Remove(id: dco_decode_u_64(raw[1]),);
+case 7: return GraphUpdate_Clear();
+                default: throw Exception("unreachable");
+            } }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected ImageQuality dco_decode_image_quality(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ImageQuality.values[raw as int]; }
+
+@protected Keyframe dco_decode_keyframe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Keyframe(time: dco_decode_f_64(arr[0]),
+value: dco_decode_keyframe_value(arr[1]),
+easing: dco_decode_easing(arr[2]),); }
+
+@protected KeyframeValue dco_decode_keyframe_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return KeyframeValue_Transform(dco_decode_box_autoadd_transform(raw[1]),);
+case 1: return KeyframeValue_Number(dco_decode_f_64(raw[1]),);
+case 2: return KeyframeValue_Color(dco_decode_box_autoadd_color(raw[1]),);
+case 3: return KeyframeValue_Path(dco_decode_list_path_element(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LineCap dco_decode_line_cap(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LineCap.values[raw as int]; }
+
+@protected LineJoin dco_decode_line_join(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LineJoin.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ColorStop> dco_decode_list_color_stop(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_color_stop).toList(); }
+
+@protected List<Glyph> dco_decode_list_glyph(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_glyph).toList(); }
+
+@protected List<GraphUpdate> dco_decode_list_graph_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_graph_update).toList(); }
+
+@protected List<Keyframe> dco_decode_list_keyframe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_keyframe).toList(); }
+
+@protected List<PathElement> dco_decode_list_path_element(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_path_element).toList(); }
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float64List; }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Uint64List.from(raw); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<SceneCommand> dco_decode_list_scene_command(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_scene_command).toList(); }
+
+@protected List<Track> dco_decode_list_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_track).toList(); }
+
+@protected LottieInfo dco_decode_lottie_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LottieInfo(animation: dco_decode_u_64(arr[0]),
+width: dco_decode_f_64(arr[1]),
+height: dco_decode_f_64(arr[2]),
+frameRate: dco_decode_f_64(arr[3]),
+duration: dco_decode_f_64(arr[4]),
+warnings: dco_decode_list_String(arr[5]),); }
+
+@protected Mix dco_decode_mix(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Mix.values[raw as int]; }
+
+@protected Frame? dco_decode_opt_box_autoadd_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_frame(raw); }
+
+@protected PngCompression? dco_decode_opt_box_autoadd_png_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_png_compression(raw); }
+
+@protected Transform? dco_decode_opt_box_autoadd_transform(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_transform(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PathElement dco_decode_path_element(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PathElement_MoveTo(x: dco_decode_f_64(raw[1]),y: dco_decode_f_64(raw[2]),);
+case 1: return PathElement_LineTo(x: dco_decode_f_64(raw[1]),y: dco_decode_f_64(raw[2]),);
+case 2: return PathElement_QuadTo(x1: dco_decode_f_64(raw[1]),y1: dco_decode_f_64(raw[2]),x: dco_decode_f_64(raw[3]),y: dco_decode_f_64(raw[4]),);
+case 3: return PathElement_CurveTo(x1: dco_decode_f_64(raw[1]),y1: dco_decode_f_64(raw[2]),x2: dco_decode_f_64(raw[3]),y2: dco_decode_f_64(raw[4]),x: dco_decode_f_64(raw[5]),y: dco_decode_f_64(raw[6]),);
+case 4: return PathElement_Close();
+                default: throw Exception("unreachable");
+            } }
+
+@protected PngCompression dco_decode_png_compression(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PngCompression.values[raw as int]; }
+
+@protected RenderOptions dco_decode_render_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RenderOptions(antialiasing: dco_decode_antialiasing(arr[0]),
+background: dco_decode_color(arr[1]),
+alphaMode: dco_decode_alpha_mode(arr[2]),
+channelOrder: dco_decode_channel_order(arr[3]),); }
+
+@protected RenderQueuePolicy dco_decode_render_queue_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RenderQueuePolicy.values[raw as int]; }
+
+@protected RendererBackend dco_decode_renderer_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RendererBackend.values[raw as int]; }
+
+@protected RendererConfig dco_decode_renderer_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RendererConfig(backend: dco_decode_renderer_backend(arr[0]),
+queuePolicy: dco_decode_render_queue_policy(arr[1]),
+antialiasingSupport: dco_decode_antialiasing_support(arr[2]),); }
+
+@protected RendererError dco_decode_renderer_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return RendererError_AdapterNotFound();
+case 1: return RendererError_DeviceCreation(dco_decode_String(raw[1]),);
+case 2: return RendererError_RendererCreation(dco_decode_String(raw[1]),);
+case 3: return RendererError_Render(dco_decode_String(raw[1]),);
+case 4: return RendererError_UnsupportedAntialiasing(dco_decode_antialiasing(raw[1]),);
+case 5: return RendererError_Unsupported(dco_decode_String(raw[1]),);
+case 6: return RendererError_Readback(dco_decode_String(raw[1]),);
+case 7: return RendererError_Encoding(dco_decode_String(raw[1]),);
+case 8: return RendererError_RendererNotFound(dco_decode_String(raw[1]),);
+case 9: return RendererError_NodeNotFound(dco_decode_u_64(raw[1]),);
+case 10: return RendererError_NodeExists(dco_decode_u_64(raw[1]),);
+case 11: return RendererError_NodeCycle(dco_decode_u_64(raw[1]),);
+case 12: return RendererError_ImageNotFound(dco_decode_u_64(raw[1]),);
+case 13: return RendererError_Decoding(dco_decode_String(raw[1]),);
+case 14: return RendererError_FontNotFound(dco_decode_u_64(raw[1]),);
+case 15: return RendererError_InvalidFont(dco_decode_String(raw[1]),);
+case 16: return RendererError_InvalidGlyph(dco_decode_u_32(raw[1]),);
+case 17: return RendererError_InvalidLayers(dco_decode_String(raw[1]),);
+case 18: return RendererError_InvalidSvg(dco_decode_String(raw[1]),);
+case 19: return RendererError_LottieNotFound(dco_decode_u_64(raw[1]),);
+case 20: return RendererError_InvalidLottie(dco_decode_String(raw[1]),);
+case 21: return RendererError_InvalidTimeline(dco_decode_String(raw[1]),);
+case 22: return RendererError_TimelineNotSet();
+case 23: return RendererError_SceneNotSet();
+case 24: return RendererError_Disconnected();
+                default: throw Exception("unreachable");
+            } }
+
+@protected SceneCommand dco_decode_scene_command(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SceneCommand_Fill(shape: dco_decode_box_autoadd_shape(raw[1]),brush: dco_decode_box_autoadd_brush(raw[2]),fillRule: dco_decode_fill_rule(raw[3]),transform: dco_decode_box_autoadd_transform(raw[4]),brushTransform: dco_decode_opt_box_autoadd_transform(raw[5]),);
+case 1: return SceneCommand_Stroke(shape: dco_decode_box_autoadd_shape(raw[1]),brush: dco_decode_box_autoadd_brush(raw[2]),style: dco_decode_box_autoadd_stroke_style(raw[3]),transform: dco_decode_box_autoadd_transform(raw[4]),brushTransform: dco_decode_opt_box_autoadd_transform(raw[5]),);
+case 2: return SceneCommand_Image(image: dco_decode_u_64(raw[1]),transform: dco_decode_box_autoadd_transform(raw[2]),opacity: dco_decode_f_64(raw[3]),quality: dco_decode_image_quality(raw[4]),);
+case 3: return SceneCommand_GlyphRun(font: dco_decode_u_64(raw[1]),size: dco_decode_f_64(raw[2]),glyphs: dco_decode_list_glyph(raw[3]),brush: dco_decode_box_autoadd_brush(raw[4]),transform: dco_decode_box_autoadd_transform(raw[5]),);
+case 4: return SceneCommand_PushLayer(clip: dco_decode_box_autoadd_shape(raw[1]),blend: dco_decode_box_autoadd_blend_mode(raw[2]),alpha: dco_decode_f_64(raw[3]),transform: dco_decode_box_autoadd_transform(raw[4]),);
+case 5: return SceneCommand_PopLayer();
+                default: throw Exception("unreachable");
+            } }
+
+@protected Shape dco_decode_shape(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return Shape_Rect(x0: dco_decode_f_64(raw[1]),y0: dco_decode_f_64(raw[2]),x1: dco_decode_f_64(raw[3]),y1: dco_decode_f_64(raw[4]),);
+case 1: return Shape_RoundedRect(x0: dco_decode_f_64(raw[1]),y0: dco_decode_f_64(raw[2]),x1: dco_decode_f_64(raw[3]),y1: dco_decode_f_64(raw[4]),radius: dco_decode_f_64(raw[5]),);
+case 2: return Shape_Circle(cx: dco_decode_f_64(raw[1]),cy: dco_decode_f_64(raw[2]),radius: dco_decode_f_64(raw[3]),);
+case 3: return Shape_Ellipse(cx: dco_decode_f_64(raw[1]),cy: dco_decode_f_64(raw[2]),rx: dco_decode_f_64(raw[3]),ry: dco_decode_f_64(raw[4]),rotation: dco_decode_f_64(raw[5]),);
+case 4: return Shape_Line(x0: dco_decode_f_64(raw[1]),y0: dco_decode_f_64(raw[2]),x1: dco_decode_f_64(raw[3]),y1: dco_decode_f_64(raw[4]),);
+case 5: return Shape_Path(elements: dco_decode_list_path_element(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected StrokeStyle dco_decode_stroke_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return StrokeStyle(width: dco_decode_f_64(arr[0]),
+join: dco_decode_line_join(arr[1]),
+miterLimit: dco_decode_f_64(arr[2]),
+startCap: dco_decode_line_cap(arr[3]),
+endCap: dco_decode_line_cap(arr[4]),
+dashPattern: dco_decode_list_prim_f_64_strict(arr[5]),
+dashOffset: dco_decode_f_64(arr[6]),); }
+
+@protected SvgScene dco_decode_svg_scene(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SvgScene(commands: dco_decode_list_scene_command(arr[0]),
+viewBox: dco_decode_view_box(arr[1]),
+warnings: dco_decode_list_String(arr[2]),); }
+
+@protected TextLayout dco_decode_text_layout(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TextLayout(glyphs: dco_decode_list_glyph(arr[0]),
+width: dco_decode_f_64(arr[1]),
+height: dco_decode_f_64(arr[2]),
+ascent: dco_decode_f_64(arr[3]),
+descent: dco_decode_f_64(arr[4]),); }
+
+@protected Timeline dco_decode_timeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Timeline(tracks: dco_decode_list_track(arr[0]),
+looping: dco_decode_bool(arr[1]),); }
+
+@protected Track dco_decode_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Track(node: dco_decode_u_64(arr[0]),
+property: dco_decode_animated_property(arr[1]),
+keyframes: dco_decode_list_keyframe(arr[2]),); }
+
+@protected Transform dco_decode_transform(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return Transform(xx: dco_decode_f_64(arr[0]),
+yx: dco_decode_f_64(arr[1]),
+xy: dco_decode_f_64(arr[2]),
+yy: dco_decode_f_64(arr[3]),
+dx: dco_decode_f_64(arr[4]),
+dy: dco_decode_f_64(arr[5]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64OrU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected ViewBox dco_decode_view_box(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ViewBox(x: dco_decode_f_64(arr[0]),
+y: dco_decode_f_64(arr[1]),
+width: dco_decode_f_64(arr[2]),
+height: dco_decode_f_64(arr[3]),); }
+
+@protected RustStreamSink<Frame> sse_decode_StreamSink_frame_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AlphaMode sse_decode_alpha_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AlphaMode.values[inner]; }
+
+@protected AnimatedProperty sse_decode_animated_property(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return AnimatedProperty_Transform();case 1: return AnimatedProperty_Opacity();case 2: var var_command = sse_decode_u_32(deserializer);
+return AnimatedProperty_Color(command: var_command);case 3: var var_command = sse_decode_u_32(deserializer);
+return AnimatedProperty_StrokeWidth(command: var_command);case 4: var var_command = sse_decode_u_32(deserializer);
+return AnimatedProperty_Path(command: var_command); default: throw UnimplementedError(''); }
+             }
+
+@protected Antialiasing sse_decode_antialiasing(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Antialiasing.values[inner]; }
+
+@protected AntialiasingSupport sse_decode_antialiasing_support(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_area = sse_decode_bool(deserializer);
+var var_msaa8 = sse_decode_bool(deserializer);
+var var_msaa16 = sse_decode_bool(deserializer);
+return AntialiasingSupport(area: var_area, msaa8: var_msaa8, msaa16: var_msaa16); }
+
+@protected BlendMode sse_decode_blend_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_mix = sse_decode_mix(deserializer);
+var var_compose = sse_decode_compose(deserializer);
+return BlendMode(mix: var_mix, compose: var_compose); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected BlendMode sse_decode_box_autoadd_blend_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blend_mode(deserializer)); }
+
+@protected Brush sse_decode_box_autoadd_brush(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_brush(deserializer)); }
+
+@protected Color sse_decode_box_autoadd_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_color(deserializer)); }
+
+@protected Frame sse_decode_box_autoadd_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_frame(deserializer)); }
+
+@protected PngCompression sse_decode_box_autoadd_png_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_png_compression(deserializer)); }
+
+@protected RenderOptions sse_decode_box_autoadd_render_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_render_options(deserializer)); }
+
+@protected RendererConfig sse_decode_box_autoadd_renderer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_renderer_config(deserializer)); }
+
+@protected Shape sse_decode_box_autoadd_shape(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_shape(deserializer)); }
+
+@protected StrokeStyle sse_decode_box_autoadd_stroke_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stroke_style(deserializer)); }
+
+@protected Timeline sse_decode_box_autoadd_timeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_timeline(deserializer)); }
+
+@protected Transform sse_decode_box_autoadd_transform(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_transform(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected Brush sse_decode_brush(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_color = sse_decode_box_autoadd_color(deserializer);
+return Brush_Solid(color: var_color);case 1: var var_x0 = sse_decode_f_64(deserializer);
+var var_y0 = sse_decode_f_64(deserializer);
+var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+var var_stops = sse_decode_list_color_stop(deserializer);
+var var_extend = sse_decode_extend(deserializer);
+return Brush_LinearGradient(x0: var_x0, y0: var_y0, x1: var_x1, y1: var_y1, stops: var_stops, extend: var_extend);case 2: var var_x0 = sse_decode_f_64(deserializer);
+var var_y0 = sse_decode_f_64(deserializer);
+var var_r0 = sse_decode_f_64(deserializer);
+var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+var var_r1 = sse_decode_f_64(deserializer);
+var var_stops = sse_decode_list_color_stop(deserializer);
+var var_extend = sse_decode_extend(deserializer);
+return Brush_RadialGradient(x0: var_x0, y0: var_y0, r0: var_r0, x1: var_x1, y1: var_y1, r1: var_r1, stops: var_stops, extend: var_extend);case 3: var var_cx = sse_decode_f_64(deserializer);
+var var_cy = sse_decode_f_64(deserializer);
+var var_startAngle = sse_decode_f_64(deserializer);
+var var_endAngle = sse_decode_f_64(deserializer);
+var var_stops = sse_decode_list_color_stop(deserializer);
+var var_extend = sse_decode_extend(deserializer);
+return Brush_SweepGradient(cx: var_cx, cy: var_cy, startAngle: var_startAngle, endAngle: var_endAngle, stops: var_stops, extend: var_extend);case 4: var var_image = sse_decode_u_64(deserializer);
+return Brush_Image(image: var_image); default: throw UnimplementedError(''); }
+             }
+
+@protected ChannelOrder sse_decode_channel_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChannelOrder.values[inner]; }
+
+@protected Color sse_decode_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_r = sse_decode_u_8(deserializer);
+var var_g = sse_decode_u_8(deserializer);
+var var_b = sse_decode_u_8(deserializer);
+var var_a = sse_decode_u_8(deserializer);
+return Color(r: var_r, g: var_g, b: var_b, a: var_a); }
+
+@protected ColorStop sse_decode_color_stop(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_offset = sse_decode_f_64(deserializer);
+var var_color = sse_decode_color(deserializer);
+return ColorStop(offset: var_offset, color: var_color); }
+
+@protected Compose sse_decode_compose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Compose.values[inner]; }
+
+@protected Easing sse_decode_easing(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return Easing_Hold();case 1: return Easing_Linear();case 2: var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+var var_x2 = sse_decode_f_64(deserializer);
+var var_y2 = sse_decode_f_64(deserializer);
+return Easing_CubicBezier(x1: var_x1, y1: var_y1, x2: var_x2, y2: var_y2);case 3: var var_stiffness = sse_decode_f_64(deserializer);
+var var_damping = sse_decode_f_64(deserializer);
+var var_mass = sse_decode_f_64(deserializer);
+return Easing_Spring(stiffness: var_stiffness, damping: var_damping, mass: var_mass); default: throw UnimplementedError(''); }
+             }
+
+@protected Extend sse_decode_extend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Extend.values[inner]; }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FillRule sse_decode_fill_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FillRule.values[inner]; }
+
+@protected Frame sse_decode_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_channelOrder = sse_decode_channel_order(deserializer);
+var var_alphaMode = sse_decode_alpha_mode(deserializer);
+var var_skippedFrames = sse_decode_u_32(deserializer);
+var var_frameNumber = sse_decode_u_64(deserializer);
+var var_timestampMicros = sse_decode_u_64(deserializer);
+return Frame(data: var_data, width: var_width, height: var_height, channelOrder: var_channelOrder, alphaMode: var_alphaMode, skippedFrames: var_skippedFrames, frameNumber: var_frameNumber, timestampMicros: var_timestampMicros); }
+
+@protected Glyph sse_decode_glyph(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+return Glyph(id: var_id, x: var_x, y: var_y); }
+
+@protected GraphUpdate sse_decode_graph_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_id = sse_decode_u_64(deserializer);
+var var_parent = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_commands = sse_decode_list_scene_command(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+var var_zIndex = sse_decode_i_32(deserializer);
+var var_visible = sse_decode_bool(deserializer);
+return GraphUpdate_Insert(id: var_id, parent: var_parent, commands: var_commands, transform: var_transform, zIndex: var_zIndex, visible: var_visible);case 1: var var_id = sse_decode_u_64(deserializer);
+var var_commands = sse_decode_list_scene_command(deserializer);
+return GraphUpdate_SetCommands(id: var_id, commands: var_commands);case 2: var var_id = sse_decode_u_64(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+return GraphUpdate_SetTransform(id: var_id, transform: var_transform);case 3: var var_id = sse_decode_u_64(deserializer);
+var var_zIndex = sse_decode_i_32(deserializer);
+return GraphUpdate_SetZIndex(id: var_id, zIndex: var_zIndex);case 4: var var_id = sse_decode_u_64(deserializer);
+var var_visible = sse_decode_bool(deserializer);
+return GraphUpdate_SetVisible(id: var_id, visible: var_visible);case 5: var var_id = sse_decode_u_64(deserializer);
+var var_parent = sse_decode_opt_box_autoadd_u_64(deserializer);
+return GraphUpdate_Reparent(id: var_id, parent: var_parent);case 6: var var_id = sse_decode_u_64(deserializer);
+return GraphUpdate_Remove(id: var_id);case 7: return GraphUpdate_Clear(); default: throw UnimplementedError(''); }
+             }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected ImageQuality sse_decode_image_quality(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ImageQuality.values[inner]; }
+
+@protected Keyframe sse_decode_keyframe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_time = sse_decode_f_64(deserializer);
+var var_value = sse_decode_keyframe_value(deserializer);
+var var_easing = sse_decode_easing(deserializer);
+return Keyframe(time: var_time, value: var_value, easing: var_easing); }
+
+@protected KeyframeValue sse_decode_keyframe_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_transform(deserializer);
+return KeyframeValue_Transform(var_field0);case 1: var var_field0 = sse_decode_f_64(deserializer);
+return KeyframeValue_Number(var_field0);case 2: var var_field0 = sse_decode_box_autoadd_color(deserializer);
+return KeyframeValue_Color(var_field0);case 3: var var_field0 = sse_decode_list_path_element(deserializer);
+return KeyframeValue_Path(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected LineCap sse_decode_line_cap(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LineCap.values[inner]; }
+
+@protected LineJoin sse_decode_line_join(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LineJoin.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ColorStop> sse_decode_list_color_stop(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ColorStop>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_color_stop(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Glyph> sse_decode_list_glyph(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Glyph>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_glyph(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GraphUpdate> sse_decode_list_graph_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GraphUpdate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_graph_update(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Keyframe> sse_decode_list_keyframe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Keyframe>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_keyframe(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PathElement> sse_decode_list_path_element(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PathElement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_path_element(deserializer)); }
+        return ans_;
+         }
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat64List(len_); }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<SceneCommand> sse_decode_list_scene_command(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SceneCommand>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_scene_command(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Track> sse_decode_list_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Track>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_track(deserializer)); }
+        return ans_;
+         }
+
+@protected LottieInfo sse_decode_lottie_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_animation = sse_decode_u_64(deserializer);
+var var_width = sse_decode_f_64(deserializer);
+var var_height = sse_decode_f_64(deserializer);
+var var_frameRate = sse_decode_f_64(deserializer);
+var var_duration = sse_decode_f_64(deserializer);
+var var_warnings = sse_decode_list_String(deserializer);
+return LottieInfo(animation: var_animation, width: var_width, height: var_height, frameRate: var_frameRate, duration: var_duration, warnings: var_warnings); }
+
+@protected Mix sse_decode_mix(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Mix.values[inner]; }
+
+@protected Frame? sse_decode_opt_box_autoadd_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_frame(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PngCompression? sse_decode_opt_box_autoadd_png_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_png_compression(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Transform? sse_decode_opt_box_autoadd_transform(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_transform(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PathElement sse_decode_path_element(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+return PathElement_MoveTo(x: var_x, y: var_y);case 1: var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+return PathElement_LineTo(x: var_x, y: var_y);case 2: var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+return PathElement_QuadTo(x1: var_x1, y1: var_y1, x: var_x, y: var_y);case 3: var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+var var_x2 = sse_decode_f_64(deserializer);
+var var_y2 = sse_decode_f_64(deserializer);
+var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+return PathElement_CurveTo(x1: var_x1, y1: var_y1, x2: var_x2, y2: var_y2, x: var_x, y: var_y);case 4: return PathElement_Close(); default: throw UnimplementedError(''); }
+             }
+
+@protected PngCompression sse_decode_png_compression(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PngCompression.values[inner]; }
+
+@protected RenderOptions sse_decode_render_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_antialiasing = sse_decode_antialiasing(deserializer);
+var var_background = sse_decode_color(deserializer);
+var var_alphaMode = sse_decode_alpha_mode(deserializer);
+var var_channelOrder = sse_decode_channel_order(deserializer);
+return RenderOptions(antialiasing: var_antialiasing, background: var_background, alphaMode: var_alphaMode, channelOrder: var_channelOrder); }
+
+@protected RenderQueuePolicy sse_decode_render_queue_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RenderQueuePolicy.values[inner]; }
+
+@protected RendererBackend sse_decode_renderer_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RendererBackend.values[inner]; }
+
+@protected RendererConfig sse_decode_renderer_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_backend = sse_decode_renderer_backend(deserializer);
+var var_queuePolicy = sse_decode_render_queue_policy(deserializer);
+var var_antialiasingSupport = sse_decode_antialiasing_support(deserializer);
+return RendererConfig(backend: var_backend, queuePolicy: var_queuePolicy, antialiasingSupport: var_antialiasingSupport); }
+
+@protected RendererError sse_decode_renderer_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return RendererError_AdapterNotFound();case 1: var var_field0 = sse_decode_String(deserializer);
+return RendererError_DeviceCreation(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return RendererError_RendererCreation(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return RendererError_Render(var_field0);case 4: var var_field0 = sse_decode_antialiasing(deserializer);
+return RendererError_UnsupportedAntialiasing(var_field0);case 5: var var_field0 = sse_decode_String(deserializer);
+return RendererError_Unsupported(var_field0);case 6: var var_field0 = sse_decode_String(deserializer);
+return RendererError_Readback(var_field0);case 7: var var_field0 = sse_decode_String(deserializer);
+return RendererError_Encoding(var_field0);case 8: var var_field0 = sse_decode_String(deserializer);
+return RendererError_RendererNotFound(var_field0);case 9: var var_field0 = sse_decode_u_64(deserializer);
+return RendererError_NodeNotFound(var_field0);case 10: var var_field0 = sse_decode_u_64(deserializer);
+return RendererError_NodeExists(var_field0);case 11: var var_field0 = sse_decode_u_64(deserializer);
+return RendererError_NodeCycle(var_field0);case 12: var var_field0 = sse_decode_u_64(deserializer);
+return RendererError_ImageNotFound(var_field0);case 13: var var_field0 = sse_decode_String(deserializer);
+return RendererError_Decoding(var_field0);case 14: var var_field0 = sse_decode_u_64(deserializer);
+return RendererError_FontNotFound(var_field0);case 15: var var_field0 = sse_decode_String(deserializer);
+return RendererError_InvalidFont(var_field0);case 16: var var_field0 = sse_decode_u_32(deserializer);
+return RendererError_InvalidGlyph(var_field0);case 17: var var_field0 = sse_decode_String(deserializer);
+return RendererError_InvalidLayers(var_field0);case 18: var var_field0 = sse_decode_String(deserializer);
+return RendererError_InvalidSvg(var_field0);case 19: var var_field0 = sse_decode_u_64(deserializer);
+return RendererError_LottieNotFound(var_field0);case 20: var var_field0 = sse_decode_String(deserializer);
+return RendererError_InvalidLottie(var_field0);case 21: var var_field0 = sse_decode_String(deserializer);
+return RendererError_InvalidTimeline(var_field0);case 22: return RendererError_TimelineNotSet();case 23: return RendererError_SceneNotSet();case 24: return RendererError_Disconnected(); default: throw UnimplementedError(''); }
+             }
+
+@protected SceneCommand sse_decode_scene_command(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_shape = sse_decode_box_autoadd_shape(deserializer);
+var var_brush = sse_decode_box_autoadd_brush(deserializer);
+var var_fillRule = sse_decode_fill_rule(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+var var_brushTransform = sse_decode_opt_box_autoadd_transform(deserializer);
+return SceneCommand_Fill(shape: var_shape, brush: var_brush, fillRule: var_fillRule, transform: var_transform, brushTransform: var_brushTransform);case 1: var var_shape = sse_decode_box_autoadd_shape(deserializer);
+var var_brush = sse_decode_box_autoadd_brush(deserializer);
+var var_style = sse_decode_box_autoadd_stroke_style(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+var var_brushTransform = sse_decode_opt_box_autoadd_transform(deserializer);
+return SceneCommand_Stroke(shape: var_shape, brush: var_brush, style: var_style, transform: var_transform, brushTransform: var_brushTransform);case 2: var var_image = sse_decode_u_64(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+var var_opacity = sse_decode_f_64(deserializer);
+var var_quality = sse_decode_image_quality(deserializer);
+return SceneCommand_Image(image: var_image, transform: var_transform, opacity: var_opacity, quality: var_quality);case 3: var var_font = sse_decode_u_64(deserializer);
+var var_size = sse_decode_f_64(deserializer);
+var var_glyphs = sse_decode_list_glyph(deserializer);
+var var_brush = sse_decode_box_autoadd_brush(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+return SceneCommand_GlyphRun(font: var_font, size: var_size, glyphs: var_glyphs, brush: var_brush, transform: var_transform);case 4: var var_clip = sse_decode_box_autoadd_shape(deserializer);
+var var_blend = sse_decode_box_autoadd_blend_mode(deserializer);
+var var_alpha = sse_decode_f_64(deserializer);
+var var_transform = sse_decode_box_autoadd_transform(deserializer);
+return SceneCommand_PushLayer(clip: var_clip, blend: var_blend, alpha: var_alpha, transform: var_transform);case 5: return SceneCommand_PopLayer(); default: throw UnimplementedError(''); }
+             }
+
+@protected Shape sse_decode_shape(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_x0 = sse_decode_f_64(deserializer);
+var var_y0 = sse_decode_f_64(deserializer);
+var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+return Shape_Rect(x0: var_x0, y0: var_y0, x1: var_x1, y1: var_y1);case 1: var var_x0 = sse_decode_f_64(deserializer);
+var var_y0 = sse_decode_f_64(deserializer);
+var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+var var_radius = sse_decode_f_64(deserializer);
+return Shape_RoundedRect(x0: var_x0, y0: var_y0, x1: var_x1, y1: var_y1, radius: var_radius);case 2: var var_cx = sse_decode_f_64(deserializer);
+var var_cy = sse_decode_f_64(deserializer);
+var var_radius = sse_decode_f_64(deserializer);
+return Shape_Circle(cx: var_cx, cy: var_cy, radius: var_radius);case 3: var var_cx = sse_decode_f_64(deserializer);
+var var_cy = sse_decode_f_64(deserializer);
+var var_rx = sse_decode_f_64(deserializer);
+var var_ry = sse_decode_f_64(deserializer);
+var var_rotation = sse_decode_f_64(deserializer);
+return Shape_Ellipse(cx: var_cx, cy: var_cy, rx: var_rx, ry: var_ry, rotation: var_rotation);case 4: var var_x0 = sse_decode_f_64(deserializer);
+var var_y0 = sse_decode_f_64(deserializer);
+var var_x1 = sse_decode_f_64(deserializer);
+var var_y1 = sse_decode_f_64(deserializer);
+return Shape_Line(x0: var_x0, y0: var_y0, x1: var_x1, y1: var_y1);case 5: var var_elements = sse_decode_list_path_element(deserializer);
+return Shape_Path(elements: var_elements); default: throw UnimplementedError(''); }
+             }
+
+@protected StrokeStyle sse_decode_stroke_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_f_64(deserializer);
+var var_join = sse_decode_line_join(deserializer);
+var var_miterLimit = sse_decode_f_64(deserializer);
+var var_startCap = sse_decode_line_cap(deserializer);
+var var_endCap = sse_decode_line_cap(deserializer);
+var var_dashPattern = sse_decode_list_prim_f_64_strict(deserializer);
+var var_dashOffset = sse_decode_f_64(deserializer);
+return StrokeStyle(width: var_width, join: var_join, miterLimit: var_miterLimit, startCap: var_startCap, endCap: var_endCap, dashPattern: var_dashPattern, dashOffset: var_dashOffset); }
+
+@protected SvgScene sse_decode_svg_scene(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_commands = sse_decode_list_scene_command(deserializer);
+var var_viewBox = sse_decode_view_box(deserializer);
+var var_warnings = sse_decode_list_String(deserializer);
+return SvgScene(commands: var_commands, viewBox: var_viewBox, warnings: var_warnings); }
+
+@protected TextLayout sse_decode_text_layout(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_glyphs = sse_decode_list_glyph(deserializer);
+var var_width = sse_decode_f_64(deserializer);
+var var_height = sse_decode_f_64(deserializer);
+var var_ascent = sse_decode_f_64(deserializer);
+var var_descent = sse_decode_f_64(deserializer);
+return TextLayout(glyphs: var_glyphs, width: var_width, height: var_height, ascent: var_ascent, descent: var_descent); }
+
+@protected Timeline sse_decode_timeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tracks = sse_decode_list_track(deserializer);
+var var_looping = sse_decode_bool(deserializer);
+return Timeline(tracks: var_tracks, looping: var_looping); }
+
+@protected Track sse_decode_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_node = sse_decode_u_64(deserializer);
+var var_property = sse_decode_animated_property(deserializer);
+var var_keyframes = sse_decode_list_keyframe(deserializer);
+return Track(node: var_node, property: var_property, keyframes: var_keyframes); }
+
+@protected Transform sse_decode_transform(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_xx = sse_decode_f_64(deserializer);
+var var_yx = sse_decode_f_64(deserializer);
+var var_xy = sse_decode_f_64(deserializer);
+var var_yy = sse_decode_f_64(deserializer);
+var var_dx = sse_decode_f_64(deserializer);
+var var_dy = sse_decode_f_64(deserializer);
+return Transform(xx: var_xx, yx: var_yx, xy: var_xy, yy: var_yy, dx: var_dx, dy: var_dy); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected int sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected ViewBox sse_decode_view_box(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_x = sse_decode_f_64(deserializer);
+var var_y = sse_decode_f_64(deserializer);
+var var_width = sse_decode_f_64(deserializer);
+var var_height = sse_decode_f_64(deserializer);
+return ViewBox(x: var_x, y: var_y, width: var_width, height: var_height); }
+
+@protected void sse_encode_StreamSink_frame_Sse(RustStreamSink<Frame> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(decodeSuccessData: sse_decode_frame, decodeErrorData: null)), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_alpha_mode(AlphaMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_animated_property(AnimatedProperty self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case AnimatedProperty_Transform(): sse_encode_i_32(0, serializer); case AnimatedProperty_Opacity(): sse_encode_i_32(1, serializer); case AnimatedProperty_Color(command: final command): sse_encode_i_32(2, serializer); sse_encode_u_32(command, serializer);
+case AnimatedProperty_StrokeWidth(command: final command): sse_encode_i_32(3, serializer); sse_encode_u_32(command, serializer);
+case AnimatedProperty_Path(command: final command): sse_encode_i_32(4, serializer); sse_encode_u_32(command, serializer);
+  } }
+
+@protected void sse_encode_antialiasing(Antialiasing self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_antialiasing_support(AntialiasingSupport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.area, serializer);
+sse_encode_bool(self.msaa8, serializer);
+sse_encode_bool(self.msaa16, serializer);
+ }
+
+@protected void sse_encode_blend_mode(BlendMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_mix(self.mix, serializer);
+sse_encode_compose(self.compose, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_blend_mode(BlendMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blend_mode(self, serializer); }
+
+@protected void sse_encode_box_autoadd_brush(Brush self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_brush(self, serializer); }
+
+@protected void sse_encode_box_autoadd_color(Color self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_color(self, serializer); }
+
+@protected void sse_encode_box_autoadd_frame(Frame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_frame(self, serializer); }
+
+@protected void sse_encode_box_autoadd_png_compression(PngCompression self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_png_compression(self, serializer); }
+
+@protected void sse_encode_box_autoadd_render_options(RenderOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_render_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_renderer_config(RendererConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_renderer_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_shape(Shape self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_shape(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stroke_style(StrokeStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stroke_style(self, serializer); }
+
+@protected void sse_encode_box_autoadd_timeline(Timeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_timeline(self, serializer); }
+
+@protected void sse_encode_box_autoadd_transform(Transform self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_transform(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_brush(Brush self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case Brush_Solid(color: final color): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_color(color, serializer);
+case Brush_LinearGradient(x0: final x0,y0: final y0,x1: final x1,y1: final y1,stops: final stops,extend: final extend): sse_encode_i_32(1, serializer); sse_encode_f_64(x0, serializer);
+sse_encode_f_64(y0, serializer);
+sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+sse_encode_list_color_stop(stops, serializer);
+sse_encode_extend(extend, serializer);
+case Brush_RadialGradient(x0: final x0,y0: final y0,r0: final r0,x1: final x1,y1: final y1,r1: final r1,stops: final stops,extend: final extend): sse_encode_i_32(2, serializer); sse_encode_f_64(x0, serializer);
+sse_encode_f_64(y0, serializer);
+sse_encode_f_64(r0, serializer);
+sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+sse_encode_f_64(r1, serializer);
+sse_encode_list_color_stop(stops, serializer);
+sse_encode_extend(extend, serializer);
+case Brush_SweepGradient(cx: final cx,cy: final cy,startAngle: final startAngle,endAngle: final endAngle,stops: final stops,extend: final extend): sse_encode_i_32(3, serializer); sse_encode_f_64(cx, serializer);
+sse_encode_f_64(cy, serializer);
+sse_encode_f_64(startAngle, serializer);
+sse_encode_f_64(endAngle, serializer);
+sse_encode_list_color_stop(stops, serializer);
+sse_encode_extend(extend, serializer);
+case Brush_Image(image: final image): sse_encode_i_32(4, serializer); sse_encode_u_64(image, serializer);
+  } }
+
+@protected void sse_encode_channel_order(ChannelOrder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_color(Color self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.r, serializer);
+sse_encode_u_8(self.g, serializer);
+sse_encode_u_8(self.b, serializer);
+sse_encode_u_8(self.a, serializer);
+ }
+
+@protected void sse_encode_color_stop(ColorStop self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.offset, serializer);
+sse_encode_color(self.color, serializer);
+ }
+
+@protected void sse_encode_compose(Compose self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_easing(Easing self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case Easing_Hold(): sse_encode_i_32(0, serializer); case Easing_Linear(): sse_encode_i_32(1, serializer); case Easing_CubicBezier(x1: final x1,y1: final y1,x2: final x2,y2: final y2): sse_encode_i_32(2, serializer); sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+sse_encode_f_64(x2, serializer);
+sse_encode_f_64(y2, serializer);
+case Easing_Spring(stiffness: final stiffness,damping: final damping,mass: final mass): sse_encode_i_32(3, serializer); sse_encode_f_64(stiffness, serializer);
+sse_encode_f_64(damping, serializer);
+sse_encode_f_64(mass, serializer);
+  } }
+
+@protected void sse_encode_extend(Extend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_fill_rule(FillRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_frame(Frame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_channel_order(self.channelOrder, serializer);
+sse_encode_alpha_mode(self.alphaMode, serializer);
+sse_encode_u_32(self.skippedFrames, serializer);
+sse_encode_u_64(self.frameNumber, serializer);
+sse_encode_u_64(self.timestampMicros, serializer);
+ }
+
+@protected void sse_encode_glyph(Glyph self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_f_64(self.x, serializer);
+sse_encode_f_64(self.y, serializer);
+ }
+
+@protected void sse_encode_graph_update(GraphUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case GraphUpdate_Insert(id: final id,parent: final parent,commands: final commands,transform: final transform,zIndex: final zIndex,visible: final visible): sse_encode_i_32(0, serializer); sse_encode_u_64(id, serializer);
+sse_encode_opt_box_autoadd_u_64(parent, serializer);
+sse_encode_list_scene_command(commands, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+sse_encode_i_32(zIndex, serializer);
+sse_encode_bool(visible, serializer);
+case GraphUpdate_SetCommands(id: final id,commands: final commands): sse_encode_i_32(1, serializer); sse_encode_u_64(id, serializer);
+sse_encode_list_scene_command(commands, serializer);
+case GraphUpdate_SetTransform(id: final id,transform: final transform): sse_encode_i_32(2, serializer); sse_encode_u_64(id, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+case GraphUpdate_SetZIndex(id: final id,zIndex: final zIndex): sse_encode_i_32(3, serializer); sse_encode_u_64(id, serializer);
+sse_encode_i_32(zIndex, serializer);
+case GraphUpdate_SetVisible(id: final id,visible: final visible): sse_encode_i_32(4, serializer); sse_encode_u_64(id, serializer);
+sse_encode_bool(visible, serializer);
+case GraphUpdate_Reparent(id: final id,parent: final parent): sse_encode_i_32(5, serializer); sse_encode_u_64(id, serializer);
+sse_encode_opt_box_autoadd_u_64(parent, serializer);
+case GraphUpdate_Remove(id: final id): sse_encode_i_32(6, serializer); sse_encode_u_64(id, serializer);
+case GraphUpdate_Clear(): sse_encode_i_32(7, serializer);   } }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_image_quality(ImageQuality self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_keyframe(Keyframe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.time, serializer);
+sse_encode_keyframe_value(self.value, serializer);
+sse_encode_easing(self.easing, serializer);
+ }
+
+@protected void sse_encode_keyframe_value(KeyframeValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case KeyframeValue_Transform(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_transform(field0, serializer);
+case KeyframeValue_Number(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_64(field0, serializer);
+case KeyframeValue_Color(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_color(field0, serializer);
+case KeyframeValue_Path(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_list_path_element(field0, serializer);
+  } }
+
+@protected void sse_encode_line_cap(LineCap self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_line_join(LineJoin self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_color_stop(List<ColorStop> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_color_stop(item, serializer); } }
+
+@protected void sse_encode_list_glyph(List<Glyph> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_glyph(item, serializer); } }
+
+@protected void sse_encode_list_graph_update(List<GraphUpdate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_graph_update(item, serializer); } }
+
+@protected void sse_encode_list_keyframe(List<Keyframe> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_keyframe(item, serializer); } }
+
+@protected void sse_encode_list_path_element(List<PathElement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_path_element(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat64List(self); }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_scene_command(List<SceneCommand> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_scene_command(item, serializer); } }
+
+@protected void sse_encode_list_track(List<Track> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_track(item, serializer); } }
+
+@protected void sse_encode_lottie_info(LottieInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.animation, serializer);
+sse_encode_f_64(self.width, serializer);
+sse_encode_f_64(self.height, serializer);
+sse_encode_f_64(self.frameRate, serializer);
+sse_encode_f_64(self.duration, serializer);
+sse_encode_list_String(self.warnings, serializer);
+ }
+
+@protected void sse_encode_mix(Mix self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_box_autoadd_frame(Frame? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_frame(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_png_compression(PngCompression? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_png_compression(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_transform(Transform? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_transform(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_path_element(PathElement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PathElement_MoveTo(x: final x,y: final y): sse_encode_i_32(0, serializer); sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+case PathElement_LineTo(x: final x,y: final y): sse_encode_i_32(1, serializer); sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+case PathElement_QuadTo(x1: final x1,y1: final y1,x: final x,y: final y): sse_encode_i_32(2, serializer); sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+case PathElement_CurveTo(x1: final x1,y1: final y1,x2: final x2,y2: final y2,x: final x,y: final y): sse_encode_i_32(3, serializer); sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+sse_encode_f_64(x2, serializer);
+sse_encode_f_64(y2, serializer);
+sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+case PathElement_Close(): sse_encode_i_32(4, serializer);   } }
+
+@protected void sse_encode_png_compression(PngCompression self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_render_options(RenderOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_antialiasing(self.antialiasing, serializer);
+sse_encode_color(self.background, serializer);
+sse_encode_alpha_mode(self.alphaMode, serializer);
+sse_encode_channel_order(self.channelOrder, serializer);
+ }
+
+@protected void sse_encode_render_queue_policy(RenderQueuePolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_renderer_backend(RendererBackend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_renderer_config(RendererConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_renderer_backend(self.backend, serializer);
+sse_encode_render_queue_policy(self.queuePolicy, serializer);
+sse_encode_antialiasing_support(self.antialiasingSupport, serializer);
+ }
+
+@protected void sse_encode_renderer_error(RendererError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case RendererError_AdapterNotFound(): sse_encode_i_32(0, serializer); case RendererError_DeviceCreation(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case RendererError_RendererCreation(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case RendererError_Render(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case RendererError_UnsupportedAntialiasing(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_antialiasing(field0, serializer);
+case RendererError_Unsupported(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_String(field0, serializer);
+case RendererError_Readback(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+case RendererError_Encoding(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+case RendererError_RendererNotFound(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_String(field0, serializer);
+case RendererError_NodeNotFound(field0: final field0): sse_encode_i_32(9, serializer); sse_encode_u_64(field0, serializer);
+case RendererError_NodeExists(field0: final field0): sse_encode_i_32(10, serializer); sse_encode_u_64(field0, serializer);
+case RendererError_NodeCycle(field0: final field0): sse_encode_i_32(11, serializer); sse_encode_u_64(field0, serializer);
+case RendererError_ImageNotFound(field0: final field0): sse_encode_i_32(12, serializer); sse_encode_u_64(field0, serializer);
+case RendererError_Decoding(field0: final field0): sse_encode_i_32(13, serializer); sse_encode_String(field0, serializer);
+case RendererError_FontNotFound(field0: final field0): sse_encode_i_32(14, serializer); sse_encode_u_64(field0, serializer);
+case RendererError_InvalidFont(field0: final field0): sse_encode_i_32(15, serializer); sse_encode_String(field0, serializer);
+case RendererError_InvalidGlyph(field0: final field0): sse_encode_i_32(16, serializer); sse_encode_u_32(field0, serializer);
+case RendererError_InvalidLayers(field0: final field0): sse_encode_i_32(17, serializer); sse_encode_String(field0, serializer);
+case RendererError_InvalidSvg(field0: final field0): sse_encode_i_32(18, serializer); sse_encode_String(field0, serializer);
+case RendererError_LottieNotFound(field0: final field0): sse_encode_i_32(19, serializer); sse_encode_u_64(field0, serializer);
+case RendererError_InvalidLottie(field0: final field0): sse_encode_i_32(20, serializer); sse_encode_String(field0, serializer);
+case RendererError_InvalidTimeline(field0: final field0): sse_encode_i_32(21, serializer); sse_encode_String(field0, serializer);
+case RendererError_TimelineNotSet(): sse_encode_i_32(22, serializer); case RendererError_SceneNotSet(): sse_encode_i_32(23, serializer); case RendererError_Disconnected(): sse_encode_i_32(24, serializer);   } }
+
+@protected void sse_encode_scene_command(SceneCommand self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SceneCommand_Fill(shape: final shape,brush: final brush,fillRule: final fillRule,transform: final transform,brushTransform: final brushTransform): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_shape(shape, serializer);
+sse_encode_box_autoadd_brush(brush, serializer);
+sse_encode_fill_rule(fillRule, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+sse_encode_opt_box_autoadd_transform(brushTransform, serializer);
+case SceneCommand_Stroke(shape: final shape,brush: final brush,style: final style,transform: final transform,brushTransform: final brushTransform): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_shape(shape, serializer);
+sse_encode_box_autoadd_brush(brush, serializer);
+sse_encode_box_autoadd_stroke_style(style, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+sse_encode_opt_box_autoadd_transform(brushTransform, serializer);
+case SceneCommand_Image(image: final image,transform: final transform,opacity: final opacity,quality: final quality): sse_encode_i_32(2, serializer); sse_encode_u_64(image, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+sse_encode_f_64(opacity, serializer);
+sse_encode_image_quality(quality, serializer);
+case SceneCommand_GlyphRun(font: final font,size: final size,glyphs: final glyphs,brush: final brush,transform: final transform): sse_encode_i_32(3, serializer); sse_encode_u_64(font, serializer);
+sse_encode_f_64(size, serializer);
+sse_encode_list_glyph(glyphs, serializer);
+sse_encode_box_autoadd_brush(brush, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+case SceneCommand_PushLayer(clip: final clip,blend: final blend,alpha: final alpha,transform: final transform): sse_encode_i_32(4, serializer); sse_encode_box_autoadd_shape(clip, serializer);
+sse_encode_box_autoadd_blend_mode(blend, serializer);
+sse_encode_f_64(alpha, serializer);
+sse_encode_box_autoadd_transform(transform, serializer);
+case SceneCommand_PopLayer(): sse_encode_i_32(5, serializer);   } }
+
+@protected void sse_encode_shape(Shape self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case Shape_Rect(x0: final x0,y0: final y0,x1: final x1,y1: final y1): sse_encode_i_32(0, serializer); sse_encode_f_64(x0, serializer);
+sse_encode_f_64(y0, serializer);
+sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+case Shape_RoundedRect(x0: final x0,y0: final y0,x1: final x1,y1: final y1,radius: final radius): sse_encode_i_32(1, serializer); sse_encode_f_64(x0, serializer);
+sse_encode_f_64(y0, serializer);
+sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+sse_encode_f_64(radius, serializer);
+case Shape_Circle(cx: final cx,cy: final cy,radius: final radius): sse_encode_i_32(2, serializer); sse_encode_f_64(cx, serializer);
+sse_encode_f_64(cy, serializer);
+sse_encode_f_64(radius, serializer);
+case Shape_Ellipse(cx: final cx,cy: final cy,rx: final rx,ry: final ry,rotation: final rotation): sse_encode_i_32(3, serializer); sse_encode_f_64(cx, serializer);
+sse_encode_f_64(cy, serializer);
+sse_encode_f_64(rx, serializer);
+sse_encode_f_64(ry, serializer);
+sse_encode_f_64(rotation, serializer);
+case Shape_Line(x0: final x0,y0: final y0,x1: final x1,y1: final y1): sse_encode_i_32(4, serializer); sse_encode_f_64(x0, serializer);
+sse_encode_f_64(y0, serializer);
+sse_encode_f_64(x1, serializer);
+sse_encode_f_64(y1, serializer);
+case Shape_Path(elements: final elements): sse_encode_i_32(5, serializer); sse_encode_list_path_element(elements, serializer);
+  } }
+
+@protected void sse_encode_stroke_style(StrokeStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.width, serializer);
+sse_encode_line_join(self.join, serializer);
+sse_encode_f_64(self.miterLimit, serializer);
+sse_encode_line_cap(self.startCap, serializer);
+sse_encode_line_cap(self.endCap, serializer);
+sse_encode_list_prim_f_64_strict(self.dashPattern, serializer);
+sse_encode_f_64(self.dashOffset, serializer);
+ }
+
+@protected void sse_encode_svg_scene(SvgScene self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_scene_command(self.commands, serializer);
+sse_encode_view_box(self.viewBox, serializer);
+sse_encode_list_String(self.warnings, serializer);
+ }
+
+@protected void sse_encode_text_layout(TextLayout self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_glyph(self.glyphs, serializer);
+sse_encode_f_64(self.width, serializer);
+sse_encode_f_64(self.height, serializer);
+sse_encode_f_64(self.ascent, serializer);
+sse_encode_f_64(self.descent, serializer);
+ }
+
+@protected void sse_encode_timeline(Timeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_track(self.tracks, serializer);
+sse_encode_bool(self.looping, serializer);
+ }
+
+@protected void sse_encode_track(Track self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.node, serializer);
+sse_encode_animated_property(self.property, serializer);
+sse_encode_list_keyframe(self.keyframes, serializer);
+ }
+
+@protected void sse_encode_transform(Transform self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.xx, serializer);
+sse_encode_f_64(self.yx, serializer);
+sse_encode_f_64(self.xy, serializer);
+sse_encode_f_64(self.yy, serializer);
+sse_encode_f_64(self.dx, serializer);
+sse_encode_f_64(self.dy, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_view_box(ViewBox self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.x, serializer);
+sse_encode_f_64(self.y, serializer);
+sse_encode_f_64(self.width, serializer);
+sse_encode_f_64(self.height, serializer);
+ }
+                }
+                
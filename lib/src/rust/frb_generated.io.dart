@@ -3,91 +3,522 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/animation.dart';
+import 'api/error.dart';
+import 'api/export.dart';
+import 'api/graph.dart';
+import 'api/image.dart';
+import 'api/lottie.dart';
+import 'api/scene.dart';
 import 'api/simple.dart';
+import 'api/svg.dart';
+import 'api/text.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+                  
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+                  @protected RustStreamSink<Frame> dco_decode_StreamSink_frame_Sse(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+@protected AlphaMode dco_decode_alpha_mode(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected AnimatedProperty dco_decode_animated_property(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected Antialiasing dco_decode_antialiasing(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected AntialiasingSupport dco_decode_antialiasing_support(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected BlendMode dco_decode_blend_mode(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected BlendMode dco_decode_box_autoadd_blend_mode(dynamic raw);
 
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+@protected Brush dco_decode_box_autoadd_brush(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected Color dco_decode_box_autoadd_color(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected Frame dco_decode_box_autoadd_frame(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected PngCompression dco_decode_box_autoadd_png_compression(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected RenderOptions dco_decode_box_autoadd_render_options(dynamic raw);
+
+@protected RendererConfig dco_decode_box_autoadd_renderer_config(dynamic raw);
+
+@protected Shape dco_decode_box_autoadd_shape(dynamic raw);
+
+@protected StrokeStyle dco_decode_box_autoadd_stroke_style(dynamic raw);
+
+@protected Timeline dco_decode_box_autoadd_timeline(dynamic raw);
+
+@protected Transform dco_decode_box_autoadd_transform(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected Brush dco_decode_brush(dynamic raw);
+
+@protected ChannelOrder dco_decode_channel_order(dynamic raw);
+
+@protected Color dco_decode_color(dynamic raw);
+
+@protected ColorStop dco_decode_color_stop(dynamic raw);
+
+@protected Compose dco_decode_compose(dynamic raw);
+
+@protected Easing dco_decode_easing(dynamic raw);
+
+@protected Extend dco_decode_extend(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FillRule dco_decode_fill_rule(dynamic raw);
+
+@protected Frame dco_decode_frame(dynamic raw);
+
+@protected Glyph dco_decode_glyph(dynamic raw);
+
+@protected GraphUpdate dco_decode_graph_update(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected ImageQuality dco_decode_image_quality(dynamic raw);
+
+@protected Keyframe dco_decode_keyframe(dynamic raw);
+
+@protected KeyframeValue dco_decode_keyframe_value(dynamic raw);
+
+@protected LineCap dco_decode_line_cap(dynamic raw);
+
+@protected LineJoin dco_decode_line_join(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<ColorStop> dco_decode_list_color_stop(dynamic raw);
+
+@protected List<Glyph> dco_decode_list_glyph(dynamic raw);
+
+@protected List<GraphUpdate> dco_decode_list_graph_update(dynamic raw);
+
+@protected List<Keyframe> dco_decode_list_keyframe(dynamic raw);
+
+@protected List<PathElement> dco_decode_list_path_element(dynamic raw);
+
+@protected Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<SceneCommand> dco_decode_list_scene_command(dynamic raw);
+
+@protected List<Track> dco_decode_list_track(dynamic raw);
+
+@protected LottieInfo dco_decode_lottie_info(dynamic raw);
+
+@protected Mix dco_decode_mix(dynamic raw);
+
+@protected Frame? dco_decode_opt_box_autoadd_frame(dynamic raw);
+
+@protected PngCompression? dco_decode_opt_box_autoadd_png_compression(dynamic raw);
+
+@protected Transform? dco_decode_opt_box_autoadd_transform(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected PathElement dco_decode_path_element(dynamic raw);
+
+@protected PngCompression dco_decode_png_compression(dynamic raw);
+
+@protected RenderOptions dco_decode_render_options(dynamic raw);
+
+@protected RenderQueuePolicy dco_decode_render_queue_policy(dynamic raw);
+
+@protected RendererBackend dco_decode_renderer_backend(dynamic raw);
+
+@protected RendererConfig dco_decode_renderer_config(dynamic raw);
+
+@protected RendererError dco_decode_renderer_error(dynamic raw);
+
+@protected SceneCommand dco_decode_scene_command(dynamic raw);
+
+@protected Shape dco_decode_shape(dynamic raw);
+
+@protected StrokeStyle dco_decode_stroke_style(dynamic raw);
+
+@protected SvgScene dco_decode_svg_scene(dynamic raw);
+
+@protected TextLayout dco_decode_text_layout(dynamic raw);
+
+@protected Timeline dco_decode_timeline(dynamic raw);
+
+@protected Track dco_decode_track(dynamic raw);
+
+@protected Transform dco_decode_transform(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected int dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected ViewBox dco_decode_view_box(dynamic raw);
+
+@protected RustStreamSink<Frame> sse_decode_StreamSink_frame_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AlphaMode sse_decode_alpha_mode(SseDeserializer deserializer);
+
+@protected AnimatedProperty sse_decode_animated_property(SseDeserializer deserializer);
+
+@protected Antialiasing sse_decode_antialiasing(SseDeserializer deserializer);
+
+@protected AntialiasingSupport sse_decode_antialiasing_support(SseDeserializer deserializer);
+
+@protected BlendMode sse_decode_blend_mode(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected BlendMode sse_decode_box_autoadd_blend_mode(SseDeserializer deserializer);
+
+@protected Brush sse_decode_box_autoadd_brush(SseDeserializer deserializer);
+
+@protected Color sse_decode_box_autoadd_color(SseDeserializer deserializer);
+
+@protected Frame sse_decode_box_autoadd_frame(SseDeserializer deserializer);
+
+@protected PngCompression sse_decode_box_autoadd_png_compression(SseDeserializer deserializer);
+
+@protected RenderOptions sse_decode_box_autoadd_render_options(SseDeserializer deserializer);
+
+@protected RendererConfig sse_decode_box_autoadd_renderer_config(SseDeserializer deserializer);
+
+@protected Shape sse_decode_box_autoadd_shape(SseDeserializer deserializer);
+
+@protected StrokeStyle sse_decode_box_autoadd_stroke_style(SseDeserializer deserializer);
+
+@protected Timeline sse_decode_box_autoadd_timeline(SseDeserializer deserializer);
+
+@protected Transform sse_decode_box_autoadd_transform(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected Brush sse_decode_brush(SseDeserializer deserializer);
+
+@protected ChannelOrder sse_decode_channel_order(SseDeserializer deserializer);
+
+@protected Color sse_decode_color(SseDeserializer deserializer);
+
+@protected ColorStop sse_decode_color_stop(SseDeserializer deserializer);
+
+@protected Compose sse_decode_compose(SseDeserializer deserializer);
+
+@protected Easing sse_decode_easing(SseDeserializer deserializer);
+
+@protected Extend sse_decode_extend(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FillRule sse_decode_fill_rule(SseDeserializer deserializer);
+
+@protected Frame sse_decode_frame(SseDeserializer deserializer);
+
+@protected Glyph sse_decode_glyph(SseDeserializer deserializer);
+
+@protected GraphUpdate sse_decode_graph_update(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected ImageQuality sse_decode_image_quality(SseDeserializer deserializer);
+
+@protected Keyframe sse_decode_keyframe(SseDeserializer deserializer);
+
+@protected KeyframeValue sse_decode_keyframe_value(SseDeserializer deserializer);
+
+@protected LineCap sse_decode_line_cap(SseDeserializer deserializer);
+
+@protected LineJoin sse_decode_line_join(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<ColorStop> sse_decode_list_color_stop(SseDeserializer deserializer);
+
+@protected List<Glyph> sse_decode_list_glyph(SseDeserializer deserializer);
+
+@protected List<GraphUpdate> sse_decode_list_graph_update(SseDeserializer deserializer);
+
+@protected List<Keyframe> sse_decode_list_keyframe(SseDeserializer deserializer);
+
+@protected List<PathElement> sse_decode_list_path_element(SseDeserializer deserializer);
+
+@protected Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<SceneCommand> sse_decode_list_scene_command(SseDeserializer deserializer);
+
+@protected List<Track> sse_decode_list_track(SseDeserializer deserializer);
+
+@protected LottieInfo sse_decode_lottie_info(SseDeserializer deserializer);
+
+@protected Mix sse_decode_mix(SseDeserializer deserializer);
+
+@protected Frame? sse_decode_opt_box_autoadd_frame(SseDeserializer deserializer);
+
+@protected PngCompression? sse_decode_opt_box_autoadd_png_compression(SseDeserializer deserializer);
+
+@protected Transform? sse_decode_opt_box_autoadd_transform(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PathElement sse_decode_path_element(SseDeserializer deserializer);
+
+@protected PngCompression sse_decode_png_compression(SseDeserializer deserializer);
+
+@protected RenderOptions sse_decode_render_options(SseDeserializer deserializer);
+
+@protected RenderQueuePolicy sse_decode_render_queue_policy(SseDeserializer deserializer);
+
+@protected RendererBackend sse_decode_renderer_backend(SseDeserializer deserializer);
+
+@protected RendererConfig sse_decode_renderer_config(SseDeserializer deserializer);
+
+@protected RendererError sse_decode_renderer_error(SseDeserializer deserializer);
+
+@protected SceneCommand sse_decode_scene_command(SseDeserializer deserializer);
+
+@protected Shape sse_decode_shape(SseDeserializer deserializer);
+
+@protected StrokeStyle sse_decode_stroke_style(SseDeserializer deserializer);
+
+@protected SvgScene sse_decode_svg_scene(SseDeserializer deserializer);
+
+@protected TextLayout sse_decode_text_layout(SseDeserializer deserializer);
+
+@protected Timeline sse_decode_timeline(SseDeserializer deserializer);
+
+@protected Track sse_decode_track(SseDeserializer deserializer);
+
+@protected Transform sse_decode_transform(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected int sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected ViewBox sse_decode_view_box(SseDeserializer deserializer);
+
+@protected void sse_encode_StreamSink_frame_Sse(RustStreamSink<Frame> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_alpha_mode(AlphaMode self, SseSerializer serializer);
+
+@protected void sse_encode_animated_property(AnimatedProperty self, SseSerializer serializer);
+
+@protected void sse_encode_antialiasing(Antialiasing self, SseSerializer serializer);
+
+@protected void sse_encode_antialiasing_support(AntialiasingSupport self, SseSerializer serializer);
+
+@protected void sse_encode_blend_mode(BlendMode self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blend_mode(BlendMode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_brush(Brush self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_color(Color self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_frame(Frame self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_png_compression(PngCompression self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_render_options(RenderOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_renderer_config(RendererConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_shape(Shape self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stroke_style(StrokeStyle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_timeline(Timeline self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_transform(Transform self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(int self, SseSerializer serializer);
+
+@protected void sse_encode_brush(Brush self, SseSerializer serializer);
+
+@protected void sse_encode_channel_order(ChannelOrder self, SseSerializer serializer);
+
+@protected void sse_encode_color(Color self, SseSerializer serializer);
+
+@protected void sse_encode_color_stop(ColorStop self, SseSerializer serializer);
+
+@protected void sse_encode_compose(Compose self, SseSerializer serializer);
+
+@protected void sse_encode_easing(Easing self, SseSerializer serializer);
+
+@protected void sse_encode_extend(Extend self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_fill_rule(FillRule self, SseSerializer serializer);
+
+@protected void sse_encode_frame(Frame self, SseSerializer serializer);
+
+@protected void sse_encode_glyph(Glyph self, SseSerializer serializer);
+
+@protected void sse_encode_graph_update(GraphUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_image_quality(ImageQuality self, SseSerializer serializer);
+
+@protected void sse_encode_keyframe(Keyframe self, SseSerializer serializer);
+
+@protected void sse_encode_keyframe_value(KeyframeValue self, SseSerializer serializer);
+
+@protected void sse_encode_line_cap(LineCap self, SseSerializer serializer);
+
+@protected void sse_encode_line_join(LineJoin self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_color_stop(List<ColorStop> self, SseSerializer serializer);
+
+@protected void sse_encode_list_glyph(List<Glyph> self, SseSerializer serializer);
+
+@protected void sse_encode_list_graph_update(List<GraphUpdate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_keyframe(List<Keyframe> self, SseSerializer serializer);
+
+@protected void sse_encode_list_path_element(List<PathElement> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_64_strict(Float64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_scene_command(List<SceneCommand> self, SseSerializer serializer);
+
+@protected void sse_encode_list_track(List<Track> self, SseSerializer serializer);
+
+@protected void sse_encode_lottie_info(LottieInfo self, SseSerializer serializer);
+
+@protected void sse_encode_mix(Mix self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_frame(Frame? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_png_compression(PngCompression? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_transform(Transform? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_path_element(PathElement self, SseSerializer serializer);
+
+@protected void sse_encode_png_compression(PngCompression self, SseSerializer serializer);
+
+@protected void sse_encode_render_options(RenderOptions self, SseSerializer serializer);
+
+@protected void sse_encode_render_queue_policy(RenderQueuePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_renderer_backend(RendererBackend self, SseSerializer serializer);
+
+@protected void sse_encode_renderer_config(RendererConfig self, SseSerializer serializer);
+
+@protected void sse_encode_renderer_error(RendererError self, SseSerializer serializer);
+
+@protected void sse_encode_scene_command(SceneCommand self, SseSerializer serializer);
+
+@protected void sse_encode_shape(Shape self, SseSerializer serializer);
+
+@protected void sse_encode_stroke_style(StrokeStyle self, SseSerializer serializer);
+
+@protected void sse_encode_svg_scene(SvgScene self, SseSerializer serializer);
+
+@protected void sse_encode_text_layout(TextLayout self, SseSerializer serializer);
+
+@protected void sse_encode_timeline(Timeline self, SseSerializer serializer);
+
+@protected void sse_encode_track(Track self, SseSerializer serializer);
+
+@protected void sse_encode_transform(Transform self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_view_box(ViewBox self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-}
+            
+        }
+        
\ No newline at end of file
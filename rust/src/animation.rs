@@ -0,0 +1,464 @@
+//! The render thread's side of `api::animation`: the playback clock, and
+//! evaluating tracks into values that override the scene graph's.
+
+use crate::api::animation::AnimatedProperty;
+use crate::api::animation::Easing;
+use crate::api::animation::Keyframe;
+use crate::api::animation::KeyframeValue;
+use crate::api::animation::Timeline;
+use crate::api::error::RendererError;
+use crate::api::scene::identity_transform;
+use crate::api::scene::BlendMode;
+use crate::api::scene::Brush;
+use crate::api::scene::Color;
+use crate::api::scene::Mix;
+use crate::api::scene::PathElement;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Shape;
+use crate::api::scene::Transform;
+use crate::easing::cubic_bezier;
+use crate::easing::spring;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use vello::kurbo::Affine;
+use vello::kurbo::Vec2;
+
+/// Clip of the layer that fades a node, larger than anything it draws.
+const UNCLIPPED: Shape = Shape::Rect {
+    x0: -1e6,
+    y0: -1e6,
+    x1: 1e6,
+    y1: 1e6,
+};
+
+/// Playback requests, see `api::animation`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Playback {
+    Play,
+    Pause,
+    /// To a position in seconds.
+    Seek(f64),
+}
+
+/// Where the timestamps a timeline is evaluated at come from. Timestamps of
+/// different clocks can't be compared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Clock {
+    /// The renderer's, as in `Frame::timestamp_micros`.
+    Renderer,
+    /// The caller's, passed to `render_graph_at`.
+    Caller,
+}
+
+/// A timeline and where it is.
+pub(crate) struct Player {
+    timeline: Timeline,
+    /// Time of the last keyframe of any track.
+    duration: f64,
+    playing: bool,
+    /// Position of the last frame, or where playback was moved to since.
+    position: f64,
+    /// A frame timestamp, its clock and the position at it, set by the first
+    /// frame after playback starts or moves, or switches clocks.
+    anchor: Option<(Clock, u64, f64)>,
+}
+
+/// What a timeline sets on a node at one point in time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct NodeAnimation {
+    pub(crate) transform: Option<Transform>,
+    pub(crate) commands: CommandAnimation,
+}
+
+/// Animated values of a node's commands.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct CommandAnimation {
+    opacity: Option<f64>,
+    /// By command index, here and below.
+    colors: Vec<(u32, Color)>,
+    stroke_widths: Vec<(u32, f64)>,
+    paths: Vec<(u32, Vec<PathElement>)>,
+}
+
+/// Checks what `api::animation::set_timeline` documents.
+pub(crate) fn check(timeline: &Timeline) -> Result<(), RendererError> {
+    for (i, track) in timeline.tracks.iter().enumerate() {
+        let invalid = |problem: &str| {
+            Err(RendererError::InvalidTimeline(format!(
+                "track {i} of node {}: {problem}",
+                track.node
+            )))
+        };
+        let Some(first) = track.keyframes.first() else {
+            return invalid("no keyframes");
+        };
+        let mut previous_time = 0.0;
+        for keyframe in &track.keyframes {
+            if !keyframe.time.is_finite() || keyframe.time < previous_time {
+                return invalid("keyframe times must be finite, positive and in order");
+            }
+            previous_time = keyframe.time;
+            let fits = match (track.property, &keyframe.value) {
+                (AnimatedProperty::Transform, KeyframeValue::Transform(_))
+                | (AnimatedProperty::Opacity, KeyframeValue::Number(_))
+                | (AnimatedProperty::StrokeWidth { .. }, KeyframeValue::Number(_))
+                | (AnimatedProperty::Color { .. }, KeyframeValue::Color(_)) => true,
+                (AnimatedProperty::Path { .. }, KeyframeValue::Path(path)) => {
+                    let KeyframeValue::Path(first_path) = &first.value else {
+                        unreachable!("the first keyframe was checked first");
+                    };
+                    compatible(first_path, path)
+                }
+                _ => false,
+            };
+            if !fits {
+                return invalid("keyframe values don't fit the property or each other");
+            }
+            let easing_valid = match keyframe.easing {
+                Easing::Hold | Easing::Linear => true,
+                Easing::CubicBezier { x1, y1, x2, y2 } => {
+                    [x1, y1, x2, y2].iter().all(|value| value.is_finite())
+                        && (0.0..=1.0).contains(&x1)
+                        && (0.0..=1.0).contains(&x2)
+                }
+                Easing::Spring {
+                    stiffness,
+                    damping,
+                    mass,
+                } => {
+                    [stiffness, damping, mass]
+                        .iter()
+                        .all(|value| value.is_finite())
+                        && stiffness > 0.0
+                        && mass > 0.0
+                        && damping >= 0.0
+                }
+            };
+            if !easing_valid {
+                return invalid("invalid easing parameters");
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Player {
+    /// A player paused at the start of `timeline`, which `check` accepted.
+    pub(crate) fn new(timeline: Timeline) -> Self {
+        let duration = timeline
+            .tracks
+            .iter()
+            .filter_map(|track| track.keyframes.last())
+            .map(|keyframe| keyframe.time)
+            .fold(0.0, f64::max);
+        Self {
+            timeline,
+            duration,
+            playing: false,
+            position: 0.0,
+            anchor: None,
+        }
+    }
+
+    pub(crate) fn control(&mut self, playback: Playback) {
+        match playback {
+            Playback::Play => self.playing = true,
+            Playback::Pause => self.playing = false,
+            Playback::Seek(position) => self.position = self.wrap(position),
+        }
+        self.anchor = None;
+    }
+
+    /// Advances a playing timeline to the frame drawn at `timestamp_micros`
+    /// of `clock` and returns the values of every animated node there.
+    pub(crate) fn evaluate(
+        &mut self,
+        clock: Clock,
+        timestamp_micros: u64,
+    ) -> HashMap<u64, NodeAnimation> {
+        if self.playing {
+            // A frame timed by the other clock continues from the last one.
+            let (_, anchor_timestamp, anchor_position) = match self.anchor {
+                Some(anchor) if anchor.0 == clock => anchor,
+                _ => *self.anchor.insert((clock, timestamp_micros, self.position)),
+            };
+            let elapsed = timestamp_micros.saturating_sub(anchor_timestamp) as f64 / 1e6;
+            self.position = self.wrap(anchor_position + elapsed);
+        }
+
+        let mut nodes: HashMap<u64, NodeAnimation> = HashMap::new();
+        for track in &self.timeline.tracks {
+            let node = nodes.entry(track.node).or_default();
+            let commands = &mut node.commands;
+            match (track.property, value_at(&track.keyframes, self.position)) {
+                (AnimatedProperty::Transform, KeyframeValue::Transform(transform)) => {
+                    node.transform = Some(transform);
+                }
+                (AnimatedProperty::Opacity, KeyframeValue::Number(opacity)) => {
+                    commands.opacity = Some(opacity.clamp(0.0, 1.0));
+                }
+                (AnimatedProperty::Color { command }, KeyframeValue::Color(color)) => {
+                    commands.colors.push((command, color));
+                }
+                (AnimatedProperty::StrokeWidth { command }, KeyframeValue::Number(width)) => {
+                    commands.stroke_widths.push((command, width.max(0.0)));
+                }
+                (AnimatedProperty::Path { command }, KeyframeValue::Path(elements)) => {
+                    commands.paths.push((command, elements));
+                }
+                _ => unreachable!("`check` accepted the timeline"),
+            }
+        }
+        nodes
+    }
+
+    /// Whether frames drawn later show a different position.
+    pub(crate) fn is_animating(&self) -> bool {
+        self.playing
+            && (self.timeline.looping && self.duration > 0.0 || self.position < self.duration)
+    }
+
+    /// Where `position` is on the timeline.
+    fn wrap(&self, position: f64) -> f64 {
+        if self.timeline.looping && self.duration > 0.0 {
+            position.rem_euclid(self.duration)
+        } else {
+            position.clamp(0.0, self.duration)
+        }
+    }
+}
+
+impl CommandAnimation {
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// `commands` with the animated values in place of their own.
+    pub(crate) fn apply(&self, commands: &[SceneCommand]) -> Vec<SceneCommand> {
+        let mut commands = commands.to_vec();
+        for &(index, color) in &self.colors {
+            if let Some(
+                SceneCommand::Fill { brush, .. }
+                | SceneCommand::Stroke { brush, .. }
+                | SceneCommand::GlyphRun { brush, .. },
+            ) = commands.get_mut(index as usize)
+            {
+                *brush = Brush::Solid { color };
+            }
+        }
+        for &(index, width) in &self.stroke_widths {
+            if let Some(SceneCommand::Stroke { style, .. }) = commands.get_mut(index as usize) {
+                style.width = width;
+            }
+        }
+        for (index, elements) in &self.paths {
+            if let Some(SceneCommand::Fill { shape, .. } | SceneCommand::Stroke { shape, .. }) =
+                commands.get_mut(*index as usize)
+            {
+                *shape = Shape::Path {
+                    elements: elements.clone(),
+                };
+            }
+        }
+        // A layer fades overlapping commands as a whole, and image brushes
+        // too.
+        if let Some(opacity) = self.opacity {
+            commands.insert(
+                0,
+                SceneCommand::PushLayer {
+                    clip: UNCLIPPED,
+                    // `Mix::Clip` would skip the alpha inside the clip.
+                    blend: BlendMode {
+                        mix: Mix::Normal,
+                        ..Default::default()
+                    },
+                    alpha: opacity,
+                    transform: identity_transform(),
+                },
+            );
+            commands.push(SceneCommand::PopLayer);
+        }
+        commands
+    }
+}
+
+/// The value of a track with `keyframes` at `position`.
+fn value_at(keyframes: &[Keyframe], position: f64) -> KeyframeValue {
+    let next = keyframes.partition_point(|keyframe| keyframe.time <= position);
+    let (Some(previous), Some(next)) = (next.checked_sub(1), keyframes.get(next)) else {
+        // Before the first or after the last keyframe.
+        let keyframe = if next == 0 {
+            &keyframes[0]
+        } else {
+            &keyframes[keyframes.len() - 1]
+        };
+        return keyframe.value.clone();
+    };
+    let previous = &keyframes[previous];
+    let span = next.time - previous.time;
+    let linear = (position - previous.time) / span;
+    let progress = match next.easing {
+        Easing::Hold => 0.0,
+        Easing::Linear => linear,
+        Easing::CubicBezier { x1, y1, x2, y2 } => cubic_bezier(x1, y1, x2, y2, linear),
+        Easing::Spring {
+            stiffness,
+            damping,
+            mass,
+        } => spring(stiffness, damping, mass, position - previous.time),
+    };
+    interpolate(&previous.value, &next.value, progress)
+}
+
+/// Goes `progress` of the way from `from` to `to`. Progress beyond 0 to 1,
+/// from springs and cubic Béziers that overshoot, extrapolates.
+fn interpolate(from: &KeyframeValue, to: &KeyframeValue, progress: f64) -> KeyframeValue {
+    let lerp = |from: f64, to: f64| from + (to - from) * progress;
+    match (from, to) {
+        (KeyframeValue::Number(from), KeyframeValue::Number(to)) => {
+            KeyframeValue::Number(lerp(*from, *to))
+        }
+        (KeyframeValue::Color(from), KeyframeValue::Color(to)) => {
+            let channel =
+                |from: u8, to: u8| lerp(from.into(), to.into()).round().clamp(0.0, 255.0) as u8;
+            KeyframeValue::Color(Color {
+                r: channel(from.r, to.r),
+                g: channel(from.g, to.g),
+                b: channel(from.b, to.b),
+                a: channel(from.a, to.a),
+            })
+        }
+        (KeyframeValue::Transform(from), KeyframeValue::Transform(to)) => {
+            let (from, to) = (Decomposed::new(*from), Decomposed::new(*to));
+            // Turns the short way around.
+            let turn = (to.rotation - from.rotation + PI).rem_euclid(2.0 * PI) - PI;
+            KeyframeValue::Transform(
+                Decomposed {
+                    translation: from.translation.lerp(to.translation, progress),
+                    rotation: from.rotation + turn * progress,
+                    scale: from.scale.lerp(to.scale, progress),
+                    skew: lerp(from.skew, to.skew),
+                }
+                .compose(),
+            )
+        }
+        (KeyframeValue::Path(from), KeyframeValue::Path(to)) => KeyframeValue::Path(
+            from.iter()
+                .zip(to)
+                .map(|(from, to)| interpolate_element(*from, *to, &lerp))
+                .collect(),
+        ),
+        _ => unreachable!("`check` accepted the timeline"),
+    }
+}
+
+/// Whether paths can be morphed into each other: they need the same kinds of
+/// elements in the same order.
+fn compatible(a: &[PathElement], b: &[PathElement]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| std::mem::discriminant(a) == std::mem::discriminant(b))
+}
+
+fn interpolate_element(
+    from: PathElement,
+    to: PathElement,
+    lerp: &impl Fn(f64, f64) -> f64,
+) -> PathElement {
+    match (from, to) {
+        (PathElement::MoveTo { x, y }, PathElement::MoveTo { x: x_to, y: y_to }) => {
+            PathElement::MoveTo {
+                x: lerp(x, x_to),
+                y: lerp(y, y_to),
+            }
+        }
+        (PathElement::LineTo { x, y }, PathElement::LineTo { x: x_to, y: y_to }) => {
+            PathElement::LineTo {
+                x: lerp(x, x_to),
+                y: lerp(y, y_to),
+            }
+        }
+        (
+            PathElement::QuadTo { x1, y1, x, y },
+            PathElement::QuadTo {
+                x1: x1_to,
+                y1: y1_to,
+                x: x_to,
+                y: y_to,
+            },
+        ) => PathElement::QuadTo {
+            x1: lerp(x1, x1_to),
+            y1: lerp(y1, y1_to),
+            x: lerp(x, x_to),
+            y: lerp(y, y_to),
+        },
+        (
+            PathElement::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            },
+            PathElement::CurveTo {
+                x1: x1_to,
+                y1: y1_to,
+                x2: x2_to,
+                y2: y2_to,
+                x: x_to,
+                y: y_to,
+            },
+        ) => PathElement::CurveTo {
+            x1: lerp(x1, x1_to),
+            y1: lerp(y1, y1_to),
+            x2: lerp(x2, x2_to),
+            y2: lerp(y2, y2_to),
+            x: lerp(x, x_to),
+            y: lerp(y, y_to),
+        },
+        (PathElement::Close, PathElement::Close) => PathElement::Close,
+        _ => unreachable!("`check` accepted the paths as compatible"),
+    }
+}
+
+/// A transform as a translation of a rotation of a skew along x of a scale.
+struct Decomposed {
+    translation: Vec2,
+    /// In radians.
+    rotation: f64,
+    scale: Vec2,
+    /// Added to x for every unit of y, before rotating.
+    skew: f64,
+}
+
+impl Decomposed {
+    fn new(transform: Transform) -> Self {
+        let [xx, yx, xy, yy, dx, dy] = Affine::from(transform).as_coeffs();
+        let scale_x = xx.hypot(yx);
+        let rotation = yx.atan2(xx);
+        let (sin, cos) = rotation.sin_cos();
+        // The second column without the rotation is (skew * scale_y, scale_y).
+        let scale_y = yy * cos - xy * sin;
+        let sheared = xy * cos + yy * sin;
+        Self {
+            translation: Vec2::new(dx, dy),
+            rotation,
+            scale: Vec2::new(scale_x, scale_y),
+            skew: if scale_y == 0.0 {
+                0.0
+            } else {
+                sheared / scale_y
+            },
+        }
+    }
+
+    fn compose(&self) -> Transform {
+        (Affine::translate(self.translation)
+            * Affine::rotate(self.rotation)
+            * Affine::skew(self.skew, 0.0)
+            * Affine::scale_non_uniform(self.scale.x, self.scale.y))
+        .into()
+    }
+}
@@ -0,0 +1,173 @@
+//! Keyframe animation of scene graph nodes, played on the render thread.
+//!
+//! Dart sets a timeline once and then only controls its playback. Every
+//! frame of the scene graph evaluates the timeline at the frame's timestamp
+//! before drawing, so nothing has to be sent per frame.
+
+use crate::animation;
+use crate::animation::Playback;
+use crate::api::error::RendererError;
+use crate::api::scene::Color;
+use crate::api::scene::PathElement;
+use crate::api::scene::Transform;
+use crate::api::simple::send_command;
+use crate::api::simple::Frame;
+use crate::api::simple::RenderOptions;
+use crate::renderer::RenderCommand;
+
+/// How a track moves from one keyframe's value to the next one's.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    /// Keeps the previous value until the keyframe's time.
+    Hold,
+    Linear,
+    /// Like CSS's `cubic-bezier(x1, y1, x2, y2)`. `x1` and `x2` must be
+    /// between 0 and 1.
+    CubicBezier {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+    },
+    /// Follows a spring that is released at the previous keyframe's value,
+    /// and is cut off at this keyframe's time. Springs damped less than
+    /// `2 * sqrt(stiffness * mass)` overshoot.
+    Spring {
+        stiffness: f64,
+        damping: f64,
+        mass: f64,
+    },
+}
+
+/// What a track animates. Animated values replace the node's own until the
+/// timeline is cleared; `update_graph` keeps changing the node's own values
+/// underneath.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimatedProperty {
+    /// The node's transform, interpolated as translation, rotation, scale and
+    /// skew. Takes `KeyframeValue::Transform`s.
+    Transform,
+    /// Multiplies the alpha of everything the node's own commands draw, not
+    /// that of its children. Takes `KeyframeValue::Number`s from 0 to 1.
+    ///
+    /// The commands are drawn in a layer with that alpha, so they can nest
+    /// one layer less.
+    Opacity,
+    /// Paints the fill, stroke or glyph run at index `command` of the node's
+    /// commands in a solid color. Takes `KeyframeValue::Color`s.
+    Color { command: u32 },
+    /// The width of the stroke at index `command` of the node's commands.
+    /// Takes `KeyframeValue::Number`s.
+    StrokeWidth { command: u32 },
+    /// Morphs the shape of the fill or stroke at index `command` of the
+    /// node's commands. Takes `KeyframeValue::Path`s, which must all have the
+    /// same kinds of elements in the same order.
+    Path { command: u32 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeyframeValue {
+    Transform(Transform),
+    Number(f64),
+    Color(Color),
+    Path(Vec<PathElement>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keyframe {
+    /// Seconds from the start of the timeline.
+    pub time: f64,
+    pub value: KeyframeValue,
+    /// How the track gets from the previous keyframe to this one. Unused on
+    /// the first keyframe.
+    pub easing: Easing,
+}
+
+/// The values of one property of one node over time. Before its first
+/// keyframe and after its last one, the track keeps their values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track {
+    /// Tracks of nodes that aren't in the scene graph do nothing.
+    pub node: u64,
+    pub property: AnimatedProperty,
+    /// In increasing order of time.
+    pub keyframes: Vec<Keyframe>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timeline {
+    pub tracks: Vec<Track>,
+    /// Starts over after the last keyframe of any track, instead of staying
+    /// there.
+    pub looping: bool,
+}
+
+/// Sets the timeline animating the scene graph of the renderer registered
+/// under `id`, paused at its start. Replaces the previous timeline.
+///
+/// Fails with `RendererError::InvalidTimeline` if a track has no keyframes,
+/// keyframes out of order, values that don't fit its property or invalid
+/// easing parameters.
+pub async fn set_timeline(id: String, timeline: Timeline) -> Result<(), RendererError> {
+    animation::check(&timeline)?;
+    send_command(
+        id,
+        RenderCommand::SetTimeline {
+            timeline: Some(timeline),
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Removes the timeline, which returns every node to its own values.
+pub async fn clear_timeline(id: String) -> Result<(), RendererError> {
+    send_command(id, RenderCommand::SetTimeline { timeline: None }).await?;
+    Ok(())
+}
+
+/// Plays the timeline from its current position, starting with the next
+/// frame.
+pub async fn play_timeline(id: String) -> Result<(), RendererError> {
+    control_timeline(id, Playback::Play).await
+}
+
+/// Stops the timeline at the position of the last frame drawn.
+pub async fn pause_timeline(id: String) -> Result<(), RendererError> {
+    control_timeline(id, Playback::Pause).await
+}
+
+/// Moves the timeline to `position` seconds from its start. A playing
+/// timeline continues from there with the next frame.
+pub async fn seek_timeline(id: String, position: f64) -> Result<(), RendererError> {
+    control_timeline(id, Playback::Seek(position)).await
+}
+
+async fn control_timeline(id: String, playback: Playback) -> Result<(), RendererError> {
+    send_command(id, RenderCommand::ControlTimeline { playback }).await?;
+    Ok(())
+}
+
+/// Like `render_graph`, but evaluates the timeline at `timestamp_micros`
+/// instead of the renderer's own clock, for example at the vsync time of a
+/// Flutter `Ticker`. Timestamps only need to be consistent with each other.
+/// Mixing frames of both clocks is fine, playback continues from the last
+/// frame whenever the clock changes.
+pub async fn render_graph_at(
+    id: String,
+    timestamp_micros: u64,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<Option<Frame>, RendererError> {
+    send_command(
+        id,
+        RenderCommand::RenderGraph {
+            width,
+            height,
+            options,
+            timestamp_micros: Some(timestamp_micros),
+        },
+    )
+    .await
+}
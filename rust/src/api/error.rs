@@ -0,0 +1,97 @@
+use crate::api::simple::Antialiasing;
+use std::fmt;
+
+/// Errors reported by the renderer API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RendererError {
+    /// No wgpu adapter is available for the requested backend.
+    AdapterNotFound,
+    /// The adapter was found but refused to create a device.
+    DeviceCreation(String),
+    /// vello couldn't build its render pipelines.
+    RendererCreation(String),
+    /// vello or wgpu failed while rendering a frame.
+    Render(String),
+    /// The frame asked for an antialiasing method that the renderer wasn't
+    /// created with.
+    UnsupportedAntialiasing(Antialiasing),
+    /// The frame uses something the renderer's backend can't draw.
+    Unsupported(String),
+    /// The rendered frame couldn't be copied back from the GPU.
+    Readback(String),
+    /// The frame couldn't be encoded into an image file.
+    Encoding(String),
+    /// No renderer is registered under the given id.
+    RendererNotFound(String),
+    /// The scene graph has no node with the given id.
+    NodeNotFound(u64),
+    /// A node with the given id is already in the scene graph.
+    NodeExists(u64),
+    /// The node can't be moved below one of its own descendants.
+    NodeCycle(u64),
+    /// The renderer has no image with the given handle, or it was released.
+    ImageNotFound(u64),
+    /// Uploaded image data couldn't be decoded.
+    Decoding(String),
+    /// No font is registered under the given handle, or it was released.
+    FontNotFound(u64),
+    /// Registered font data isn't a TrueType or OpenType font.
+    InvalidFont(String),
+    /// A glyph run has a glyph id above 65535, which no font can have.
+    InvalidGlyph(u32),
+    /// `PushLayer` and `PopLayer` commands don't pair up, or are nested
+    /// deeper than vello can draw.
+    InvalidLayers(String),
+    /// An SVG document couldn't be parsed.
+    InvalidSvg(String),
+    /// No Lottie animation is loaded under the given handle, or it was
+    /// released.
+    LottieNotFound(u64),
+    /// A Lottie animation couldn't be parsed.
+    InvalidLottie(String),
+    /// A timeline passed to `set_timeline` can't be played.
+    InvalidTimeline(String),
+    /// Timeline playback was controlled before a timeline was set.
+    TimelineNotSet,
+    /// A scene was exported before `set_scene` or `stream_graph` gave the
+    /// renderer a current scene.
+    SceneNotSet,
+    /// The render thread couldn't be started or has stopped.
+    Disconnected,
+}
+
+impl fmt::Display for RendererError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AdapterNotFound => write!(f, "No suitable adapter found"),
+            Self::DeviceCreation(e) => write!(f, "Couldn't create device: {e}"),
+            Self::RendererCreation(e) => write!(f, "Couldn't create renderer: {e}"),
+            Self::Render(e) => write!(f, "Rendering failed: {e}"),
+            Self::UnsupportedAntialiasing(antialiasing) => {
+                write!(f, "Renderer doesn't support {antialiasing:?} antialiasing")
+            }
+            Self::Unsupported(feature) => write!(f, "Not supported: {feature}"),
+            Self::Readback(e) => write!(f, "Failed to read back frame: {e}"),
+            Self::Encoding(e) => write!(f, "Failed to encode frame: {e}"),
+            Self::RendererNotFound(id) => write!(f, "Renderer not found: {id}"),
+            Self::NodeNotFound(id) => write!(f, "Node not found: {id}"),
+            Self::NodeExists(id) => write!(f, "Node already exists: {id}"),
+            Self::NodeCycle(id) => write!(f, "Node {id} can't be a descendant of itself"),
+            Self::ImageNotFound(handle) => write!(f, "Image not found: {handle}"),
+            Self::Decoding(e) => write!(f, "Couldn't decode image: {e}"),
+            Self::FontNotFound(handle) => write!(f, "Font not found: {handle}"),
+            Self::InvalidFont(e) => write!(f, "Invalid font: {e}"),
+            Self::InvalidGlyph(id) => write!(f, "Invalid glyph id: {id}"),
+            Self::InvalidLayers(e) => write!(f, "Invalid layers: {e}"),
+            Self::InvalidSvg(e) => write!(f, "Invalid SVG: {e}"),
+            Self::LottieNotFound(handle) => write!(f, "Lottie animation not found: {handle}"),
+            Self::InvalidLottie(e) => write!(f, "Invalid Lottie animation: {e}"),
+            Self::InvalidTimeline(e) => write!(f, "Invalid timeline: {e}"),
+            Self::TimelineNotSet => write!(f, "No timeline is set"),
+            Self::SceneNotSet => write!(f, "No scene is set"),
+            Self::Disconnected => write!(f, "Renderer connection lost"),
+        }
+    }
+}
+
+impl std::error::Error for RendererError {}
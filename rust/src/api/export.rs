@@ -0,0 +1,84 @@
+//! Encoding scenes and rendered frames into image files.
+
+use crate::api::error::RendererError;
+use crate::api::scene::SceneCommand;
+use crate::api::simple::render_scene;
+use crate::api::simple::send_command;
+use crate::api::simple::AlphaMode;
+use crate::api::simple::ChannelOrder;
+use crate::api::simple::Frame;
+use crate::api::simple::RenderOptions;
+use crate::images;
+use crate::renderer::RenderCommand;
+use futures_intrusive::channel::shared::oneshot_channel;
+
+/// How hard the PNG encoder tries to shrink the file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PngCompression {
+    /// Larger files, encoded quickly. Good for screenshots taken every frame.
+    Fast,
+    #[default]
+    Default,
+    /// Smallest files, encoded slowly. Good for exports that are kept.
+    Best,
+}
+
+/// Encodes `frame` as an 8 bit RGBA PNG. `None` uses
+/// `PngCompression::Default`.
+///
+/// PNG stores `ChannelOrder::Rgba` and `AlphaMode::Straight` pixels, frames
+/// rendered otherwise are converted first. Translucent pixels of
+/// premultiplied frames lose some precision on the way.
+pub fn encode_png(
+    mut frame: Frame,
+    compression: Option<PngCompression>,
+) -> Result<Vec<u8>, RendererError> {
+    images::to_straight_rgba(&mut frame.data, frame.channel_order, frame.alpha_mode);
+    let compression = match compression.unwrap_or_default() {
+        PngCompression::Fast => png::Compression::Fast,
+        PngCompression::Default => png::Compression::Default,
+        PngCompression::Best => png::Compression::Best,
+    };
+    images::encode_png(&frame.data, frame.width, frame.height, compression)
+}
+
+/// Renders `scene` with the renderer registered under `id` and returns it as
+/// a PNG, which unlike `Frame::data` carries its own dimensions.
+///
+/// Returns `None` if the frame was skipped, see `render_scene`.
+pub async fn render_png(
+    id: String,
+    scene: Vec<SceneCommand>,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+    compression: Option<PngCompression>,
+) -> Result<Option<Vec<u8>>, RendererError> {
+    let options = RenderOptions {
+        alpha_mode: AlphaMode::Straight,
+        channel_order: ChannelOrder::Rgba,
+        ..options
+    };
+    let frame = render_scene(id, scene, width, height, options).await?;
+    frame
+        .map(|frame| encode_png(frame, compression))
+        .transpose()
+}
+
+/// Writes the current scene of the renderer registered under `id` as an SVG
+/// document: the scene set with `set_scene`, or the scene graph after
+/// `graph::stream_graph`, at the size and on the background they are
+/// streamed with. Fails with `RendererError::SceneNotSet` before either.
+///
+/// Paths, strokes, gradients and layers stay vectors. Glyph runs become
+/// paths, so the document doesn't depend on the fonts, and images are
+/// embedded as PNGs. Sweep gradients are drawn as wedges, like the renderer
+/// does. Strokes with different caps at their two ends are written as their
+/// filled outlines. Image brushes and layers with a `Compose` other than
+/// `SrcOver` have no SVG equivalent and fail with
+/// `RendererError::Unsupported`.
+pub async fn export_svg(id: String) -> Result<String, RendererError> {
+    let (svg_tx, svg_rx) = oneshot_channel();
+    send_command(id, RenderCommand::ExportSvg { svg: svg_tx }).await?;
+    svg_rx.receive().await.ok_or(RendererError::Disconnected)?
+}
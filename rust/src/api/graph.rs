@@ -0,0 +1,140 @@
+//! Retained scene graph, kept on the render thread between frames.
+//!
+//! Instead of resending the whole scene for every frame, Dart sends the nodes
+//! that changed. Each node's subtree is encoded once and reused until the
+//! subtree changes.
+
+use crate::api::error::RendererError;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Transform;
+use crate::api::simple::send_command;
+use crate::api::simple::Frame;
+use crate::api::simple::RenderOptions;
+use crate::renderer::RenderCommand;
+use futures_intrusive::channel::shared::oneshot_channel;
+
+/// A change to the scene graph. Node ids are chosen by the caller and must be
+/// unique within a renderer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphUpdate {
+    /// Adds a node as the last child of `parent`, or at the top level if
+    /// `parent` is `None`.
+    Insert {
+        id: u64,
+        parent: Option<u64>,
+        /// Drawn in the node's own coordinates, below its children.
+        commands: Vec<SceneCommand>,
+        /// Maps the node's coordinates to its parent's.
+        transform: Transform,
+        /// Siblings are drawn in increasing `z_index`, and in insertion order
+        /// when equal.
+        z_index: i32,
+        /// Hidden nodes are skipped together with their children.
+        visible: bool,
+    },
+    SetCommands {
+        id: u64,
+        commands: Vec<SceneCommand>,
+    },
+    SetTransform {
+        id: u64,
+        transform: Transform,
+    },
+    SetZIndex {
+        id: u64,
+        z_index: i32,
+    },
+    SetVisible {
+        id: u64,
+        visible: bool,
+    },
+    /// Moves a node with its children to the end of `parent`'s children.
+    Reparent {
+        id: u64,
+        parent: Option<u64>,
+    },
+    /// Removes a node together with its children.
+    Remove {
+        id: u64,
+    },
+    /// Removes every node.
+    Clear,
+}
+
+/// Applies `updates` in order to the scene graph of the renderer registered
+/// under `id`.
+///
+/// Stops at the first update that fails, for example because it refers to a
+/// node that doesn't exist. Earlier updates in the batch stay applied.
+pub async fn update_graph(id: String, updates: Vec<GraphUpdate>) -> Result<(), RendererError> {
+    send_command(id, RenderCommand::UpdateGraph { updates }).await?;
+    Ok(())
+}
+
+/// Renders the scene graph of the renderer registered under `id`, animated
+/// by its timeline at the renderer's own clock if one is set.
+///
+/// Returns `None` if the renderer uses `RenderQueuePolicy::LatestWins` and a
+/// newer request arrived before this one was rendered.
+pub async fn render_graph(
+    id: String,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<Option<Frame>, RendererError> {
+    send_command(
+        id,
+        RenderCommand::RenderGraph {
+            width,
+            height,
+            options,
+            timestamp_micros: None,
+        },
+    )
+    .await
+}
+
+/// Makes the renderer registered under `id` stream its scene graph to
+/// `simple::subscribe_frames` instead of the scene set with `simple::set_scene`,
+/// until the next `set_scene`.
+///
+/// A frame is pushed right away, after every `update_graph` and timeline
+/// change, and while a timeline plays, at up to 60 frames per second.
+pub async fn stream_graph(
+    id: String,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<(), RendererError> {
+    send_command(
+        id,
+        RenderCommand::StreamGraph {
+            width,
+            height,
+            options,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Finds the scene graph nodes of the renderer registered under `id` that
+/// paint the point `(x, y)` in scene coordinates, topmost first.
+///
+/// Only a node's own commands count, not its children's, and hidden nodes are
+/// never hit. Fills respect their fill rule and strokes their width, caps,
+/// joins and dashes. Colors are ignored, so fully transparent shapes are hit
+/// too.
+pub async fn hit_test(id: String, x: f64, y: f64) -> Result<Vec<u64>, RendererError> {
+    let (hits_tx, hits_rx) = oneshot_channel();
+    send_command(
+        id,
+        RenderCommand::HitTest {
+            x,
+            y,
+            hits: hits_tx,
+        },
+    )
+    .await?;
+    hits_rx.receive().await.ok_or(RendererError::Disconnected)
+}
@@ -0,0 +1,54 @@
+//! Bitmaps uploaded once and then drawn by handle, with
+//! `SceneCommand::Image` or `Brush::Image`.
+//!
+//! Every renderer has its own images. They stay in memory until
+//! `release_image` is called or the renderer is destroyed.
+
+use crate::api::error::RendererError;
+use crate::api::simple::send_command;
+use crate::images;
+use crate::renderer::RenderCommand;
+use futures_intrusive::channel::shared::oneshot_channel;
+use vello::peniko;
+
+/// Decodes a PNG or JPEG file and adds it to the images of the renderer
+/// registered under `id`. Returns the handle scenes draw it with.
+pub async fn upload_image(id: String, bytes: Vec<u8>) -> Result<u64, RendererError> {
+    let image = images::decode(&bytes)?;
+    register(id, image).await
+}
+
+/// Adds `width` x `height` straight-alpha RGBA pixels, row by row without
+/// padding, to the images of the renderer registered under `id`. Returns the
+/// handle scenes draw them with.
+pub async fn upload_rgba_image(
+    id: String,
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+) -> Result<u64, RendererError> {
+    let image = images::from_rgba(data, width, height)?;
+    register(id, image).await
+}
+
+/// Frees an image of the renderer registered under `id`. Frames drawing it
+/// afterwards fail with `RendererError::ImageNotFound`, except for scene
+/// graph nodes that were encoded before and haven't changed since, which
+/// keep the image alive until they do.
+pub async fn release_image(id: String, image: u64) -> Result<(), RendererError> {
+    send_command(id, RenderCommand::ReleaseImage { image }).await?;
+    Ok(())
+}
+
+async fn register(id: String, image: peniko::Image) -> Result<u64, RendererError> {
+    let (handle_tx, handle_rx) = oneshot_channel();
+    send_command(
+        id,
+        RenderCommand::UploadImage {
+            image,
+            handle: handle_tx,
+        },
+    )
+    .await?;
+    handle_rx.receive().await.ok_or(RendererError::Disconnected)
+}
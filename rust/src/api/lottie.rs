@@ -0,0 +1,85 @@
+//! Playing Lottie animations by evaluating them into scene commands.
+//!
+//! Animations are shared by every renderer and stay in memory until
+//! `release_lottie` is called.
+
+use crate::api::error::RendererError;
+use crate::api::scene::SceneCommand;
+use crate::api::simple::render_scene;
+use crate::api::simple::Frame;
+use crate::api::simple::RenderOptions;
+use crate::lottie;
+use vello::kurbo::Affine;
+
+/// A loaded animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LottieInfo {
+    /// The handle the animation is drawn and released by.
+    pub animation: u64,
+    /// Size of the composition, which its frames are drawn in.
+    pub width: f64,
+    pub height: f64,
+    /// Frames per second.
+    pub frame_rate: f64,
+    /// In seconds.
+    pub duration: f64,
+    /// One message for every feature of the animation that is left out or is
+    /// drawn differently than in After Effects.
+    pub warnings: Vec<String>,
+}
+
+/// Loads a Lottie animation from its JSON.
+///
+/// Shape, solid, null and precomposition layers are drawn, with parenting,
+/// transforms and masks. Shapes can be rects, ellipses, stars, polygons and
+/// paths, painted with solid or gradient fills and strokes and cut with trim
+/// paths. Everything else is left out with a warning.
+pub fn load_lottie(json: String) -> Result<LottieInfo, RendererError> {
+    lottie::load(&json)
+}
+
+/// Frees an animation.
+pub fn release_lottie(animation: u64) -> Result<(), RendererError> {
+    lottie::release(animation)
+}
+
+/// What `load_lottie` returned for an animation.
+pub fn lottie_info(animation: u64) -> Result<LottieInfo, RendererError> {
+    Ok(lottie::get(animation)?.info().clone())
+}
+
+/// The frame of an animation `time` seconds after its start, in the
+/// coordinates of its composition, for example to draw it in a scene graph
+/// node. Times beyond its duration show its last frame.
+///
+/// Masks and layer opacity use layers, and are left out, or turned into the
+/// opacity of every paint, where that would nest layers deeper than vello
+/// can draw.
+pub fn lottie_frame(animation: u64, time: f64) -> Result<Vec<SceneCommand>, RendererError> {
+    Ok(lottie::get(animation)?.frame(time, Affine::IDENTITY))
+}
+
+/// Renders the frame of an animation `time` seconds after its start with the
+/// renderer registered under `id`, scaled to fit and centered in `width` x
+/// `height`.
+///
+/// Returns `None` if the frame was skipped, see `render_scene`.
+pub async fn render_lottie(
+    id: String,
+    animation: u64,
+    time: f64,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<Option<Frame>, RendererError> {
+    let animation = lottie::get(animation)?;
+    let info = animation.info();
+    let (frame_width, frame_height) = (f64::from(width), f64::from(height));
+    let scale = (frame_width / info.width).min(frame_height / info.height);
+    let fit = Affine::translate((
+        (frame_width - info.width * scale) / 2.0,
+        (frame_height - info.height * scale) / 2.0,
+    )) * Affine::scale(scale);
+    let scene = animation.frame(time, fit);
+    render_scene(id, scene, width, height, options).await
+}
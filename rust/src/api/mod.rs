@@ -2,4 +2,13 @@
 // Do not put code in `mod.rs`, but put in e.g. `simple.rs`.
 //
 
+pub mod animation;
+pub mod error;
+pub mod export;
+pub mod graph;
+pub mod image;
+pub mod lottie;
+pub mod scene;
 pub mod simple;
+pub mod svg;
+pub mod text;
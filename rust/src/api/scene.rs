@@ -0,0 +1,503 @@
+//! Scene description that Dart builds and submits for rendering.
+//!
+//! These types only describe what to draw; the render thread translates them
+//! into `vello::Scene` calls. They can also be (de)serialized, which is how
+//! the `render` command line tool reads scene files.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A color with straight (non-premultiplied) 8 bit components.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+/// A 2D affine transform, using the same coefficient order as
+/// `kurbo::Affine`: `x' = xx * x + xy * y + dx`, `y' = yx * x + yy * y + dy`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Transform {
+    pub xx: f64,
+    pub yx: f64,
+    pub xy: f64,
+    pub yy: f64,
+    pub dx: f64,
+    pub dy: f64,
+}
+
+/// A color at a position along a gradient, from 0 at its start to 1 at its
+/// end.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColorStop {
+    pub offset: f64,
+    pub color: Color,
+}
+
+/// How a gradient continues beyond its first and last stop.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Extend {
+    /// Keeps the color of the nearest end.
+    #[default]
+    Pad,
+    Repeat,
+    /// Repeats, mirroring every other repetition.
+    Reflect,
+}
+
+/// What a shape is painted with. Gradient coordinates are in the shape's
+/// coordinate space.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Brush {
+    Solid {
+        color: Color,
+    },
+    /// Varies along the line from `(x0, y0)` to `(x1, y1)`.
+    LinearGradient {
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+        stops: Vec<ColorStop>,
+        #[serde(default)]
+        extend: Extend,
+    },
+    /// Varies from the circle around `(x0, y0)` with radius `r0` to the one
+    /// around `(x1, y1)` with radius `r1`.
+    ///
+    /// Only drawn by `RendererBackend::Gpu` renderers, the `Cpu` backend
+    /// fails frames that use it with `RendererError::Unsupported`.
+    RadialGradient {
+        x0: f64,
+        y0: f64,
+        r0: f64,
+        x1: f64,
+        y1: f64,
+        r1: f64,
+        stops: Vec<ColorStop>,
+        #[serde(default)]
+        extend: Extend,
+    },
+    /// Varies with the angle around `(cx, cy)`, from `start_angle` to
+    /// `end_angle` in radians. Angles grow from the positive x axis towards the
+    /// positive y axis.
+    SweepGradient {
+        cx: f64,
+        cy: f64,
+        start_angle: f64,
+        end_angle: f64,
+        stops: Vec<ColorStop>,
+        #[serde(default)]
+        extend: Extend,
+    },
+    /// An image uploaded with `upload_image`, with its top left corner at
+    /// the origin and one pixel per unit. Transparent beyond its edges.
+    Image {
+        image: u64,
+    },
+}
+
+impl From<Color> for Brush {
+    fn from(color: Color) -> Self {
+        Self::Solid { color }
+    }
+}
+
+/// Geometry that can be filled or stroked.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Shape {
+    Rect {
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+    },
+    RoundedRect {
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+        radius: f64,
+    },
+    Circle {
+        cx: f64,
+        cy: f64,
+        radius: f64,
+    },
+    /// Ellipse with radii `rx`/`ry`, rotated by `rotation` radians.
+    Ellipse {
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+    },
+    Line {
+        x0: f64,
+        y0: f64,
+        x1: f64,
+        y1: f64,
+    },
+    Path {
+        elements: Vec<PathElement>,
+    },
+}
+
+/// One segment of a `Shape::Path`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PathElement {
+    MoveTo {
+        x: f64,
+        y: f64,
+    },
+    LineTo {
+        x: f64,
+        y: f64,
+    },
+    QuadTo {
+        x1: f64,
+        y1: f64,
+        x: f64,
+        y: f64,
+    },
+    CurveTo {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        x: f64,
+        y: f64,
+    },
+    Close,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillRule {
+    #[default]
+    NonZero,
+    EvenOdd,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineJoin {
+    Bevel,
+    Miter,
+    Round,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineCap {
+    Butt,
+    Square,
+    Round,
+}
+
+/// How smoothly scaled images are sampled.
+///
+/// Currently ignored: vello 0.1 samples every image bilinearly, whatever the
+/// quality. It is kept in scenes so that they draw as asked once vello
+/// supports it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageQuality {
+    /// Nearest neighbor.
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+/// How a shape is outlined by `SceneCommand::Stroke`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StrokeStyle {
+    pub width: f64,
+    pub join: LineJoin,
+    pub miter_limit: f64,
+    pub start_cap: LineCap,
+    pub end_cap: LineCap,
+    /// Alternating dash and gap lengths. Empty for a solid line.
+    #[serde(default)]
+    pub dash_pattern: Vec<f64>,
+    #[serde(default)]
+    pub dash_offset: f64,
+}
+
+/// How a layer's colors are mixed with the ones below it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mix {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+    /// Like `Normal`, but lets vello skip the layer wherever it is fully
+    /// inside its clip and opaque.
+    #[default]
+    Clip,
+}
+
+/// Porter-Duff operator combining a layer with the content below it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compose {
+    Clear,
+    Copy,
+    Dest,
+    #[default]
+    SrcOver,
+    DestOver,
+    SrcIn,
+    DestIn,
+    SrcOut,
+    DestOut,
+    SrcAtop,
+    DestAtop,
+    Xor,
+    Plus,
+    PlusLighter,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlendMode {
+    #[serde(default)]
+    pub mix: Mix,
+    #[serde(default)]
+    pub compose: Compose,
+}
+
+/// A glyph of a font, with the position of its origin on the baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Glyph {
+    pub id: u32,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A single drawing operation, applied in order on top of the previous ones.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SceneCommand {
+    Fill {
+        shape: Shape,
+        brush: Brush,
+        #[serde(default)]
+        fill_rule: FillRule,
+        #[serde(default = "identity_transform")]
+        transform: Transform,
+        /// Applied to the brush on top of `transform`.
+        #[serde(default)]
+        brush_transform: Option<Transform>,
+    },
+    Stroke {
+        shape: Shape,
+        brush: Brush,
+        style: StrokeStyle,
+        #[serde(default = "identity_transform")]
+        transform: Transform,
+        /// Applied to the brush on top of `transform`.
+        #[serde(default)]
+        brush_transform: Option<Transform>,
+    },
+    /// Draws an image uploaded with `upload_image`, with its top left corner
+    /// at the origin and one pixel per unit.
+    Image {
+        image: u64,
+        #[serde(default = "identity_transform")]
+        transform: Transform,
+        /// From 0 for invisible to 1 for opaque.
+        #[serde(default = "full_opacity")]
+        opacity: f64,
+        /// Ignored for now, see `ImageQuality`.
+        #[serde(default)]
+        quality: ImageQuality,
+    },
+    /// Fills glyphs of a font registered with `register_font`, for example
+    /// the ones `layout_unshaped_text` placed.
+    GlyphRun {
+        font: u64,
+        /// Pixels per em.
+        size: f64,
+        glyphs: Vec<Glyph>,
+        brush: Brush,
+        #[serde(default = "identity_transform")]
+        transform: Transform,
+    },
+    /// Starts a group of commands that is clipped to `clip` and then blended
+    /// onto what's below as a whole, until the matching `PopLayer`.
+    ///
+    /// Layers must be balanced within a scene or scene graph node. vello
+    /// draws at most four nested layers, including the ones sweep
+    /// gradients, translucent images and glyph runs not painted in a solid
+    /// color use internally.
+    PushLayer {
+        /// Filled with the non-zero rule.
+        clip: Shape,
+        #[serde(default)]
+        blend: BlendMode,
+        /// Opacity of the whole group, from 0 to 1.
+        #[serde(default = "full_opacity")]
+        alpha: f64,
+        /// Applies to `clip` only, not to the commands in the layer.
+        #[serde(default = "identity_transform")]
+        transform: Transform,
+    },
+    PopLayer,
+}
+
+fn full_opacity() -> f64 {
+    1.0
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn identity_transform() -> Transform {
+    Transform {
+        xx: 1.0,
+        yx: 0.0,
+        xy: 0.0,
+        yy: 1.0,
+        dx: 0.0,
+        dy: 0.0,
+    }
+}
+
+/// The shapes the demo used to draw, as a scene description.
+#[flutter_rust_bridge::frb(sync)]
+pub fn demo_scene() -> Vec<SceneCommand> {
+    let identity = identity_transform();
+    let stroke = StrokeStyle {
+        width: 6.0,
+        join: LineJoin::Round,
+        miter_limit: 4.0,
+        start_cap: LineCap::Round,
+        end_cap: LineCap::Round,
+        dash_pattern: vec![],
+        dash_offset: 0.0,
+    };
+    let color = |r, g, b| Color { r, g, b, a: 255 };
+
+    vec![
+        // An outlined rectangle
+        SceneCommand::Stroke {
+            shape: Shape::RoundedRect {
+                x0: 10.0,
+                y0: 10.0,
+                x1: 240.0,
+                y1: 240.0,
+                radius: 20.0,
+            },
+            brush: color(250, 179, 135).into(),
+            style: stroke.clone(),
+            transform: identity,
+            brush_transform: None,
+        },
+        // A filled circle
+        SceneCommand::Fill {
+            shape: Shape::Circle {
+                cx: 420.0,
+                cy: 200.0,
+                radius: 120.0,
+            },
+            brush: color(243, 139, 168).into(),
+            fill_rule: FillRule::NonZero,
+            transform: identity,
+            brush_transform: None,
+        },
+        // A filled ellipse
+        SceneCommand::Fill {
+            shape: Shape::Ellipse {
+                cx: 250.0,
+                cy: 420.0,
+                rx: 100.0,
+                ry: 160.0,
+                rotation: -90.0,
+            },
+            brush: color(203, 166, 247).into(),
+            fill_rule: FillRule::NonZero,
+            transform: identity,
+            brush_transform: None,
+        },
+        // A straight line
+        SceneCommand::Stroke {
+            shape: Shape::Line {
+                x0: 260.0,
+                y0: 20.0,
+                x1: 620.0,
+                y1: 100.0,
+            },
+            brush: color(137, 180, 250).into(),
+            style: stroke.clone(),
+            transform: identity,
+            brush_transform: None,
+        },
+        // A Bezier curve
+        SceneCommand::Stroke {
+            shape: Shape::Path {
+                elements: vec![
+                    PathElement::MoveTo { x: 200.0, y: 300.0 },
+                    PathElement::CurveTo {
+                        x1: 250.0,
+                        y1: 350.0,
+                        x2: 350.0,
+                        y2: 250.0,
+                        x: 400.0,
+                        y: 300.0,
+                    },
+                    PathElement::Close,
+                ],
+            },
+            brush: color(123, 201, 111).into(),
+            style: stroke.clone(),
+            transform: identity,
+            brush_transform: None,
+        },
+        // A path
+        SceneCommand::Stroke {
+            shape: Shape::Path {
+                elements: vec![
+                    PathElement::MoveTo { x: 100.0, y: 100.0 },
+                    PathElement::LineTo { x: 150.0, y: 150.0 },
+                    PathElement::QuadTo {
+                        x1: 200.0,
+                        y1: 100.0,
+                        x: 250.0,
+                        y: 150.0,
+                    },
+                    PathElement::CurveTo {
+                        x1: 300.0,
+                        y1: 200.0,
+                        x2: 350.0,
+                        y2: 150.0,
+                        x: 400.0,
+                        y: 200.0,
+                    },
+                    PathElement::Close,
+                ],
+            },
+            brush: color(213, 61, 79).into(),
+            style: stroke,
+            transform: identity,
+            brush_transform: None,
+        },
+    ]
+}
@@ -1,40 +1,55 @@
-use core::num::NonZeroUsize;
-use pollster::FutureExt;
+use crate::api::error::RendererError;
+use crate::api::scene;
+use crate::api::scene::identity_transform;
+use crate::api::scene::FillRule;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Shape;
+use crate::frb_generated::StreamSink;
+use crate::renderer::RenderCommand;
+use crate::renderer::RenderRequest;
+use crate::renderer::RenderThread;
+use crate::renderer::Subscriber;
+use futures_intrusive::channel::shared::oneshot_channel;
 use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc;
 use std::sync::mpsc::Receiver;
 use std::sync::mpsc::Sender;
 use std::sync::Mutex;
+use std::sync::PoisonError;
 use std::sync::RwLock;
-use std::thread::spawn;
-use vello::block_on_wgpu;
-use vello::kurbo::Affine;
-use vello::kurbo::Circle;
-use vello::kurbo::Ellipse;
-use vello::kurbo::Line;
-use vello::kurbo::RoundedRect;
-use vello::kurbo::Stroke;
-use vello::peniko::Color;
-use vello::util::RenderContext;
-use vello::AaConfig;
-use vello::Renderer;
-use vello::RendererOptions;
-use vello::Scene;
-use wgpu::BufferDescriptor;
-use wgpu::BufferUsages;
-use wgpu::CommandEncoderDescriptor;
-use wgpu::Extent3d;
-use wgpu::ImageCopyBuffer;
-use wgpu::TextureDescriptor;
-use wgpu::TextureFormat;
-use wgpu::TextureUsages;
+use std::sync::RwLockReadGuard;
+use std::sync::RwLockWriteGuard;
+use std::thread;
+use std::thread::JoinHandle;
 
 static mut INITIALIZED: bool = false;
 
+/// Id of the renderer created by `init_app` and used by `test_render`.
+const DEFAULT_RENDERER_ID: &str = "key";
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Held while `create_renderer` creates a device, so that concurrent calls
+/// with the same id create one renderer. Some drivers don't cope with
+/// devices being created concurrently either.
+static CREATING: Mutex<()> = Mutex::new(());
+
 lazy_static::lazy_static!(
     static ref RENDERERS: RwLock<HashMap<String, SimpleContext>> = RwLock::new(HashMap::new());
 );
 
+// A panic while the map is locked must not take every other renderer down
+// with it, so poisoning is ignored. The map itself is never left half-updated.
+fn renderers() -> RwLockReadGuard<'static, HashMap<String, SimpleContext>> {
+    RENDERERS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn renderers_mut() -> RwLockWriteGuard<'static, HashMap<String, SimpleContext>> {
+    RENDERERS.write().unwrap_or_else(PoisonError::into_inner)
+}
+
 #[flutter_rust_bridge::frb(sync)] // Synchronous mode for simplicity of the demo
 pub fn greet(name: String) -> String {
     format!("Hello, {name}!")
@@ -52,305 +67,371 @@ pub fn init_app() {
     // Default utilities - feel free to customize
     flutter_rust_bridge::setup_default_user_utils();
 
-    init_renderer();
+    let id = DEFAULT_RENDERER_ID.to_string();
+    // The demo renders on every hover event, which arrive faster than frames
+    // are drawn, so only the newest one is worth drawing.
+    let config = RendererConfig {
+        backend: RendererBackend::Gpu,
+        queue_policy: RenderQueuePolicy::LatestWins,
+        antialiasing_support: AntialiasingSupport::default(),
+    };
+    if create_renderer(id.clone(), config).is_err() {
+        // Without a usable GPU fall back to software rendering. If that fails
+        // as well, `test_render` reports the renderer as missing.
+        let config = RendererConfig {
+            backend: RendererBackend::Cpu,
+            ..config
+        };
+        let _ = create_renderer(id, config);
+    }
 }
 
-fn init_renderer() {
-    let key: String = "key".to_string();
-
-    {
-        let map = RENDERERS.read().unwrap();
-        if map.contains_key(&key) {
-            let decoder = &map[&key];
-            return;
-        }
+/// Starts a renderer with its own render thread and wgpu device under `id`,
+/// returning once the device is ready. Does nothing if a renderer with that id
+/// already exists.
+pub fn create_renderer(id: String, config: RendererConfig) -> Result<(), RendererError> {
+    let _creating = CREATING.lock().unwrap_or_else(PoisonError::into_inner);
+    if renderers().contains_key(&id) {
+        return Ok(());
     }
 
     let (renderer_request_tx, renderer_request_rx): (
         Sender<RenderRequest>,
         Receiver<RenderRequest>,
     ) = mpsc::channel();
-    let (renderer_response_tx, renderer_response_rx): (
-        Sender<RenderResponse>,
-        Receiver<RenderResponse>,
-    ) = mpsc::channel();
-    let (renderer_info_tx, renderer_info_rx): (Sender<RenderInfo>, Receiver<RenderInfo>) =
-        mpsc::channel();
-
-    let mut render_cx = RenderContext::new().unwrap();
-
-    // let mut renderers: Vec<Option<Renderer>> = vec![];
-
-    // renderers[0]
-    //     .get_or_insert_with(|| renderer);
-
-    let mut scene = Scene::new();
-
-    spawn(move || {
-        let device_id = render_cx.device(None).block_on().unwrap();
-        let device_handle = &mut render_cx.devices[device_id];
-        let device = &device_handle.device;
-        let queue = &device_handle.queue;
-        let renderer = Mutex::new(
-            Renderer::new(
-                device,
-                RendererOptions {
-                    surface_format: None,
-                    use_cpu: false,
-                    antialiasing_support: vello::AaSupport::all(),
-                    num_init_threads: NonZeroUsize::new(1),
-                },
-            )
-            .expect("Couldn't create renderer"),
-        );
-
-        let (width, height) = (800, 600);
-
-        loop {
-            let request = renderer_request_rx.recv().unwrap();
-            let response = match request.command {
-                RenderCommand::Render(pos) => {
-                    scene.reset();
-                    // add_shapes_to_scene(&mut scene);
-                    fn render_rectangle_pos(scene: &mut Scene, pos: &Pos) {
-                        scene.fill(
-                            vello::peniko::Fill::NonZero,
-                            Affine::IDENTITY,
-                            Color::rgb(0.9804, 0.702, 0.5294),
-                            None,
-                            &RoundedRect::new(pos.x, pos.y, 0.0, 0.0, 20.0),
-                        );
-                    }
-                    render_rectangle_pos(&mut scene, &pos);
-                    let size = Extent3d {
-                        width,
-                        height,
-                        depth_or_array_layers: 1,
-                    };
-
-                    let target = device.create_texture(&TextureDescriptor {
-                        label: Some("Target texture"),
-                        size,
-                        mip_level_count: 1,
-                        sample_count: 1,
-                        dimension: wgpu::TextureDimension::D2,
-                        format: TextureFormat::Rgba8Unorm,
-                        usage: TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC,
-                        view_formats: &[],
-                    });
-                    let view = target.create_view(&wgpu::TextureViewDescriptor::default());
-
-                    renderer
-                        .lock()
-                        .unwrap()
-                        .render_to_texture(
-                            device,
-                            queue,
-                            &scene,
-                            &view,
-                            &vello::RenderParams {
-                                base_color: Color::BLACK, // Background color
-                                width,
-                                height,
-                                antialiasing_method: AaConfig::Msaa16,
-                            },
-                        )
-                        .unwrap();
-
-                    fn get_bytes_from_texture(
-                        target: &wgpu::Texture,
-                        device: &wgpu::Device,
-                        queue: &wgpu::Queue,
-                        size: Extent3d,
-                        width: u32,
-                        height: u32,
-                    ) -> Vec<u8> {
-                        let padded_byte_width = (width * 4 + 255) & !255; // Ensure padded_byte_width is a multiple of 256
-                        let buffer_size = padded_byte_width as u64 * height as u64;
-                        let buffer = device.create_buffer(&BufferDescriptor {
-                            label: Some("val"),
-                            size: buffer_size,
-                            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
-                            mapped_at_creation: false,
-                        });
-                        let mut encoder =
-                            device.create_command_encoder(&CommandEncoderDescriptor {
-                                label: Some("Copy out buffer"),
-                            });
-                        encoder.copy_texture_to_buffer(
-                            target.as_image_copy(),
-                            ImageCopyBuffer {
-                                buffer: &buffer,
-                                layout: wgpu::ImageDataLayout {
-                                    offset: 0,
-                                    bytes_per_row: Some(padded_byte_width),
-                                    rows_per_image: None,
-                                },
-                            },
-                            size,
-                        );
-                        queue.submit([encoder.finish()]);
-                        let buf_slice = buffer.slice(..);
-
-                        let (sender, receiver) =
-                            futures_intrusive::channel::shared::oneshot_channel();
-                        buf_slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
-                        if let Some(recv_result) = block_on_wgpu(device, receiver.receive()) {
-                            recv_result.expect("Failed to receive buffer data");
-                        }
-
-                        let data = buf_slice.get_mapped_range();
-                        let mut result_unpadded =
-                            Vec::<u8>::with_capacity((width * height * 4).try_into().unwrap());
-                        for row in 0..height {
-                            let start = (row * padded_byte_width).try_into().unwrap();
-                            result_unpadded.extend(&data[start..start + (width * 4) as usize]);
-                        }
-
-                        result_unpadded
-                    }
-
-                    let data = get_bytes_from_texture(&target, device, queue, size, width, height);
-
-                    RenderResponse { data }
+    let (init_tx, init_rx) = mpsc::channel();
+
+    let thread = thread::Builder::new()
+        .name(format!("vello-renderer-{id}"))
+        .spawn(move || {
+            let render_thread = match RenderThread::new(config) {
+                Ok(render_thread) => render_thread,
+                Err(e) => {
+                    let _ = init_tx.send(Err(e));
+                    return;
                 }
             };
-            match renderer_response_tx.send(response) {
-                Ok(result) => (),
-                Err(e) => panic!("Decoder connection lost. {}", e),
-            };
+            let _ = init_tx.send(Ok(()));
+            render_thread.run(renderer_request_rx);
+        })
+        .map_err(|_| RendererError::Disconnected)?;
+
+    // Creating the device takes a while, seconds on the CPU backend, so the
+    // map is only locked once it's done.
+    init_rx.recv().map_err(|_| RendererError::Disconnected)??;
+
+    renderers_mut().insert(
+        id,
+        SimpleContext {
+            renderer_request_tx,
+            thread,
+        },
+    );
+    Ok(())
+}
+
+/// Stops the renderer registered under `id`, waiting for its render thread to
+/// exit so that its wgpu device is released before this returns.
+pub fn destroy_renderer(id: String) -> Result<(), RendererError> {
+    let context = renderers_mut()
+        .remove(&id)
+        .ok_or(RendererError::RendererNotFound(id))?;
+    let SimpleContext {
+        renderer_request_tx,
+        thread,
+    } = context;
+    drop(renderer_request_tx);
+    thread.join().map_err(|_| RendererError::Disconnected)
+}
+
+/// Renders the pointer demo scene with the renderer created by `init_app`,
+/// returning the pixels of the frame, or `None` if it was skipped.
+pub async fn test_render(
+    x: f32,
+    y: f32,
+    width: u32,
+    height: u32,
+) -> Result<Option<Vec<u8>>, RendererError> {
+    let frame = render(DEFAULT_RENDERER_ID.to_string(), x, y, width, height).await?;
+    Ok(frame.map(|frame| frame.data))
+}
+
+/// Renders the pointer demo scene with the renderer registered under `id`.
+pub async fn render(
+    id: String,
+    x: f32,
+    y: f32,
+    width: u32,
+    height: u32,
+) -> Result<Option<Frame>, RendererError> {
+    let scene = vec![SceneCommand::Fill {
+        shape: Shape::RoundedRect {
+            x0: x.into(),
+            y0: y.into(),
+            x1: 0.0,
+            y1: 0.0,
+            radius: 20.0,
+        },
+        brush: scene::Color {
+            r: 250,
+            g: 179,
+            b: 135,
+            a: 255,
         }
-    });
-
-    {
-        let mut map = RENDERERS.write().unwrap();
-        map.insert(
-            key,
-            SimpleContext {
-                renderer_request_tx: renderer_request_tx,
-                renderer_response_rx: renderer_response_rx,
-            },
-        );
-    }
+        .into(),
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    }];
+    render_scene(id, scene, width, height, RenderOptions::default()).await
+}
+
+/// Renders `scene` with the renderer registered under `id`.
+///
+/// Returns `None` if the renderer uses `RenderQueuePolicy::LatestWins` and a
+/// newer request arrived before this one was rendered.
+pub async fn render_scene(
+    id: String,
+    scene: Vec<SceneCommand>,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<Option<Frame>, RendererError> {
+    send_command(
+        id,
+        RenderCommand::Render {
+            scene,
+            width,
+            height,
+            options,
+        },
+    )
+    .await
 }
 
-pub async fn test_render(x: f32, y: f32) -> Vec<u8> {
-    let key: String = "key".to_string();
+/// Streams the frames of the scene set with `set_scene`, or of the scene
+/// graph after `graph::stream_graph`, from the renderer registered under `id`.
+/// Closing the stream on the Dart side unsubscribes.
+pub async fn subscribe_frames(id: String, sink: StreamSink<Frame>) -> Result<(), RendererError> {
+    let subscriber = Subscriber::Stream(sink);
+    send_command(id, RenderCommand::Subscribe { subscriber }).await?;
+    Ok(())
+}
 
-    let map = RENDERERS.read().unwrap();
-    if !map.contains_key(&key) {
-        panic!("Renderer not found. {}", key);
-    }
+/// Like `subscribe_frames`, but for Rust callers, which have no Dart stream to
+/// hand over. Dropping the receiver unsubscribes.
+#[flutter_rust_bridge::frb(ignore)]
+pub async fn subscribe_frames_to(id: String, sender: Sender<Frame>) -> Result<(), RendererError> {
+    let subscriber = Subscriber::Channel(sender);
+    send_command(id, RenderCommand::Subscribe { subscriber }).await?;
+    Ok(())
+}
 
-    let context = &map[&key];
+/// Replaces the scene of the renderer registered under `id` and pushes a
+/// frame of it to every `subscribe_frames` stream, which stop receiving frames
+/// of the scene graph if `graph::stream_graph` was called. New subscribers get
+/// a frame rendered with the same `options`.
+///
+/// With `RenderQueuePolicy::LatestWins` the frame is skipped if a newer scene
+/// or render request is already queued.
+pub async fn set_scene(
+    id: String,
+    scene: Vec<SceneCommand>,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Result<(), RendererError> {
+    send_command(
+        id,
+        RenderCommand::SetScene {
+            scene,
+            width,
+            height,
+            options,
+        },
+    )
+    .await?;
+    Ok(())
+}
 
-    match context.renderer_request_tx.send(RenderRequest {
-        command: RenderCommand::Render(Pos {
-            x: x.into(),
-            y: y.into(),
-        }),
-    }) {
-        Ok(result) => result,
-        Err(e) => panic!("Renderer connection lost. {}", e),
-    };
-    let result = context
-        .renderer_response_rx
-        .recv()
-        .expect("Failed to receive data");
-    result.data
+pub(crate) async fn send_command(
+    id: String,
+    command: RenderCommand,
+) -> Result<Option<Frame>, RendererError> {
+    // Only hold the map lock long enough to grab a sender, so that other
+    // callers and `destroy_renderer` aren't blocked while this frame renders.
+    let renderer_request_tx = renderers()
+        .get(&id)
+        .map(|context| context.renderer_request_tx.clone())
+        .ok_or(RendererError::RendererNotFound(id))?;
+
+    let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    let (reply_tx, reply_rx) = oneshot_channel();
+    renderer_request_tx
+        .send(RenderRequest {
+            id: request_id,
+            command,
+            reply: reply_tx,
+        })
+        .map_err(|_| RendererError::Disconnected)?;
+    drop(renderer_request_tx);
+
+    let response = reply_rx
+        .receive()
+        .await
+        .ok_or(RendererError::Disconnected)?;
+    debug_assert_eq!(response.request_id, request_id);
+    response.data
 }
 
-fn add_shapes_to_scene(scene: &mut Scene) {
-    // Draw an outlined rectangle
-    let stroke = Stroke::new(6.0);
-    let rect = RoundedRect::new(10.0, 10.0, 240.0, 240.0, 20.0);
-    let rect_stroke_color = Color::rgb(0.9804, 0.702, 0.5294);
-    scene.stroke(&stroke, Affine::IDENTITY, rect_stroke_color, None, &rect);
-
-    // Draw a filled circle
-    let circle = Circle::new((420.0, 200.0), 120.0);
-    let circle_fill_color = Color::rgb(0.9529, 0.5451, 0.6588);
-    scene.fill(
-        vello::peniko::Fill::NonZero,
-        Affine::IDENTITY,
-        circle_fill_color,
-        None,
-        &circle,
-    );
+/// Where a renderer runs vello's pipeline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RendererBackend {
+    /// Hardware adapter, all stages in GPU compute shaders.
+    Gpu,
+    /// Software fallback adapter with vello's CPU shaders, for machines
+    /// without a GPU such as headless CI boxes.
+    ///
+    /// Draws the same pixels as `Gpu`, except that vello 0.1's CPU shaders
+    /// garble radial gradients. Frames with a `Brush::RadialGradient` fail
+    /// with `RendererError::Unsupported` instead.
+    Cpu,
+}
 
-    // Draw a filled ellipse
-    let ellipse = Ellipse::new((250.0, 420.0), (100.0, 160.0), -90.0);
-    let ellipse_fill_color = Color::rgb(0.7961, 0.651, 0.9686);
-    scene.fill(
-        vello::peniko::Fill::NonZero,
-        Affine::IDENTITY,
-        ellipse_fill_color,
-        None,
-        &ellipse,
-    );
+/// Settings a renderer is created with.
+#[derive(Clone, Copy, Debug)]
+pub struct RendererConfig {
+    pub backend: RendererBackend,
+    pub queue_policy: RenderQueuePolicy,
+    /// Which `Antialiasing` methods frames can use. Every enabled method adds
+    /// a pipeline that is compiled when the renderer is created.
+    pub antialiasing_support: AntialiasingSupport,
+}
 
-    // Draw a straight line
-    let line = Line::new((260.0, 20.0), (620.0, 100.0));
-    let line_stroke_color = Color::rgb(0.5373, 0.7059, 0.9804);
-    scene.stroke(&stroke, Affine::IDENTITY, line_stroke_color, None, &line);
-
-    // Draw a Bezier curve
-    let bezier = vello::kurbo::BezPath::from_vec(vec![
-        vello::kurbo::PathEl::MoveTo((200.0, 300.0).into()),
-        vello::kurbo::PathEl::CurveTo(
-            (250.0, 350.0).into(),
-            (350.0, 250.0).into(),
-            (400.0, 300.0).into(),
-        ),
-        vello::kurbo::PathEl::ClosePath,
-    ]);
-    let bezier_stroke_color = Color::rgb(0.4824, 0.7882, 0.4353);
-    scene.stroke(
-        &stroke,
-        Affine::IDENTITY,
-        bezier_stroke_color,
-        None,
-        &bezier,
-    );
+/// What a renderer does with render requests that queue up while it is busy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderQueuePolicy {
+    /// Render every request, in order.
+    Fifo,
+    /// Only render the newest pending request and answer the older ones
+    /// with `None`. Suited to frames driven by pointer input.
+    LatestWins,
+}
 
-    // Draw a path
-    let mut path = vello::kurbo::BezPath::new();
-    path.move_to((100.0, 100.0));
-    path.line_to((150.0, 150.0));
-    path.quad_to((200.0, 100.0), (250.0, 150.0));
-    path.curve_to((300.0, 200.0), (350.0, 150.0), (400.0, 200.0));
-    path.close_path();
-    let path_stroke_color = Color::rgb(0.8353, 0.2392, 0.3098);
-    scene.stroke(&stroke, Affine::IDENTITY, path_stroke_color, None, &path);
+/// How the edges of shapes are anti-aliased.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Antialiasing {
+    /// Analytic area coverage. Fastest, but conflates adjacent edges.
+    Area,
+    /// 8 samples per pixel.
+    Msaa8,
+    /// 16 samples per pixel.
+    Msaa16,
 }
 
-enum RenderCommand {
-    Render(Pos),
+/// A set of `Antialiasing` methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AntialiasingSupport {
+    pub area: bool,
+    pub msaa8: bool,
+    pub msaa16: bool,
 }
 
-struct Pos {
-    pub x: f64,
-    pub y: f64,
+impl AntialiasingSupport {
+    pub(crate) fn supports(self, antialiasing: Antialiasing) -> bool {
+        match antialiasing {
+            Antialiasing::Area => self.area,
+            Antialiasing::Msaa8 => self.msaa8,
+            Antialiasing::Msaa16 => self.msaa16,
+        }
+    }
 }
 
-struct RenderRequest {
-    command: RenderCommand,
+/// Every method.
+impl Default for AntialiasingSupport {
+    fn default() -> Self {
+        Self {
+            area: true,
+            msaa8: true,
+            msaa16: true,
+        }
+    }
 }
 
-struct RenderResponse {
-    data: Vec<u8>,
+/// Settings for a single frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderOptions {
+    pub antialiasing: Antialiasing,
+    /// Color the frame is cleared to before the scene is drawn. Use a zero
+    /// alpha to composite the frame over other widgets.
+    pub background: scene::Color,
+    pub alpha_mode: AlphaMode,
+    pub channel_order: ChannelOrder,
 }
 
-struct SimpleContext {
-    renderer_request_tx: Sender<RenderRequest>,
-    renderer_response_rx: Receiver<RenderResponse>,
+/// Byte order of the pixels in `Frame::data`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelOrder {
+    /// Red, green, blue, alpha. Flutter's `PixelFormat.rgba8888`.
+    Rgba,
+    /// Blue, green, red, alpha. Flutter's `PixelFormat.bgra8888`, and the
+    /// native layout of many platform textures.
+    Bgra,
 }
 
-unsafe impl Send for SimpleContext {}
-unsafe impl Sync for SimpleContext {}
+/// How the color channels of `Frame::data` relate to its alpha channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlphaMode {
+    /// Color channels are independent of alpha, as in PNG files.
+    Straight,
+    /// Color channels are already multiplied by alpha, as most compositors
+    /// expect.
+    Premultiplied,
+}
 
-struct RenderInfo {
-    width: u32,
-    height: u32,
-    data: Vec<u8>,
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            antialiasing: Antialiasing::Msaa16,
+            background: scene::Color {
+                r: 0,
+                g: 0,
+                b: 0,
+                a: 255,
+            },
+            alpha_mode: AlphaMode::Straight,
+            channel_order: ChannelOrder::Rgba,
+        }
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn default_render_options() -> RenderOptions {
+    RenderOptions::default()
+}
+
+/// A rendered frame.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    /// Tightly packed rows of 4 byte pixels, laid out as `channel_order` and
+    /// `alpha_mode` say.
+    pub data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// The `RenderOptions::channel_order` the frame was rendered with.
+    pub channel_order: ChannelOrder,
+    /// The `RenderOptions::alpha_mode` the frame was rendered with.
+    pub alpha_mode: AlphaMode,
+    /// Number of older requests dropped in favour of this frame.
+    pub skipped_frames: u32,
+    /// Counts the frames drawn by a renderer, starting at 0.
+    pub frame_number: u64,
+    /// When the frame was drawn, in microseconds since the renderer was
+    /// created.
+    pub timestamp_micros: u64,
+}
+
+struct SimpleContext {
+    renderer_request_tx: Sender<RenderRequest>,
+    thread: JoinHandle<()>,
 }
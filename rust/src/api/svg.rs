@@ -0,0 +1,36 @@
+//! Importing SVG documents as scene commands.
+
+use crate::api::error::RendererError;
+use crate::api::scene::SceneCommand;
+use crate::svg;
+
+/// The area of an SVG document's coordinates that is meant to be shown.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// An SVG document converted to scene commands.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgScene {
+    /// In the coordinates of `view_box`. Drawing them with a transform, as
+    /// part of a scene or in a scene graph node, places the document.
+    pub commands: Vec<SceneCommand>,
+    /// The document's `viewBox`, or its `width` and `height` if it has none.
+    pub view_box: ViewBox,
+    /// One message for every feature of the document that was left out or is
+    /// drawn differently than SVG specifies.
+    pub warnings: Vec<String>,
+}
+
+/// Converts an SVG document into scene commands.
+///
+/// Paths, basic shapes, transforms, solid and gradient fills and strokes,
+/// and groups with opacity, blend modes or clip paths are supported. Text,
+/// images, patterns, masks and filters are left out with a warning.
+pub fn import_svg(svg: String) -> Result<SvgScene, RendererError> {
+    svg::import(&svg)
+}
@@ -0,0 +1,50 @@
+//! Fonts and text layout for `SceneCommand::GlyphRun`.
+//!
+//! Fonts are shared by every renderer and stay in memory until
+//! `release_font` is called.
+
+use crate::api::error::RendererError;
+use crate::api::scene::Glyph;
+use crate::fonts;
+
+/// Glyphs of a laid out string, in a box whose top left corner is the origin.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLayout {
+    /// Positioned on their baselines.
+    pub glyphs: Vec<Glyph>,
+    /// Advance of the longest line.
+    pub width: f64,
+    /// From the top of the first line to the bottom of the last one.
+    pub height: f64,
+    /// Distance from the top of a line to its baseline.
+    pub ascent: f64,
+    /// Distance from the baseline of a line to its bottom.
+    pub descent: f64,
+}
+
+/// Registers a TrueType or OpenType font. Returns the handle that layouts and
+/// glyph runs use it by.
+pub fn register_font(bytes: Vec<u8>) -> Result<u64, RendererError> {
+    fonts::register(bytes)
+}
+
+/// Frees a font. Frames with glyph runs in it fail with
+/// `RendererError::FontNotFound` afterwards, except for scene graph nodes that
+/// were encoded before and haven't changed since.
+pub fn release_font(font: u64) -> Result<(), RendererError> {
+    fonts::release(font)
+}
+
+/// Lays out `text` in a single font at `size` pixels per em, without shaping.
+///
+/// Each character maps to one glyph through the font's character map, placed
+/// by its advance width. There is no kerning, no ligatures, no bidirectional
+/// text and no line wrapping; every `\n` starts a new line. Scripts that need
+/// shaping, such as Arabic or Devanagari, come out wrong.
+pub fn layout_unshaped_text(
+    font: u64,
+    text: String,
+    size: f64,
+) -> Result<TextLayout, RendererError> {
+    Ok(fonts::layout_unshaped(&fonts::get(font)?, &text, size))
+}
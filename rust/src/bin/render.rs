@@ -0,0 +1,161 @@
+//! Renders a scene file to a PNG, using the same renderer as the Flutter app.
+//!
+//! Scene files are JSON arrays of `SceneCommand`s, for example
+//! `[{"type": "fill", "shape": {"type": "circle", "cx": 50, "cy": 50,
+//! "radius": 40}, "brush": {"type": "solid", "color": {"r": 243, "g": 139,
+//! "b": 168, "a": 255}}}]`.
+
+use anyhow::Context;
+use clap::Parser;
+use clap::ValueEnum;
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::export::encode_png;
+use rust_lib_flutter_vello::api::export::PngCompression;
+use rust_lib_flutter_vello::api::scene::demo_scene;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::simple::create_renderer;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::AlphaMode;
+use rust_lib_flutter_vello::api::simple::Antialiasing;
+use rust_lib_flutter_vello::api::simple::AntialiasingSupport;
+use rust_lib_flutter_vello::api::simple::ChannelOrder;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::simple::RenderQueuePolicy;
+use rust_lib_flutter_vello::api::simple::RendererBackend;
+use rust_lib_flutter_vello::api::simple::RendererConfig;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(about = "Renders a scene file to a PNG")]
+struct Args {
+    /// JSON file with the scene commands. Renders the demo scene if omitted.
+    scene: Option<PathBuf>,
+    /// Where to write the PNG.
+    #[arg(short, long, default_value = "scene.png")]
+    output: PathBuf,
+    #[arg(long, default_value_t = 640)]
+    width: u32,
+    #[arg(long, default_value_t = 600)]
+    height: u32,
+    #[arg(long, value_enum, default_value_t = AntialiasingArg::Msaa16)]
+    antialiasing: AntialiasingArg,
+    /// Background color as `#rrggbb` or `#rrggbbaa`.
+    #[arg(long, default_value = "#000000", value_parser = parse_color)]
+    background: Color,
+    #[arg(long, value_enum, default_value_t = BackendArg::Gpu)]
+    backend: BackendArg,
+    /// PNG compression level, the encoder's default if omitted.
+    #[arg(long, value_enum)]
+    compression: Option<CompressionArg>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AntialiasingArg {
+    Area,
+    Msaa8,
+    Msaa16,
+}
+
+impl From<AntialiasingArg> for Antialiasing {
+    fn from(arg: AntialiasingArg) -> Self {
+        match arg {
+            AntialiasingArg::Area => Antialiasing::Area,
+            AntialiasingArg::Msaa8 => Antialiasing::Msaa8,
+            AntialiasingArg::Msaa16 => Antialiasing::Msaa16,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BackendArg {
+    Gpu,
+    Cpu,
+}
+
+impl From<BackendArg> for RendererBackend {
+    fn from(arg: BackendArg) -> Self {
+        match arg {
+            BackendArg::Gpu => RendererBackend::Gpu,
+            BackendArg::Cpu => RendererBackend::Cpu,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CompressionArg {
+    Fast,
+    Default,
+    Best,
+}
+
+impl From<CompressionArg> for PngCompression {
+    fn from(arg: CompressionArg) -> Self {
+        match arg {
+            CompressionArg::Fast => PngCompression::Fast,
+            CompressionArg::Default => PngCompression::Default,
+            CompressionArg::Best => PngCompression::Best,
+        }
+    }
+}
+
+fn parse_color(s: &str) -> Result<Color, String> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if !matches!(hex.len(), 6 | 8) {
+        return Err("expected 6 or 8 hex digits".to_string());
+    }
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| format!("invalid hex digits in {s}"))
+    };
+    Ok(Color {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+        a: if hex.len() == 8 { channel(6)? } else { 255 },
+    })
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let scene: Vec<SceneCommand> = match &args.scene {
+        Some(path) => {
+            let file =
+                File::open(path).with_context(|| format!("Couldn't open {}", path.display()))?;
+            serde_json::from_reader(BufReader::new(file))
+                .with_context(|| format!("Couldn't parse {}", path.display()))?
+        }
+        None => demo_scene(),
+    };
+
+    let id = "render".to_string();
+    let options = RenderOptions {
+        antialiasing: args.antialiasing.into(),
+        background: args.background,
+        alpha_mode: AlphaMode::Straight,
+        channel_order: ChannelOrder::Rgba,
+    };
+    // Only compile the one fine pipeline this frame needs.
+    let config = RendererConfig {
+        backend: args.backend.into(),
+        queue_policy: RenderQueuePolicy::Fifo,
+        antialiasing_support: AntialiasingSupport {
+            area: options.antialiasing == Antialiasing::Area,
+            msaa8: options.antialiasing == Antialiasing::Msaa8,
+            msaa16: options.antialiasing == Antialiasing::Msaa16,
+        },
+    };
+    create_renderer(id.clone(), config)?;
+    let frame = render_scene(id, scene, args.width, args.height, options)
+        .block_on()?
+        .context("Frame was skipped")?;
+
+    let png = encode_png(frame, args.compression.map(Into::into))?;
+    std::fs::write(&args.output, png)
+        .with_context(|| format!("Couldn't write {}", args.output.display()))?;
+    Ok(())
+}
@@ -0,0 +1,189 @@
+//! Compute pass that converts rendered frames to the requested pixel format
+//! before readback.
+
+use crate::api::simple::AlphaMode;
+use crate::api::simple::ChannelOrder;
+use crate::api::simple::RenderOptions;
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 8;
+
+/// How a frame has to be changed from vello's straight-alpha RGBA output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Conversion {
+    premultiply: bool,
+    bgra: bool,
+}
+
+impl Conversion {
+    /// `None` if the frame can be read back as rendered.
+    pub(crate) fn for_options(options: &RenderOptions) -> Option<Self> {
+        let conversion = Self {
+            premultiply: options.alpha_mode == AlphaMode::Premultiplied,
+            bgra: options.channel_order == ChannelOrder::Bgra,
+        };
+        (conversion.premultiply || conversion.bgra).then_some(conversion)
+    }
+
+    fn flags(self) -> u32 {
+        self.premultiply as u32 | (self.bgra as u32) << 1
+    }
+}
+
+/// The conversion pipeline, created once per renderer.
+pub(crate) struct FormatConverter {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+/// Resources the conversion of one render target writes to.
+pub(crate) struct ConversionBuffers {
+    width: u32,
+    height: u32,
+    padded_byte_width: u32,
+    params: wgpu::Buffer,
+    /// Converted pixels, with the same padded layout as the staging buffers.
+    pub(crate) output: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+impl FormatConverter {
+    pub(crate) fn new(device: &wgpu::Device) -> Self {
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Convert shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/convert.wgsl").into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Convert bind group layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Convert pipeline layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Convert pipeline"),
+            layout: Some(&layout),
+            module: &module,
+            entry_point: "main",
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+        }
+    }
+
+    /// Allocates the buffers for converting frames rendered into `view`.
+    pub(crate) fn create_buffers(
+        &self,
+        device: &wgpu::Device,
+        view: &wgpu::TextureView,
+        width: u32,
+        height: u32,
+        padded_byte_width: u32,
+    ) -> ConversionBuffers {
+        let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Convert params"),
+            contents: &[0; 16],
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let output = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Converted frame"),
+            size: padded_byte_width as u64 * height as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Convert bind group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: output.as_entire_binding(),
+                },
+            ],
+        });
+
+        ConversionBuffers {
+            width,
+            height,
+            padded_byte_width,
+            params,
+            output,
+            bind_group,
+        }
+    }
+
+    /// Records the conversion of the frame in the texture `buffers` were
+    /// created for into `buffers.output`.
+    pub(crate) fn encode(
+        &self,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        buffers: &ConversionBuffers,
+        conversion: Conversion,
+    ) {
+        let ConversionBuffers {
+            width,
+            height,
+            padded_byte_width,
+            ..
+        } = *buffers;
+        let params = [width, height, padded_byte_width / 4, conversion.flags()];
+        let params: Vec<u8> = params.iter().flat_map(|p| p.to_ne_bytes()).collect();
+        queue.write_buffer(&buffers.params, 0, &params);
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Convert pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &buffers.bind_group, &[]);
+        pass.dispatch_workgroups(
+            width.div_ceil(WORKGROUP_SIZE),
+            height.div_ceil(WORKGROUP_SIZE),
+            1,
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Easing curves shared by the animation players.
+
+/// The y of the CSS-style `cubic-bezier(x1, y1, x2, y2)` timing curve at
+/// `x`, which is clamped to [0, 1]. `x1` and `x2` must be in [0, 1] for the
+/// curve to be a function of x; they are clamped as well.
+pub(crate) fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    let x = x.clamp(0.0, 1.0);
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    // Coordinates of B(s) = 3(1-s)^2 s P1 + 3(1-s) s^2 P2 + s^3 as polynomials.
+    let polynomial = |p1: f64, p2: f64| {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        let a = 1.0 - c - b;
+        (a, b, c)
+    };
+    let (ax, bx, cx) = polynomial(x1, x2);
+    let (ay, by, cy) = polynomial(y1, y2);
+    let sample_x = |s: f64| ((ax * s + bx) * s + cx) * s;
+
+    // Newton's method converges in a few steps unless the slope is flat...
+    let mut s = x;
+    for _ in 0..8 {
+        let error = sample_x(s) - x;
+        if error.abs() < 1e-7 {
+            return ((ay * s + by) * s + cy) * s;
+        }
+        let slope = (3.0 * ax * s + 2.0 * bx) * s + cx;
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        s -= error / slope;
+    }
+    // ...where bisection, which x being monotonic allows, takes over.
+    let (mut low, mut high) = (0.0, 1.0);
+    s = x;
+    for _ in 0..64 {
+        let error = sample_x(s) - x;
+        if error.abs() < 1e-7 {
+            break;
+        }
+        if error > 0.0 {
+            high = s;
+        } else {
+            low = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    ((ay * s + by) * s + cy) * s
+}
+
+/// How far a spring released at rest from 0 has moved towards 1 after `time`
+/// seconds. Springs with less than critical damping overshoot 1 and settle
+/// around it. `stiffness` and `mass` must be positive and `damping` must not
+/// be negative.
+pub(crate) fn spring(stiffness: f64, damping: f64, mass: f64, time: f64) -> f64 {
+    let t = time.max(0.0);
+    let natural_frequency = (stiffness / mass).sqrt();
+    let damping_ratio = damping / (2.0 * (stiffness * mass).sqrt());
+    let decay = damping_ratio * natural_frequency;
+    // Distance left to 1, which starts out at 1 without any velocity.
+    let displacement = if (damping_ratio - 1.0).abs() < 1e-9 {
+        (1.0 + natural_frequency * t) * (-natural_frequency * t).exp()
+    } else if damping_ratio < 1.0 {
+        let frequency = natural_frequency * (1.0 - damping_ratio * damping_ratio).sqrt();
+        (-decay * t).exp() * ((frequency * t).cos() + decay / frequency * (frequency * t).sin())
+    } else {
+        let spread = natural_frequency * (damping_ratio * damping_ratio - 1.0).sqrt();
+        let (fast, slow) = (-decay - spread, -decay + spread);
+        (fast * (slow * t).exp() - slow * (fast * t).exp()) / (fast - slow)
+    };
+    1.0 - displacement
+}
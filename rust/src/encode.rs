@@ -0,0 +1,643 @@
+//! Translation of the bridge scene description into `vello::Scene` calls.
+
+use crate::api::error::RendererError;
+use crate::api::scene::BlendMode;
+use crate::api::scene::Brush;
+use crate::api::scene::Color;
+use crate::api::scene::ColorStop;
+use crate::api::scene::Compose;
+use crate::api::scene::Extend;
+use crate::api::scene::FillRule;
+use crate::api::scene::LineCap;
+use crate::api::scene::LineJoin;
+use crate::api::scene::Mix;
+use crate::api::scene::PathElement;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Shape;
+use crate::api::scene::StrokeStyle;
+use crate::api::scene::Transform;
+use crate::api::simple::Antialiasing;
+use crate::api::simple::AntialiasingSupport;
+use crate::fonts;
+use crate::images::ImageRegistry;
+use std::f64::consts::SQRT_2;
+use std::f64::consts::TAU;
+use vello::kurbo;
+use vello::kurbo::Affine;
+use vello::kurbo::BezPath;
+use vello::kurbo::Shape as _;
+use vello::peniko;
+use vello::AaConfig;
+use vello::AaSupport;
+use vello::Scene;
+
+/// Flattening tolerance used when converting curved shapes to paths. Matches
+/// the tolerance vello itself uses for `kurbo::Shape`s.
+pub(crate) const TOLERANCE: f64 = 0.1;
+
+/// Number of solid wedges a full turn of a sweep gradient is split into.
+const SWEEP_WEDGES: usize = 256;
+
+/// Most layers vello's fine shader can nest.
+pub(crate) const MAX_LAYER_DEPTH: usize = 4;
+
+/// Appends `commands` to `scene`, in order. Images are looked up in
+/// `images`.
+pub(crate) fn encode_scene(
+    scene: &mut Scene,
+    commands: &[SceneCommand],
+    images: &ImageRegistry,
+) -> Result<(), RendererError> {
+    // vello would render unbalanced layers into garbage rather than fail.
+    check_layers(commands)?;
+    for command in commands {
+        match command {
+            SceneCommand::Fill {
+                shape,
+                brush,
+                fill_rule,
+                transform,
+                brush_transform,
+            } => {
+                let path = to_path(shape);
+                let bounds = path.bounding_box();
+                let transform = (*transform).into();
+                encode_brush(
+                    scene,
+                    brush,
+                    images,
+                    transform,
+                    brush_transform.map(Into::into),
+                    bounds,
+                    |scene, brush, brush_transform| {
+                        scene.fill(
+                            (*fill_rule).into(),
+                            transform,
+                            brush,
+                            brush_transform,
+                            &path,
+                        );
+                    },
+                )?;
+            }
+            SceneCommand::Stroke {
+                shape,
+                brush,
+                style,
+                transform,
+                brush_transform,
+            } => {
+                let path = to_path(shape);
+                let bounds = stroke_bounds(&path, style);
+                let stroke = style.into();
+                let transform = (*transform).into();
+                encode_brush(
+                    scene,
+                    brush,
+                    images,
+                    transform,
+                    brush_transform.map(Into::into),
+                    bounds,
+                    |scene, brush, brush_transform| {
+                        scene.stroke(&stroke, transform, brush, brush_transform, &path);
+                    },
+                )?;
+            }
+            // vello samples every image bilinearly, so there's nothing to do
+            // with the quality yet.
+            SceneCommand::Image {
+                image,
+                transform,
+                opacity,
+                quality: _,
+            } => {
+                let image = images.get(*image)?;
+                let transform = (*transform).into();
+                if *opacity < 1.0 {
+                    let bounds =
+                        kurbo::Rect::new(0.0, 0.0, image.width.into(), image.height.into());
+                    scene.push_layer(
+                        peniko::Mix::Normal,
+                        opacity.max(0.0) as f32,
+                        transform,
+                        &bounds,
+                    );
+                    scene.draw_image(image, transform);
+                    scene.pop_layer();
+                } else {
+                    scene.draw_image(image, transform);
+                }
+            }
+            SceneCommand::GlyphRun {
+                font,
+                size,
+                glyphs,
+                brush,
+                transform,
+            } => {
+                let font = fonts::get(*font)?;
+                // Outlines may reach past their advance boxes. Also rejects
+                // glyph ids vello would truncate.
+                let bounds = fonts::glyph_boxes(&font, *size, glyphs)?
+                    .into_iter()
+                    .reduce(|bounds, glyph| bounds.union(glyph))
+                    .unwrap_or_default()
+                    .inflate(*size / 2.0, *size / 2.0);
+                let transform = (*transform).into();
+                let draw_glyphs = |scene: &mut Scene, brush: peniko::BrushRef| {
+                    scene
+                        .draw_glyphs(&font)
+                        .font_size(*size as f32)
+                        .transform(transform)
+                        .brush(brush)
+                        .draw(
+                            peniko::Fill::NonZero,
+                            glyphs.iter().map(|glyph| vello::glyph::Glyph {
+                                id: glyph.id,
+                                x: glyph.x as f32,
+                                y: glyph.y as f32,
+                            }),
+                        );
+                };
+                if let Brush::Solid { color } = *brush {
+                    draw_glyphs(scene, peniko::Color::from(color).into());
+                } else {
+                    // vello positions a glyph run's brush relative to each
+                    // glyph, so other brushes paint a rectangle masked by the
+                    // glyphs instead.
+                    scene.push_layer(peniko::Mix::Normal, 1.0, transform, &bounds);
+                    encode_brush(
+                        scene,
+                        brush,
+                        images,
+                        transform,
+                        None,
+                        bounds,
+                        |scene, brush, brush_transform| {
+                            scene.fill(
+                                peniko::Fill::NonZero,
+                                transform,
+                                brush,
+                                brush_transform,
+                                &bounds,
+                            );
+                        },
+                    )?;
+                    scene.push_layer(peniko::Compose::DestIn, 1.0, transform, &bounds);
+                    draw_glyphs(scene, peniko::Color::WHITE.into());
+                    scene.pop_layer();
+                    scene.pop_layer();
+                }
+            }
+            SceneCommand::PushLayer {
+                clip,
+                blend,
+                alpha,
+                transform,
+            } => scene.push_layer(
+                *blend,
+                alpha.clamp(0.0, 1.0) as f32,
+                (*transform).into(),
+                &to_path(clip),
+            ),
+            SceneCommand::PopLayer => scene.pop_layer(),
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every `PushLayer` in `commands` has a matching `PopLayer` and
+/// that layers, together with the ones commands use internally, aren't nested
+/// deeper than vello can draw.
+pub(crate) fn check_layers(commands: &[SceneCommand]) -> Result<(), RendererError> {
+    let mut depth: usize = 0;
+    for command in commands {
+        match command {
+            SceneCommand::PushLayer { .. } => depth += 1,
+            SceneCommand::PopLayer => {
+                depth = depth.checked_sub(1).ok_or_else(|| {
+                    RendererError::InvalidLayers("PopLayer without a PushLayer".to_string())
+                })?;
+            }
+            _ => {}
+        }
+        if depth + internal_layers(command) > MAX_LAYER_DEPTH {
+            return Err(RendererError::InvalidLayers(format!(
+                "more than {MAX_LAYER_DEPTH} nested layers"
+            )));
+        }
+    }
+    match depth {
+        0 => Ok(()),
+        _ => Err(RendererError::InvalidLayers(format!(
+            "{depth} layers are never popped"
+        ))),
+    }
+}
+
+/// How deep the layers `encode_scene` pushes to draw `command` nest.
+fn internal_layers(command: &SceneCommand) -> usize {
+    let brush_layers = |brush: &Brush| match brush {
+        Brush::SweepGradient { .. } => 2,
+        _ => 0,
+    };
+    match command {
+        SceneCommand::Fill { brush, .. } | SceneCommand::Stroke { brush, .. } => {
+            brush_layers(brush)
+        }
+        SceneCommand::Image { opacity, .. } if *opacity < 1.0 => 1,
+        SceneCommand::GlyphRun {
+            brush: Brush::Solid { .. },
+            ..
+        } => 0,
+        // The glyph mask is one layer deep, inside one more.
+        SceneCommand::GlyphRun { brush, .. } => 1 + brush_layers(brush).max(1),
+        _ => 0,
+    }
+}
+
+/// Paints a shape with `brush` by calling `draw` with the matching vello
+/// brush. `bounds` must contain everything `draw` paints, in the shape's
+/// coordinates.
+fn encode_brush(
+    scene: &mut Scene,
+    brush: &Brush,
+    images: &ImageRegistry,
+    transform: Affine,
+    brush_transform: Option<Affine>,
+    bounds: kurbo::Rect,
+    draw: impl Fn(&mut Scene, peniko::BrushRef, Option<Affine>),
+) -> Result<(), RendererError> {
+    match *brush {
+        Brush::Solid { color } => draw(scene, peniko::Color::from(color).into(), None),
+        Brush::LinearGradient {
+            x0,
+            y0,
+            x1,
+            y1,
+            ref stops,
+            extend,
+        } => {
+            let gradient = peniko::Gradient::new_linear((x0, y0), (x1, y1))
+                .with_extend(extend.into())
+                .with_stops(stops.as_slice());
+            draw(scene, (&gradient).into(), brush_transform);
+        }
+        Brush::RadialGradient {
+            x0,
+            y0,
+            r0,
+            x1,
+            y1,
+            r1,
+            ref stops,
+            extend,
+        } => {
+            let gradient =
+                peniko::Gradient::new_two_point_radial((x0, y0), r0 as f32, (x1, y1), r1 as f32)
+                    .with_extend(extend.into())
+                    .with_stops(stops.as_slice());
+            draw(scene, (&gradient).into(), brush_transform);
+        }
+        Brush::SweepGradient {
+            cx,
+            cy,
+            start_angle,
+            end_angle,
+            ref stops,
+            extend,
+        } => {
+            // vello doesn't implement sweep gradients yet. Instead solid wedges
+            // fan out from the center and the shape masks them.
+            let bounds = bounds.inflate(1.0, 1.0);
+            scene.push_layer(peniko::Mix::Normal, 1.0, transform, &bounds);
+
+            let brush_transform = brush_transform.unwrap_or(Affine::IDENTITY);
+            let wedge_transform = transform * brush_transform;
+            let wedges = sweep_wedges(
+                (cx, cy).into(),
+                start_angle,
+                end_angle,
+                stops,
+                extend,
+                brush_transform.inverse().transform_rect_bbox(bounds),
+            );
+            for (wedge, color) in wedges {
+                // Adding up the clip coverage of neighbouring wedges blends
+                // their shared edge without a seam.
+                scene.push_layer(peniko::Compose::Plus, 1.0, wedge_transform, &wedge);
+                scene.fill(
+                    peniko::Fill::NonZero,
+                    wedge_transform,
+                    color,
+                    None,
+                    &wedge.bounding_box().inflate(1.0, 1.0),
+                );
+                scene.pop_layer();
+            }
+
+            scene.push_layer(peniko::Compose::DestIn, 1.0, transform, &bounds);
+            draw(scene, peniko::Color::WHITE.into(), None);
+            scene.pop_layer();
+            scene.pop_layer();
+        }
+        Brush::Image { image } => {
+            draw(scene, images.get(image)?.into(), brush_transform);
+        }
+    }
+    Ok(())
+}
+
+/// Solid wedges that fan out from `center`, in the sweep gradient's
+/// coordinates, and together cover `bounds`.
+pub(crate) fn sweep_wedges(
+    center: kurbo::Point,
+    start_angle: f64,
+    end_angle: f64,
+    stops: &[ColorStop],
+    extend: Extend,
+    bounds: kurbo::Rect,
+) -> Vec<(BezPath, peniko::Color)> {
+    let step = TAU / SWEEP_WEDGES as f64;
+    // Far enough out that the wedges cover the bounds.
+    let radius = bounds
+        .path_elements(TOLERANCE)
+        .filter_map(|element| element.end_point())
+        .map(|corner| corner.distance(center))
+        .fold(0.0, f64::max)
+        / (step / 2.0).cos()
+        + 1.0;
+    (0..SWEEP_WEDGES)
+        .map(|i| {
+            let angle = i as f64 * step;
+            let mut wedge = BezPath::new();
+            wedge.move_to(center);
+            wedge.line_to(center + kurbo::Vec2::from_angle(angle) * radius);
+            wedge.line_to(center + kurbo::Vec2::from_angle(angle + step) * radius);
+            wedge.close_path();
+            let offset = sweep_offset(angle + step / 2.0, start_angle, end_angle);
+            (wedge, sample_stops(stops, extend, offset))
+        })
+        .collect()
+}
+
+/// Position of `angle` along a sweep gradient, before extending.
+fn sweep_offset(angle: f64, start_angle: f64, end_angle: f64) -> f64 {
+    let span = end_angle - start_angle;
+    if span.abs() < f64::EPSILON {
+        return if angle < start_angle { 0.0 } else { 1.0 };
+    }
+    (angle - start_angle) / span
+}
+
+/// The color of a gradient at `offset`, interpolating straight components.
+fn sample_stops(stops: &[ColorStop], extend: Extend, offset: f64) -> peniko::Color {
+    let offset = match extend {
+        Extend::Pad => offset.clamp(0.0, 1.0),
+        Extend::Repeat => offset.rem_euclid(1.0),
+        Extend::Reflect => 1.0 - (offset.rem_euclid(2.0) - 1.0).abs(),
+    };
+    let Some(first) = stops.first() else {
+        return peniko::Color::TRANSPARENT;
+    };
+    let Some(after) = stops.iter().position(|stop| stop.offset > offset) else {
+        return stops[stops.len() - 1].color.into();
+    };
+    if after == 0 {
+        return first.color.into();
+    }
+    let (from, to) = (&stops[after - 1], &stops[after]);
+    let t = (offset - from.offset) / (to.offset - from.offset);
+    let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    peniko::Color::rgba8(
+        lerp(from.color.r, to.color.r),
+        lerp(from.color.g, to.color.g),
+        lerp(from.color.b, to.color.b),
+        lerp(from.color.a, to.color.a),
+    )
+}
+
+/// Bounds of everything stroking `path` with `style` paints.
+pub(crate) fn stroke_bounds(path: &BezPath, style: &StrokeStyle) -> kurbo::Rect {
+    // Miter joins reach furthest, square caps come second.
+    let reach = style.width / 2.0 * style.miter_limit.max(SQRT_2);
+    path.bounding_box().inflate(reach, reach)
+}
+
+pub(crate) fn to_path(shape: &Shape) -> BezPath {
+    match *shape {
+        Shape::Rect { x0, y0, x1, y1 } => kurbo::Rect::new(x0, y0, x1, y1).to_path(TOLERANCE),
+        Shape::RoundedRect {
+            x0,
+            y0,
+            x1,
+            y1,
+            radius,
+        } => kurbo::RoundedRect::new(x0, y0, x1, y1, radius).to_path(TOLERANCE),
+        Shape::Circle { cx, cy, radius } => kurbo::Circle::new((cx, cy), radius).to_path(TOLERANCE),
+        Shape::Ellipse {
+            cx,
+            cy,
+            rx,
+            ry,
+            rotation,
+        } => kurbo::Ellipse::new((cx, cy), (rx, ry), rotation).to_path(TOLERANCE),
+        Shape::Line { x0, y0, x1, y1 } => kurbo::Line::new((x0, y0), (x1, y1)).to_path(TOLERANCE),
+        Shape::Path { ref elements } => elements.iter().map(|&element| element.into()).collect(),
+    }
+}
+
+impl From<BlendMode> for peniko::BlendMode {
+    fn from(blend: BlendMode) -> Self {
+        peniko::BlendMode::new(blend.mix.into(), blend.compose.into())
+    }
+}
+
+impl From<Mix> for peniko::Mix {
+    fn from(mix: Mix) -> Self {
+        match mix {
+            Mix::Normal => peniko::Mix::Normal,
+            Mix::Multiply => peniko::Mix::Multiply,
+            Mix::Screen => peniko::Mix::Screen,
+            Mix::Overlay => peniko::Mix::Overlay,
+            Mix::Darken => peniko::Mix::Darken,
+            Mix::Lighten => peniko::Mix::Lighten,
+            Mix::ColorDodge => peniko::Mix::ColorDodge,
+            Mix::ColorBurn => peniko::Mix::ColorBurn,
+            Mix::HardLight => peniko::Mix::HardLight,
+            Mix::SoftLight => peniko::Mix::SoftLight,
+            Mix::Difference => peniko::Mix::Difference,
+            Mix::Exclusion => peniko::Mix::Exclusion,
+            Mix::Hue => peniko::Mix::Hue,
+            Mix::Saturation => peniko::Mix::Saturation,
+            Mix::Color => peniko::Mix::Color,
+            Mix::Luminosity => peniko::Mix::Luminosity,
+            Mix::Clip => peniko::Mix::Clip,
+        }
+    }
+}
+
+impl From<Compose> for peniko::Compose {
+    fn from(compose: Compose) -> Self {
+        match compose {
+            Compose::Clear => peniko::Compose::Clear,
+            Compose::Copy => peniko::Compose::Copy,
+            Compose::Dest => peniko::Compose::Dest,
+            Compose::SrcOver => peniko::Compose::SrcOver,
+            Compose::DestOver => peniko::Compose::DestOver,
+            Compose::SrcIn => peniko::Compose::SrcIn,
+            Compose::DestIn => peniko::Compose::DestIn,
+            Compose::SrcOut => peniko::Compose::SrcOut,
+            Compose::DestOut => peniko::Compose::DestOut,
+            Compose::SrcAtop => peniko::Compose::SrcAtop,
+            Compose::DestAtop => peniko::Compose::DestAtop,
+            Compose::Xor => peniko::Compose::Xor,
+            Compose::Plus => peniko::Compose::Plus,
+            Compose::PlusLighter => peniko::Compose::PlusLighter,
+        }
+    }
+}
+
+impl From<ColorStop> for peniko::ColorStop {
+    fn from(stop: ColorStop) -> Self {
+        peniko::ColorStop {
+            offset: stop.offset as f32,
+            color: stop.color.into(),
+        }
+    }
+}
+
+impl From<Extend> for peniko::Extend {
+    fn from(extend: Extend) -> Self {
+        match extend {
+            Extend::Pad => peniko::Extend::Pad,
+            Extend::Repeat => peniko::Extend::Repeat,
+            Extend::Reflect => peniko::Extend::Reflect,
+        }
+    }
+}
+
+impl From<Color> for peniko::Color {
+    fn from(color: Color) -> Self {
+        peniko::Color::rgba8(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Transform> for Affine {
+    fn from(t: Transform) -> Self {
+        Affine::new([t.xx, t.yx, t.xy, t.yy, t.dx, t.dy])
+    }
+}
+
+impl From<Affine> for Transform {
+    fn from(affine: Affine) -> Self {
+        let [xx, yx, xy, yy, dx, dy] = affine.as_coeffs();
+        Transform {
+            xx,
+            yx,
+            xy,
+            yy,
+            dx,
+            dy,
+        }
+    }
+}
+
+impl From<PathElement> for kurbo::PathEl {
+    fn from(element: PathElement) -> Self {
+        match element {
+            PathElement::MoveTo { x, y } => kurbo::PathEl::MoveTo((x, y).into()),
+            PathElement::LineTo { x, y } => kurbo::PathEl::LineTo((x, y).into()),
+            PathElement::QuadTo { x1, y1, x, y } => {
+                kurbo::PathEl::QuadTo((x1, y1).into(), (x, y).into())
+            }
+            PathElement::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => kurbo::PathEl::CurveTo((x1, y1).into(), (x2, y2).into(), (x, y).into()),
+            PathElement::Close => kurbo::PathEl::ClosePath,
+        }
+    }
+}
+
+impl From<kurbo::PathEl> for PathElement {
+    fn from(element: kurbo::PathEl) -> Self {
+        match element {
+            kurbo::PathEl::MoveTo(p) => PathElement::MoveTo { x: p.x, y: p.y },
+            kurbo::PathEl::LineTo(p) => PathElement::LineTo { x: p.x, y: p.y },
+            kurbo::PathEl::QuadTo(p1, p) => PathElement::QuadTo {
+                x1: p1.x,
+                y1: p1.y,
+                x: p.x,
+                y: p.y,
+            },
+            kurbo::PathEl::CurveTo(p1, p2, p) => PathElement::CurveTo {
+                x1: p1.x,
+                y1: p1.y,
+                x2: p2.x,
+                y2: p2.y,
+                x: p.x,
+                y: p.y,
+            },
+            kurbo::PathEl::ClosePath => PathElement::Close,
+        }
+    }
+}
+
+impl From<FillRule> for peniko::Fill {
+    fn from(fill_rule: FillRule) -> Self {
+        match fill_rule {
+            FillRule::NonZero => peniko::Fill::NonZero,
+            FillRule::EvenOdd => peniko::Fill::EvenOdd,
+        }
+    }
+}
+
+impl From<&StrokeStyle> for kurbo::Stroke {
+    fn from(style: &StrokeStyle) -> Self {
+        kurbo::Stroke::new(style.width)
+            .with_join(match style.join {
+                LineJoin::Bevel => kurbo::Join::Bevel,
+                LineJoin::Miter => kurbo::Join::Miter,
+                LineJoin::Round => kurbo::Join::Round,
+            })
+            .with_miter_limit(style.miter_limit)
+            .with_start_cap(style.start_cap.into())
+            .with_end_cap(style.end_cap.into())
+            .with_dashes(style.dash_offset, style.dash_pattern.iter().copied())
+    }
+}
+
+impl From<LineCap> for kurbo::Cap {
+    fn from(cap: LineCap) -> Self {
+        match cap {
+            LineCap::Butt => kurbo::Cap::Butt,
+            LineCap::Square => kurbo::Cap::Square,
+            LineCap::Round => kurbo::Cap::Round,
+        }
+    }
+}
+
+impl From<Antialiasing> for AaConfig {
+    fn from(antialiasing: Antialiasing) -> Self {
+        match antialiasing {
+            Antialiasing::Area => AaConfig::Area,
+            Antialiasing::Msaa8 => AaConfig::Msaa8,
+            Antialiasing::Msaa16 => AaConfig::Msaa16,
+        }
+    }
+}
+
+impl From<AntialiasingSupport> for AaSupport {
+    fn from(support: AntialiasingSupport) -> Self {
+        AaSupport {
+            area: support.area,
+            msaa8: support.msaa8,
+            msaa16: support.msaa16,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Fonts registered from Dart, shared by every renderer, and the unshaped
+//! text layout `api::text` offers on top of them.
+
+use crate::api::error::RendererError;
+use crate::api::scene::Glyph;
+use crate::api::text::TextLayout;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use vello::kurbo::BezPath;
+use vello::kurbo::Point;
+use vello::kurbo::Rect;
+use vello::peniko;
+use vello::peniko::Blob;
+use vello::skrifa::instance::LocationRef;
+use vello::skrifa::instance::Size;
+use vello::skrifa::outline::DrawSettings;
+use vello::skrifa::outline::OutlinePen;
+use vello::skrifa::FontRef;
+use vello::skrifa::GlyphId;
+use vello::skrifa::MetadataProvider;
+
+#[derive(Default)]
+struct FontRegistry {
+    fonts: HashMap<u64, peniko::Font>,
+    next_handle: u64,
+}
+
+lazy_static::lazy_static!(
+    static ref FONTS: RwLock<FontRegistry> = RwLock::new(FontRegistry::default());
+);
+
+/// Checks that `bytes` hold a TrueType or OpenType font and stores it under a
+/// new handle. Collections are read from their first font.
+pub(crate) fn register(bytes: Vec<u8>) -> Result<u64, RendererError> {
+    // The same check `font_ref` relies on.
+    FontRef::from_index(&bytes, 0).map_err(|e| RendererError::InvalidFont(e.to_string()))?;
+    let font = peniko::Font::new(Blob::new(Arc::new(bytes)), 0);
+    // Nothing can panic while the registry is locked, so it's never poisoned.
+    let mut registry = FONTS.write().unwrap();
+    let handle = registry.next_handle;
+    registry.next_handle += 1;
+    registry.fonts.insert(handle, font);
+    Ok(handle)
+}
+
+pub(crate) fn release(handle: u64) -> Result<(), RendererError> {
+    FONTS
+        .write()
+        .unwrap()
+        .fonts
+        .remove(&handle)
+        .map(|_| ())
+        .ok_or(RendererError::FontNotFound(handle))
+}
+
+pub(crate) fn get(handle: u64) -> Result<peniko::Font, RendererError> {
+    FONTS
+        .read()
+        .unwrap()
+        .fonts
+        .get(&handle)
+        .cloned()
+        .ok_or(RendererError::FontNotFound(handle))
+}
+
+/// Parses a registered font, which `register` already checked.
+pub(crate) fn font_ref(font: &peniko::Font) -> FontRef<'_> {
+    FontRef::from_index(font.data.as_ref(), font.index).expect("registered fonts are valid")
+}
+
+/// Places the glyphs of `text` one after the other, using the font's cmap and
+/// advance widths without shaping, so without kerning or ligatures. Every `\n`
+/// starts a new line.
+pub(crate) fn layout_unshaped(font: &peniko::Font, text: &str, size: f64) -> TextLayout {
+    let font = font_ref(font);
+    let size = Size::new(size as f32);
+    let metrics = font.metrics(size, LocationRef::default());
+    let glyph_metrics = font.glyph_metrics(size, LocationRef::default());
+    let charmap = font.charmap();
+    // Descents are negative.
+    let (ascent, descent) = (metrics.ascent as f64, -metrics.descent as f64);
+    let line_height = ascent + descent + metrics.leading as f64;
+
+    let mut glyphs = Vec::new();
+    let mut width: f64 = 0.0;
+    let mut baseline = ascent;
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            baseline += line_height;
+        }
+        let mut x = 0.0;
+        for ch in line.chars().filter(|ch| !ch.is_control()) {
+            // Characters the font lacks show its .notdef glyph.
+            let id = charmap.map(ch).unwrap_or_default();
+            glyphs.push(Glyph {
+                id: id.to_u16().into(),
+                x,
+                y: baseline,
+            });
+            x += glyph_metrics.advance_width(id).unwrap_or_default() as f64;
+        }
+        width = width.max(x);
+    }
+
+    TextLayout {
+        glyphs,
+        width,
+        height: baseline + descent,
+        ascent,
+        descent,
+    }
+}
+
+/// skrifa's id for `glyph`. Fonts number their glyphs with 16 bits.
+fn glyph_id(glyph: &Glyph) -> Result<GlyphId, RendererError> {
+    u16::try_from(glyph.id)
+        .map(GlyphId::new)
+        .map_err(|_| RendererError::InvalidGlyph(glyph.id))
+}
+
+/// The advance box of every glyph in a run: as wide as the glyph's advance
+/// and reaching from the font's ascent to its descent.
+pub(crate) fn glyph_boxes(
+    font: &peniko::Font,
+    size: f64,
+    glyphs: &[Glyph],
+) -> Result<Vec<Rect>, RendererError> {
+    let font = font_ref(font);
+    let size = Size::new(size as f32);
+    let metrics = font.metrics(size, LocationRef::default());
+    let glyph_metrics = font.glyph_metrics(size, LocationRef::default());
+    glyphs
+        .iter()
+        .map(|glyph| {
+            let advance = glyph_metrics
+                .advance_width(glyph_id(glyph)?)
+                .unwrap_or_default();
+            Ok(Rect::new(
+                glyph.x,
+                glyph.y - metrics.ascent as f64,
+                glyph.x + advance as f64,
+                glyph.y - metrics.descent as f64,
+            ))
+        })
+        .collect()
+}
+
+/// The outlines of every glyph in a run, placed at their positions, as one
+/// path.
+pub(crate) fn glyph_outlines(
+    font: &peniko::Font,
+    size: f64,
+    glyphs: &[Glyph],
+) -> Result<BezPath, RendererError> {
+    let outlines = font_ref(font).outline_glyphs();
+    let size = Size::new(size as f32);
+    let mut pen = GlyphPen {
+        path: BezPath::new(),
+        origin: Point::ZERO,
+    };
+    for glyph in glyphs {
+        // Glyphs without an outline, like spaces, draw nothing.
+        let Some(outline) = outlines.get(glyph_id(glyph)?) else {
+            continue;
+        };
+        pen.origin = Point::new(glyph.x, glyph.y);
+        // A glyph that fails to draw is left out, the way vello does.
+        let settings = DrawSettings::unhinted(size, LocationRef::default());
+        let _ = outline.draw(settings, &mut pen);
+    }
+    Ok(pen.path)
+}
+
+/// Collects glyph outlines, which point up, into a path that points down.
+struct GlyphPen {
+    path: BezPath,
+    origin: Point,
+}
+
+impl GlyphPen {
+    fn point(&self, x: f32, y: f32) -> Point {
+        Point::new(self.origin.x + x as f64, self.origin.y - y as f64)
+    }
+}
+
+impl OutlinePen for GlyphPen {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.path.move_to(self.point(x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.path.line_to(self.point(x, y));
+    }
+
+    fn quad_to(&mut self, cx0: f32, cy0: f32, x: f32, y: f32) {
+        self.path.quad_to(self.point(cx0, cy0), self.point(x, y));
+    }
+
+    fn curve_to(&mut self, cx0: f32, cy0: f32, cx1: f32, cy1: f32, x: f32, y: f32) {
+        self.path
+            .curve_to(self.point(cx0, cy0), self.point(cx1, cy1), self.point(x, y));
+    }
+
+    fn close(&mut self) {
+        self.path.close_path();
+    }
+}
@@ -38,16 +38,17 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire_greet_impl(
+fn wire_clear_timeline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "clear_timeline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -59,23 +60,26 @@ fn wire_greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::greet(api_name))
-            })())
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move { crate::api::animation::clear_timeline(api_id).await })()
+                        .await,
+                )
+            }
         },
     )
 }
-fn wire_init_app_impl(
+fn wire_pause_timeline_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "pause_timeline",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -89,16 +93,18 @@ fn wire_init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
+            move |context| async move {
                 transform_result_sse(
-                    (move || Result::<_, ()>::Ok(crate::api::simple::init_app()))(),
+                    (move || async move { crate::api::animation::pause_timeline(api_id).await })()
+                        .await,
                 )
             }
         },
     )
 }
-fn wire_test_render_impl(
+fn wire_play_timeline_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -106,7 +112,7 @@ fn wire_test_render_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_render",
+            debug_name: "play_timeline",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -120,13 +126,56 @@ fn wire_test_render_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_x = <f32>::sse_decode(&mut deserializer);
-            let api_y = <f32>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move { crate::api::animation::play_timeline(api_id).await })()
+                        .await,
+                )
+            }
+        },
+    )
+}
+fn wire_render_graph_at_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_graph_at",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_timestamp_micros = <u64>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse(
                     (move || async move {
-                        Result::<_, ()>::Ok(crate::api::simple::test_render(api_x, api_y).await)
+                        crate::api::animation::render_graph_at(
+                            api_id,
+                            api_timestamp_micros,
+                            api_width,
+                            api_height,
+                            api_options,
+                        )
+                        .await
                     })()
                     .await,
                 )
@@ -134,103 +183,4216 @@ fn wire_test_render_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
-}
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
-    }
-}
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire_seek_timeline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "seek_timeline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_position = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::animation::seek_timeline(api_id, api_position).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
+fn wire_set_timeline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
 ) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        2 => wire_init_app_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire_test_render_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
-    }
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_timeline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_timeline = <crate::api::animation::Timeline>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::animation::set_timeline(api_id, api_timeline).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire_greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
-    }
+fn wire_encode_png_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encode_png",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_frame = <crate::api::simple::Frame>::sse_decode(&mut deserializer);
+            let api_compression =
+                <Option<crate::api::export::PngCompression>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::export::encode_png(api_frame, api_compression)
+                })())
+            }
+        },
+    )
 }
-
-// Section: rust2dart
-
-impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
-    }
+fn wire_export_svg_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_svg",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move { crate::api::export::export_svg(api_id).await })().await,
+                )
+            }
+        },
+    )
 }
-
-impl SseEncode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire_render_png_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_png",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_scene = <Vec<crate::api::scene::SceneCommand>>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
+            let api_compression =
+                <Option<crate::api::export::PngCompression>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::export::render_png(
+                            api_id,
+                            api_scene,
+                            api_width,
+                            api_height,
+                            api_options,
+                            api_compression,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_hit_test_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hit_test",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_x = <f64>::sse_decode(&mut deserializer);
+            let api_y = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse((move || async move {
+                         crate::api::graph::hit_test(api_id, api_x, api_y).await
+                    })().await)
+            }
+        },
+    )
+}
+fn wire_render_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::graph::render_graph(api_id, api_width, api_height, api_options)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_stream_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::graph::stream_graph(api_id, api_width, api_height, api_options)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_update_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_updates = <Vec<crate::api::graph::GraphUpdate>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse((move || async move {
+                         crate::api::graph::update_graph(api_id, api_updates).await
+                    })().await)
+            }
+        },
+    )
+}
+fn wire_release_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "release_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_image = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse((move || async move {
+                         crate::api::image::release_image(api_id, api_image).await
+                    })().await)
+            }
+        },
+    )
+}
+fn wire_upload_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse((move || async move {
+                         crate::api::image::upload_image(api_id, api_bytes).await
+                    })().await)
+            }
+        },
+    )
+}
+fn wire_upload_rgba_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upload_rgba_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::image::upload_rgba_image(
+                            api_id, api_data, api_width, api_height,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_load_lottie_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_lottie",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::lottie::load_lottie(api_json))())
+            }
+        },
+    )
+}
+fn wire_lottie_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lottie_frame",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_animation = <u64>::sse_decode(&mut deserializer);
+            let api_time = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::lottie::lottie_frame(api_animation, api_time)
+                })())
+            }
+        },
+    )
+}
+fn wire_lottie_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lottie_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_animation = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::lottie::lottie_info(api_animation))())
+            }
+        },
+    )
+}
+fn wire_release_lottie_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "release_lottie",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_animation = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::lottie::release_lottie(api_animation))())
+            }
+        },
+    )
+}
+fn wire_render_lottie_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_lottie",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_animation = <u64>::sse_decode(&mut deserializer);
+            let api_time = <f64>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::lottie::render_lottie(
+                            api_id,
+                            api_animation,
+                            api_time,
+                            api_width,
+                            api_height,
+                            api_options,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_demo_scene_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "demo_scene",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::scene::demo_scene())
+            })())
+        },
+    )
+}
+fn wire_identity_transform_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "identity_transform",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::scene::identity_transform())
+            })())
+        },
+    )
+}
+fn wire_create_renderer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_renderer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::simple::RendererConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::simple::create_renderer(api_id, api_config)
+                })())
+            }
+        },
+    )
+}
+fn wire_default_render_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_render_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::default_render_options())
+            })())
+        },
+    )
+}
+fn wire_destroy_renderer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "destroy_renderer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::simple::destroy_renderer(api_id))())
+            }
+        },
+    )
+}
+fn wire_greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::greet(api_name))
+            })())
+        },
+    )
+}
+fn wire_init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse(
+                    (move || Result::<_, ()>::Ok(crate::api::simple::init_app()))(),
+                )
+            }
+        },
+    )
+}
+fn wire_render_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_x = <f32>::sse_decode(&mut deserializer);
+            let api_y = <f32>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::simple::render(api_id, api_x, api_y, api_width, api_height)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_render_scene_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_scene",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_scene = <Vec<crate::api::scene::SceneCommand>>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::simple::render_scene(
+                            api_id,
+                            api_scene,
+                            api_width,
+                            api_height,
+                            api_options,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_set_scene_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_scene",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_scene = <Vec<crate::api::scene::SceneCommand>>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::simple::RenderOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::simple::set_scene(
+                            api_id,
+                            api_scene,
+                            api_width,
+                            api_height,
+                            api_options,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_subscribe_frames_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_frames",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::simple::Frame,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::simple::subscribe_frames(api_id, api_sink).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_test_render_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_render",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_x = <f32>::sse_decode(&mut deserializer);
+            let api_y = <f32>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        crate::api::simple::test_render(api_x, api_y, api_width, api_height).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_import_svg_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_svg",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_svg = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| transform_result_sse((move || crate::api::svg::import_svg(api_svg))())
+        },
+    )
+}
+fn wire_layout_unshaped_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "layout_unshaped_text",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_font = <u64>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_size = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::text::layout_unshaped_text(api_font, api_text, api_size)
+                })())
+            }
+        },
+    )
+}
+fn wire_register_font_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_font",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || crate::api::text::register_font(api_bytes))())
+            }
+        },
+    )
+}
+fn wire_release_font_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "release_font", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_font = <u64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse((move ||  {
+                         crate::api::text::release_font(api_font)
+                    })())
+                } })
+}
+
+// Section: dart2rust
+
+impl SseDecode
+    for StreamSink<crate::api::simple::Frame, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::simple::AlphaMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::AlphaMode::Straight,
+            1 => crate::api::simple::AlphaMode::Premultiplied,
+            _ => unreachable!("Invalid variant for AlphaMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::animation::AnimatedProperty {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::animation::AnimatedProperty::Transform;
+            }
+            1 => {
+                return crate::api::animation::AnimatedProperty::Opacity;
+            }
+            2 => {
+                let mut var_command = <u32>::sse_decode(deserializer);
+                return crate::api::animation::AnimatedProperty::Color {
+                    command: var_command,
+                };
+            }
+            3 => {
+                let mut var_command = <u32>::sse_decode(deserializer);
+                return crate::api::animation::AnimatedProperty::StrokeWidth {
+                    command: var_command,
+                };
+            }
+            4 => {
+                let mut var_command = <u32>::sse_decode(deserializer);
+                return crate::api::animation::AnimatedProperty::Path {
+                    command: var_command,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::Antialiasing {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::Antialiasing::Area,
+            1 => crate::api::simple::Antialiasing::Msaa8,
+            2 => crate::api::simple::Antialiasing::Msaa16,
+            _ => unreachable!("Invalid variant for Antialiasing: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::AntialiasingSupport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_area = <bool>::sse_decode(deserializer);
+        let mut var_msaa8 = <bool>::sse_decode(deserializer);
+        let mut var_msaa16 = <bool>::sse_decode(deserializer);
+        return crate::api::simple::AntialiasingSupport {
+            area: var_area,
+            msaa8: var_msaa8,
+            msaa16: var_msaa16,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::BlendMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mix = <crate::api::scene::Mix>::sse_decode(deserializer);
+        let mut var_compose = <crate::api::scene::Compose>::sse_decode(deserializer);
+        return crate::api::scene::BlendMode {
+            mix: var_mix,
+            compose: var_compose,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::scene::Brush {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_color = <crate::api::scene::Color>::sse_decode(deserializer);
+                return crate::api::scene::Brush::Solid { color: var_color };
+            }
+            1 => {
+                let mut var_x0 = <f64>::sse_decode(deserializer);
+                let mut var_y0 = <f64>::sse_decode(deserializer);
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                let mut var_stops = <Vec<crate::api::scene::ColorStop>>::sse_decode(deserializer);
+                let mut var_extend = <crate::api::scene::Extend>::sse_decode(deserializer);
+                return crate::api::scene::Brush::LinearGradient {
+                    x0: var_x0,
+                    y0: var_y0,
+                    x1: var_x1,
+                    y1: var_y1,
+                    stops: var_stops,
+                    extend: var_extend,
+                };
+            }
+            2 => {
+                let mut var_x0 = <f64>::sse_decode(deserializer);
+                let mut var_y0 = <f64>::sse_decode(deserializer);
+                let mut var_r0 = <f64>::sse_decode(deserializer);
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                let mut var_r1 = <f64>::sse_decode(deserializer);
+                let mut var_stops = <Vec<crate::api::scene::ColorStop>>::sse_decode(deserializer);
+                let mut var_extend = <crate::api::scene::Extend>::sse_decode(deserializer);
+                return crate::api::scene::Brush::RadialGradient {
+                    x0: var_x0,
+                    y0: var_y0,
+                    r0: var_r0,
+                    x1: var_x1,
+                    y1: var_y1,
+                    r1: var_r1,
+                    stops: var_stops,
+                    extend: var_extend,
+                };
+            }
+            3 => {
+                let mut var_cx = <f64>::sse_decode(deserializer);
+                let mut var_cy = <f64>::sse_decode(deserializer);
+                let mut var_startAngle = <f64>::sse_decode(deserializer);
+                let mut var_endAngle = <f64>::sse_decode(deserializer);
+                let mut var_stops = <Vec<crate::api::scene::ColorStop>>::sse_decode(deserializer);
+                let mut var_extend = <crate::api::scene::Extend>::sse_decode(deserializer);
+                return crate::api::scene::Brush::SweepGradient {
+                    cx: var_cx,
+                    cy: var_cy,
+                    start_angle: var_startAngle,
+                    end_angle: var_endAngle,
+                    stops: var_stops,
+                    extend: var_extend,
+                };
+            }
+            4 => {
+                let mut var_image = <u64>::sse_decode(deserializer);
+                return crate::api::scene::Brush::Image { image: var_image };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::simple::ChannelOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::ChannelOrder::Rgba,
+            1 => crate::api::simple::ChannelOrder::Bgra,
+            _ => unreachable!("Invalid variant for ChannelOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::Color {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_r = <u8>::sse_decode(deserializer);
+        let mut var_g = <u8>::sse_decode(deserializer);
+        let mut var_b = <u8>::sse_decode(deserializer);
+        let mut var_a = <u8>::sse_decode(deserializer);
+        return crate::api::scene::Color {
+            r: var_r,
+            g: var_g,
+            b: var_b,
+            a: var_a,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::ColorStop {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offset = <f64>::sse_decode(deserializer);
+        let mut var_color = <crate::api::scene::Color>::sse_decode(deserializer);
+        return crate::api::scene::ColorStop {
+            offset: var_offset,
+            color: var_color,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::Compose {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::Compose::Clear,
+            1 => crate::api::scene::Compose::Copy,
+            2 => crate::api::scene::Compose::Dest,
+            3 => crate::api::scene::Compose::SrcOver,
+            4 => crate::api::scene::Compose::DestOver,
+            5 => crate::api::scene::Compose::SrcIn,
+            6 => crate::api::scene::Compose::DestIn,
+            7 => crate::api::scene::Compose::SrcOut,
+            8 => crate::api::scene::Compose::DestOut,
+            9 => crate::api::scene::Compose::SrcAtop,
+            10 => crate::api::scene::Compose::DestAtop,
+            11 => crate::api::scene::Compose::Xor,
+            12 => crate::api::scene::Compose::Plus,
+            13 => crate::api::scene::Compose::PlusLighter,
+            _ => unreachable!("Invalid variant for Compose: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::animation::Easing {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::animation::Easing::Hold;
+            }
+            1 => {
+                return crate::api::animation::Easing::Linear;
+            }
+            2 => {
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                let mut var_x2 = <f64>::sse_decode(deserializer);
+                let mut var_y2 = <f64>::sse_decode(deserializer);
+                return crate::api::animation::Easing::CubicBezier {
+                    x1: var_x1,
+                    y1: var_y1,
+                    x2: var_x2,
+                    y2: var_y2,
+                };
+            }
+            3 => {
+                let mut var_stiffness = <f64>::sse_decode(deserializer);
+                let mut var_damping = <f64>::sse_decode(deserializer);
+                let mut var_mass = <f64>::sse_decode(deserializer);
+                return crate::api::animation::Easing::Spring {
+                    stiffness: var_stiffness,
+                    damping: var_damping,
+                    mass: var_mass,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scene::Extend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::Extend::Pad,
+            1 => crate::api::scene::Extend::Repeat,
+            2 => crate::api::scene::Extend::Reflect,
+            _ => unreachable!("Invalid variant for Extend: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::scene::FillRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::FillRule::NonZero,
+            1 => crate::api::scene::FillRule::EvenOdd,
+            _ => unreachable!("Invalid variant for FillRule: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::Frame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_channelOrder = <crate::api::simple::ChannelOrder>::sse_decode(deserializer);
+        let mut var_alphaMode = <crate::api::simple::AlphaMode>::sse_decode(deserializer);
+        let mut var_skippedFrames = <u32>::sse_decode(deserializer);
+        let mut var_frameNumber = <u64>::sse_decode(deserializer);
+        let mut var_timestampMicros = <u64>::sse_decode(deserializer);
+        return crate::api::simple::Frame {
+            data: var_data,
+            width: var_width,
+            height: var_height,
+            channel_order: var_channelOrder,
+            alpha_mode: var_alphaMode,
+            skipped_frames: var_skippedFrames,
+            frame_number: var_frameNumber,
+            timestamp_micros: var_timestampMicros,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::Glyph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_x = <f64>::sse_decode(deserializer);
+        let mut var_y = <f64>::sse_decode(deserializer);
+        return crate::api::scene::Glyph {
+            id: var_id,
+            x: var_x,
+            y: var_y,
+        };
+    }
+}
+
+impl SseDecode for crate::api::graph::GraphUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                let mut var_parent = <Option<u64>>::sse_decode(deserializer);
+                let mut var_commands =
+                    <Vec<crate::api::scene::SceneCommand>>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                let mut var_zIndex = <i32>::sse_decode(deserializer);
+                let mut var_visible = <bool>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::Insert {
+                    id: var_id,
+                    parent: var_parent,
+                    commands: var_commands,
+                    transform: var_transform,
+                    z_index: var_zIndex,
+                    visible: var_visible,
+                };
+            }
+            1 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                let mut var_commands =
+                    <Vec<crate::api::scene::SceneCommand>>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::SetCommands {
+                    id: var_id,
+                    commands: var_commands,
+                };
+            }
+            2 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::SetTransform {
+                    id: var_id,
+                    transform: var_transform,
+                };
+            }
+            3 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                let mut var_zIndex = <i32>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::SetZIndex {
+                    id: var_id,
+                    z_index: var_zIndex,
+                };
+            }
+            4 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                let mut var_visible = <bool>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::SetVisible {
+                    id: var_id,
+                    visible: var_visible,
+                };
+            }
+            5 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                let mut var_parent = <Option<u64>>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::Reparent {
+                    id: var_id,
+                    parent: var_parent,
+                };
+            }
+            6 => {
+                let mut var_id = <u64>::sse_decode(deserializer);
+                return crate::api::graph::GraphUpdate::Remove { id: var_id };
+            }
+            7 => {
+                return crate::api::graph::GraphUpdate::Clear;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::scene::ImageQuality {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::ImageQuality::Low,
+            1 => crate::api::scene::ImageQuality::Medium,
+            2 => crate::api::scene::ImageQuality::High,
+            _ => unreachable!("Invalid variant for ImageQuality: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::animation::Keyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_time = <f64>::sse_decode(deserializer);
+        let mut var_value = <crate::api::animation::KeyframeValue>::sse_decode(deserializer);
+        let mut var_easing = <crate::api::animation::Easing>::sse_decode(deserializer);
+        return crate::api::animation::Keyframe {
+            time: var_time,
+            value: var_value,
+            easing: var_easing,
+        };
+    }
+}
+
+impl SseDecode for crate::api::animation::KeyframeValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::scene::Transform>::sse_decode(deserializer);
+                return crate::api::animation::KeyframeValue::Transform(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <f64>::sse_decode(deserializer);
+                return crate::api::animation::KeyframeValue::Number(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <crate::api::scene::Color>::sse_decode(deserializer);
+                return crate::api::animation::KeyframeValue::Color(var_field0);
+            }
+            3 => {
+                let mut var_field0 =
+                    <Vec<crate::api::scene::PathElement>>::sse_decode(deserializer);
+                return crate::api::animation::KeyframeValue::Path(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scene::LineCap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::LineCap::Butt,
+            1 => crate::api::scene::LineCap::Square,
+            2 => crate::api::scene::LineCap::Round,
+            _ => unreachable!("Invalid variant for LineCap: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::LineJoin {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::LineJoin::Bevel,
+            1 => crate::api::scene::LineJoin::Miter,
+            2 => crate::api::scene::LineJoin::Round,
+            _ => unreachable!("Invalid variant for LineJoin: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scene::ColorStop> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scene::ColorStop>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scene::Glyph> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scene::Glyph>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::graph::GraphUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::graph::GraphUpdate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::animation::Keyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::animation::Keyframe>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scene::PathElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scene::PathElement>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scene::SceneCommand> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scene::SceneCommand>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::animation::Track> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::animation::Track>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::lottie::LottieInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_animation = <u64>::sse_decode(deserializer);
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_height = <f64>::sse_decode(deserializer);
+        let mut var_frameRate = <f64>::sse_decode(deserializer);
+        let mut var_duration = <f64>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::lottie::LottieInfo {
+            animation: var_animation,
+            width: var_width,
+            height: var_height,
+            frame_rate: var_frameRate,
+            duration: var_duration,
+            warnings: var_warnings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::Mix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scene::Mix::Normal,
+            1 => crate::api::scene::Mix::Multiply,
+            2 => crate::api::scene::Mix::Screen,
+            3 => crate::api::scene::Mix::Overlay,
+            4 => crate::api::scene::Mix::Darken,
+            5 => crate::api::scene::Mix::Lighten,
+            6 => crate::api::scene::Mix::ColorDodge,
+            7 => crate::api::scene::Mix::ColorBurn,
+            8 => crate::api::scene::Mix::HardLight,
+            9 => crate::api::scene::Mix::SoftLight,
+            10 => crate::api::scene::Mix::Difference,
+            11 => crate::api::scene::Mix::Exclusion,
+            12 => crate::api::scene::Mix::Hue,
+            13 => crate::api::scene::Mix::Saturation,
+            14 => crate::api::scene::Mix::Color,
+            15 => crate::api::scene::Mix::Luminosity,
+            16 => crate::api::scene::Mix::Clip,
+            _ => unreachable!("Invalid variant for Mix: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<crate::api::simple::Frame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::simple::Frame>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::export::PngCompression> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::export::PngCompression>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::scene::Transform> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::scene::Transform>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::scene::PathElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_x = <f64>::sse_decode(deserializer);
+                let mut var_y = <f64>::sse_decode(deserializer);
+                return crate::api::scene::PathElement::MoveTo { x: var_x, y: var_y };
+            }
+            1 => {
+                let mut var_x = <f64>::sse_decode(deserializer);
+                let mut var_y = <f64>::sse_decode(deserializer);
+                return crate::api::scene::PathElement::LineTo { x: var_x, y: var_y };
+            }
+            2 => {
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                let mut var_x = <f64>::sse_decode(deserializer);
+                let mut var_y = <f64>::sse_decode(deserializer);
+                return crate::api::scene::PathElement::QuadTo {
+                    x1: var_x1,
+                    y1: var_y1,
+                    x: var_x,
+                    y: var_y,
+                };
+            }
+            3 => {
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                let mut var_x2 = <f64>::sse_decode(deserializer);
+                let mut var_y2 = <f64>::sse_decode(deserializer);
+                let mut var_x = <f64>::sse_decode(deserializer);
+                let mut var_y = <f64>::sse_decode(deserializer);
+                return crate::api::scene::PathElement::CurveTo {
+                    x1: var_x1,
+                    y1: var_y1,
+                    x2: var_x2,
+                    y2: var_y2,
+                    x: var_x,
+                    y: var_y,
+                };
+            }
+            4 => {
+                return crate::api::scene::PathElement::Close;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::export::PngCompression {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::export::PngCompression::Fast,
+            1 => crate::api::export::PngCompression::Default,
+            2 => crate::api::export::PngCompression::Best,
+            _ => unreachable!("Invalid variant for PngCompression: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::RenderOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_antialiasing = <crate::api::simple::Antialiasing>::sse_decode(deserializer);
+        let mut var_background = <crate::api::scene::Color>::sse_decode(deserializer);
+        let mut var_alphaMode = <crate::api::simple::AlphaMode>::sse_decode(deserializer);
+        let mut var_channelOrder = <crate::api::simple::ChannelOrder>::sse_decode(deserializer);
+        return crate::api::simple::RenderOptions {
+            antialiasing: var_antialiasing,
+            background: var_background,
+            alpha_mode: var_alphaMode,
+            channel_order: var_channelOrder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::RenderQueuePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::RenderQueuePolicy::Fifo,
+            1 => crate::api::simple::RenderQueuePolicy::LatestWins,
+            _ => unreachable!("Invalid variant for RenderQueuePolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::RendererBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::simple::RendererBackend::Gpu,
+            1 => crate::api::simple::RendererBackend::Cpu,
+            _ => unreachable!("Invalid variant for RendererBackend: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::RendererConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backend = <crate::api::simple::RendererBackend>::sse_decode(deserializer);
+        let mut var_queuePolicy = <crate::api::simple::RenderQueuePolicy>::sse_decode(deserializer);
+        let mut var_antialiasingSupport =
+            <crate::api::simple::AntialiasingSupport>::sse_decode(deserializer);
+        return crate::api::simple::RendererConfig {
+            backend: var_backend,
+            queue_policy: var_queuePolicy,
+            antialiasing_support: var_antialiasingSupport,
+        };
+    }
+}
+
+impl SseDecode for crate::api::error::RendererError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::error::RendererError::AdapterNotFound;
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::DeviceCreation(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::RendererCreation(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::Render(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <crate::api::simple::Antialiasing>::sse_decode(deserializer);
+                return crate::api::error::RendererError::UnsupportedAntialiasing(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::Unsupported(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::Readback(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::Encoding(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::RendererNotFound(var_field0);
+            }
+            9 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::error::RendererError::NodeNotFound(var_field0);
+            }
+            10 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::error::RendererError::NodeExists(var_field0);
+            }
+            11 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::error::RendererError::NodeCycle(var_field0);
+            }
+            12 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::error::RendererError::ImageNotFound(var_field0);
+            }
+            13 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::Decoding(var_field0);
+            }
+            14 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::error::RendererError::FontNotFound(var_field0);
+            }
+            15 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::InvalidFont(var_field0);
+            }
+            16 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::api::error::RendererError::InvalidGlyph(var_field0);
+            }
+            17 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::InvalidLayers(var_field0);
+            }
+            18 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::InvalidSvg(var_field0);
+            }
+            19 => {
+                let mut var_field0 = <u64>::sse_decode(deserializer);
+                return crate::api::error::RendererError::LottieNotFound(var_field0);
+            }
+            20 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::InvalidLottie(var_field0);
+            }
+            21 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::error::RendererError::InvalidTimeline(var_field0);
+            }
+            22 => {
+                return crate::api::error::RendererError::TimelineNotSet;
+            }
+            23 => {
+                return crate::api::error::RendererError::SceneNotSet;
+            }
+            24 => {
+                return crate::api::error::RendererError::Disconnected;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scene::SceneCommand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_shape = <crate::api::scene::Shape>::sse_decode(deserializer);
+                let mut var_brush = <crate::api::scene::Brush>::sse_decode(deserializer);
+                let mut var_fillRule = <crate::api::scene::FillRule>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                let mut var_brushTransform =
+                    <Option<crate::api::scene::Transform>>::sse_decode(deserializer);
+                return crate::api::scene::SceneCommand::Fill {
+                    shape: var_shape,
+                    brush: var_brush,
+                    fill_rule: var_fillRule,
+                    transform: var_transform,
+                    brush_transform: var_brushTransform,
+                };
+            }
+            1 => {
+                let mut var_shape = <crate::api::scene::Shape>::sse_decode(deserializer);
+                let mut var_brush = <crate::api::scene::Brush>::sse_decode(deserializer);
+                let mut var_style = <crate::api::scene::StrokeStyle>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                let mut var_brushTransform =
+                    <Option<crate::api::scene::Transform>>::sse_decode(deserializer);
+                return crate::api::scene::SceneCommand::Stroke {
+                    shape: var_shape,
+                    brush: var_brush,
+                    style: var_style,
+                    transform: var_transform,
+                    brush_transform: var_brushTransform,
+                };
+            }
+            2 => {
+                let mut var_image = <u64>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                let mut var_opacity = <f64>::sse_decode(deserializer);
+                let mut var_quality = <crate::api::scene::ImageQuality>::sse_decode(deserializer);
+                return crate::api::scene::SceneCommand::Image {
+                    image: var_image,
+                    transform: var_transform,
+                    opacity: var_opacity,
+                    quality: var_quality,
+                };
+            }
+            3 => {
+                let mut var_font = <u64>::sse_decode(deserializer);
+                let mut var_size = <f64>::sse_decode(deserializer);
+                let mut var_glyphs = <Vec<crate::api::scene::Glyph>>::sse_decode(deserializer);
+                let mut var_brush = <crate::api::scene::Brush>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                return crate::api::scene::SceneCommand::GlyphRun {
+                    font: var_font,
+                    size: var_size,
+                    glyphs: var_glyphs,
+                    brush: var_brush,
+                    transform: var_transform,
+                };
+            }
+            4 => {
+                let mut var_clip = <crate::api::scene::Shape>::sse_decode(deserializer);
+                let mut var_blend = <crate::api::scene::BlendMode>::sse_decode(deserializer);
+                let mut var_alpha = <f64>::sse_decode(deserializer);
+                let mut var_transform = <crate::api::scene::Transform>::sse_decode(deserializer);
+                return crate::api::scene::SceneCommand::PushLayer {
+                    clip: var_clip,
+                    blend: var_blend,
+                    alpha: var_alpha,
+                    transform: var_transform,
+                };
+            }
+            5 => {
+                return crate::api::scene::SceneCommand::PopLayer;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scene::Shape {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_x0 = <f64>::sse_decode(deserializer);
+                let mut var_y0 = <f64>::sse_decode(deserializer);
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                return crate::api::scene::Shape::Rect {
+                    x0: var_x0,
+                    y0: var_y0,
+                    x1: var_x1,
+                    y1: var_y1,
+                };
+            }
+            1 => {
+                let mut var_x0 = <f64>::sse_decode(deserializer);
+                let mut var_y0 = <f64>::sse_decode(deserializer);
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                let mut var_radius = <f64>::sse_decode(deserializer);
+                return crate::api::scene::Shape::RoundedRect {
+                    x0: var_x0,
+                    y0: var_y0,
+                    x1: var_x1,
+                    y1: var_y1,
+                    radius: var_radius,
+                };
+            }
+            2 => {
+                let mut var_cx = <f64>::sse_decode(deserializer);
+                let mut var_cy = <f64>::sse_decode(deserializer);
+                let mut var_radius = <f64>::sse_decode(deserializer);
+                return crate::api::scene::Shape::Circle {
+                    cx: var_cx,
+                    cy: var_cy,
+                    radius: var_radius,
+                };
+            }
+            3 => {
+                let mut var_cx = <f64>::sse_decode(deserializer);
+                let mut var_cy = <f64>::sse_decode(deserializer);
+                let mut var_rx = <f64>::sse_decode(deserializer);
+                let mut var_ry = <f64>::sse_decode(deserializer);
+                let mut var_rotation = <f64>::sse_decode(deserializer);
+                return crate::api::scene::Shape::Ellipse {
+                    cx: var_cx,
+                    cy: var_cy,
+                    rx: var_rx,
+                    ry: var_ry,
+                    rotation: var_rotation,
+                };
+            }
+            4 => {
+                let mut var_x0 = <f64>::sse_decode(deserializer);
+                let mut var_y0 = <f64>::sse_decode(deserializer);
+                let mut var_x1 = <f64>::sse_decode(deserializer);
+                let mut var_y1 = <f64>::sse_decode(deserializer);
+                return crate::api::scene::Shape::Line {
+                    x0: var_x0,
+                    y0: var_y0,
+                    x1: var_x1,
+                    y1: var_y1,
+                };
+            }
+            5 => {
+                let mut var_elements =
+                    <Vec<crate::api::scene::PathElement>>::sse_decode(deserializer);
+                return crate::api::scene::Shape::Path {
+                    elements: var_elements,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::scene::StrokeStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_join = <crate::api::scene::LineJoin>::sse_decode(deserializer);
+        let mut var_miterLimit = <f64>::sse_decode(deserializer);
+        let mut var_startCap = <crate::api::scene::LineCap>::sse_decode(deserializer);
+        let mut var_endCap = <crate::api::scene::LineCap>::sse_decode(deserializer);
+        let mut var_dashPattern = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_dashOffset = <f64>::sse_decode(deserializer);
+        return crate::api::scene::StrokeStyle {
+            width: var_width,
+            join: var_join,
+            miter_limit: var_miterLimit,
+            start_cap: var_startCap,
+            end_cap: var_endCap,
+            dash_pattern: var_dashPattern,
+            dash_offset: var_dashOffset,
+        };
+    }
+}
+
+impl SseDecode for crate::api::svg::SvgScene {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_commands = <Vec<crate::api::scene::SceneCommand>>::sse_decode(deserializer);
+        let mut var_viewBox = <crate::api::svg::ViewBox>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::svg::SvgScene {
+            commands: var_commands,
+            view_box: var_viewBox,
+            warnings: var_warnings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::text::TextLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_glyphs = <Vec<crate::api::scene::Glyph>>::sse_decode(deserializer);
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_height = <f64>::sse_decode(deserializer);
+        let mut var_ascent = <f64>::sse_decode(deserializer);
+        let mut var_descent = <f64>::sse_decode(deserializer);
+        return crate::api::text::TextLayout {
+            glyphs: var_glyphs,
+            width: var_width,
+            height: var_height,
+            ascent: var_ascent,
+            descent: var_descent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::animation::Timeline {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tracks = <Vec<crate::api::animation::Track>>::sse_decode(deserializer);
+        let mut var_looping = <bool>::sse_decode(deserializer);
+        return crate::api::animation::Timeline {
+            tracks: var_tracks,
+            looping: var_looping,
+        };
+    }
+}
+
+impl SseDecode for crate::api::animation::Track {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_node = <u64>::sse_decode(deserializer);
+        let mut var_property = <crate::api::animation::AnimatedProperty>::sse_decode(deserializer);
+        let mut var_keyframes = <Vec<crate::api::animation::Keyframe>>::sse_decode(deserializer);
+        return crate::api::animation::Track {
+            node: var_node,
+            property: var_property,
+            keyframes: var_keyframes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scene::Transform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_xx = <f64>::sse_decode(deserializer);
+        let mut var_yx = <f64>::sse_decode(deserializer);
+        let mut var_xy = <f64>::sse_decode(deserializer);
+        let mut var_yy = <f64>::sse_decode(deserializer);
+        let mut var_dx = <f64>::sse_decode(deserializer);
+        let mut var_dy = <f64>::sse_decode(deserializer);
+        return crate::api::scene::Transform {
+            xx: var_xx,
+            yx: var_yx,
+            xy: var_xy,
+            yy: var_yy,
+            dx: var_dx,
+            dy: var_dy,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::svg::ViewBox {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <f64>::sse_decode(deserializer);
+        let mut var_y = <f64>::sse_decode(deserializer);
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_height = <f64>::sse_decode(deserializer);
+        return crate::api::svg::ViewBox {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire_clear_timeline_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire_pause_timeline_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire_play_timeline_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire_render_graph_at_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire_seek_timeline_impl(port, ptr, rust_vec_len, data_len),
+        1 => wire_set_timeline_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire_encode_png_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire_export_svg_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire_render_png_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire_hit_test_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire_render_graph_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire_stream_graph_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire_update_graph_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire_release_image_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire_upload_image_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire_upload_rgba_image_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire_load_lottie_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire_lottie_frame_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire_lottie_info_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire_release_lottie_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire_render_lottie_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire_create_renderer_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire_destroy_renderer_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire_init_app_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire_render_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire_render_scene_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire_set_scene_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire_subscribe_frames_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire_test_render_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire_import_svg_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire_layout_unshaped_text_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire_register_font_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire_release_font_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        23 => wire_demo_scene_impl(ptr, rust_vec_len, data_len),
+        22 => wire_identity_transform_impl(ptr, rust_vec_len, data_len),
+        34 => wire_default_render_options_impl(ptr, rust_vec_len, data_len),
+        24 => wire_greet_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AlphaMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Straight => 0.into_dart(),
+            Self::Premultiplied => 1.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::AlphaMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AlphaMode>
+    for crate::api::simple::AlphaMode
+{
+    fn into_into_dart(self) -> crate::api::simple::AlphaMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::animation::AnimatedProperty {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::animation::AnimatedProperty::Transform => [0.into_dart()].into_dart(),
+            crate::api::animation::AnimatedProperty::Opacity => [1.into_dart()].into_dart(),
+            crate::api::animation::AnimatedProperty::Color { command } => {
+                [2.into_dart(), command.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::animation::AnimatedProperty::StrokeWidth { command } => {
+                [3.into_dart(), command.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::animation::AnimatedProperty::Path { command } => {
+                [4.into_dart(), command.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::animation::AnimatedProperty
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::animation::AnimatedProperty>
+    for crate::api::animation::AnimatedProperty
+{
+    fn into_into_dart(self) -> crate::api::animation::AnimatedProperty {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::Antialiasing {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Area => 0.into_dart(),
+            Self::Msaa8 => 1.into_dart(),
+            Self::Msaa16 => 2.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::Antialiasing
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::Antialiasing>
+    for crate::api::simple::Antialiasing
+{
+    fn into_into_dart(self) -> crate::api::simple::Antialiasing {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::AntialiasingSupport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.area.into_into_dart().into_dart(),
+            self.msaa8.into_into_dart().into_dart(),
+            self.msaa16.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::AntialiasingSupport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::AntialiasingSupport>
+    for crate::api::simple::AntialiasingSupport
+{
+    fn into_into_dart(self) -> crate::api::simple::AntialiasingSupport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::BlendMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mix.into_into_dart().into_dart(),
+            self.compose.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::BlendMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::BlendMode>
+    for crate::api::scene::BlendMode
+{
+    fn into_into_dart(self) -> crate::api::scene::BlendMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Brush {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scene::Brush::Solid { color } => {
+                [0.into_dart(), color.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::scene::Brush::LinearGradient {
+                x0,
+                y0,
+                x1,
+                y1,
+                stops,
+                extend,
+            } => [
+                1.into_dart(),
+                x0.into_into_dart().into_dart(),
+                y0.into_into_dart().into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+                stops.into_into_dart().into_dart(),
+                extend.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Brush::RadialGradient {
+                x0,
+                y0,
+                r0,
+                x1,
+                y1,
+                r1,
+                stops,
+                extend,
+            } => [
+                2.into_dart(),
+                x0.into_into_dart().into_dart(),
+                y0.into_into_dart().into_dart(),
+                r0.into_into_dart().into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+                r1.into_into_dart().into_dart(),
+                stops.into_into_dart().into_dart(),
+                extend.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Brush::SweepGradient {
+                cx,
+                cy,
+                start_angle,
+                end_angle,
+                stops,
+                extend,
+            } => [
+                3.into_dart(),
+                cx.into_into_dart().into_dart(),
+                cy.into_into_dart().into_dart(),
+                start_angle.into_into_dart().into_dart(),
+                end_angle.into_into_dart().into_dart(),
+                stops.into_into_dart().into_dart(),
+                extend.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Brush::Image { image } => {
+                [4.into_dart(), image.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Brush {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Brush> for crate::api::scene::Brush {
+    fn into_into_dart(self) -> crate::api::scene::Brush {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ChannelOrder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rgba => 0.into_dart(),
+            Self::Bgra => 1.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ChannelOrder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ChannelOrder>
+    for crate::api::simple::ChannelOrder
+{
+    fn into_into_dart(self) -> crate::api::simple::ChannelOrder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Color {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.r.into_into_dart().into_dart(),
+            self.g.into_into_dart().into_dart(),
+            self.b.into_into_dart().into_dart(),
+            self.a.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Color {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Color> for crate::api::scene::Color {
+    fn into_into_dart(self) -> crate::api::scene::Color {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::ColorStop {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.offset.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::ColorStop {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::ColorStop>
+    for crate::api::scene::ColorStop
+{
+    fn into_into_dart(self) -> crate::api::scene::ColorStop {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Compose {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Clear => 0.into_dart(),
+            Self::Copy => 1.into_dart(),
+            Self::Dest => 2.into_dart(),
+            Self::SrcOver => 3.into_dart(),
+            Self::DestOver => 4.into_dart(),
+            Self::SrcIn => 5.into_dart(),
+            Self::DestIn => 6.into_dart(),
+            Self::SrcOut => 7.into_dart(),
+            Self::DestOut => 8.into_dart(),
+            Self::SrcAtop => 9.into_dart(),
+            Self::DestAtop => 10.into_dart(),
+            Self::Xor => 11.into_dart(),
+            Self::Plus => 12.into_dart(),
+            Self::PlusLighter => 13.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Compose {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Compose> for crate::api::scene::Compose {
+    fn into_into_dart(self) -> crate::api::scene::Compose {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::animation::Easing {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::animation::Easing::Hold => [0.into_dart()].into_dart(),
+            crate::api::animation::Easing::Linear => [1.into_dart()].into_dart(),
+            crate::api::animation::Easing::CubicBezier { x1, y1, x2, y2 } => [
+                2.into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+                x2.into_into_dart().into_dart(),
+                y2.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::animation::Easing::Spring {
+                stiffness,
+                damping,
+                mass,
+            } => [
+                3.into_dart(),
+                stiffness.into_into_dart().into_dart(),
+                damping.into_into_dart().into_dart(),
+                mass.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::animation::Easing {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::animation::Easing>
+    for crate::api::animation::Easing
+{
+    fn into_into_dart(self) -> crate::api::animation::Easing {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Extend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Pad => 0.into_dart(),
+            Self::Repeat => 1.into_dart(),
+            Self::Reflect => 2.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Extend {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Extend> for crate::api::scene::Extend {
+    fn into_into_dart(self) -> crate::api::scene::Extend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::FillRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NonZero => 0.into_dart(),
+            Self::EvenOdd => 1.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::FillRule {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::FillRule>
+    for crate::api::scene::FillRule
+{
+    fn into_into_dart(self) -> crate::api::scene::FillRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::Frame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.channel_order.into_into_dart().into_dart(),
+            self.alpha_mode.into_into_dart().into_dart(),
+            self.skipped_frames.into_into_dart().into_dart(),
+            self.frame_number.into_into_dart().into_dart(),
+            self.timestamp_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::simple::Frame {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::Frame> for crate::api::simple::Frame {
+    fn into_into_dart(self) -> crate::api::simple::Frame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Glyph {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Glyph {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Glyph> for crate::api::scene::Glyph {
+    fn into_into_dart(self) -> crate::api::scene::Glyph {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::graph::GraphUpdate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::graph::GraphUpdate::Insert {
+                id,
+                parent,
+                commands,
+                transform,
+                z_index,
+                visible,
+            } => [
+                0.into_dart(),
+                id.into_into_dart().into_dart(),
+                parent.into_into_dart().into_dart(),
+                commands.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+                z_index.into_into_dart().into_dart(),
+                visible.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::graph::GraphUpdate::SetCommands { id, commands } => [
+                1.into_dart(),
+                id.into_into_dart().into_dart(),
+                commands.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::graph::GraphUpdate::SetTransform { id, transform } => [
+                2.into_dart(),
+                id.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::graph::GraphUpdate::SetZIndex { id, z_index } => [
+                3.into_dart(),
+                id.into_into_dart().into_dart(),
+                z_index.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::graph::GraphUpdate::SetVisible { id, visible } => [
+                4.into_dart(),
+                id.into_into_dart().into_dart(),
+                visible.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::graph::GraphUpdate::Reparent { id, parent } => [
+                5.into_dart(),
+                id.into_into_dart().into_dart(),
+                parent.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::graph::GraphUpdate::Remove { id } => {
+                [6.into_dart(), id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::graph::GraphUpdate::Clear => [7.into_dart()].into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::graph::GraphUpdate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::graph::GraphUpdate>
+    for crate::api::graph::GraphUpdate
+{
+    fn into_into_dart(self) -> crate::api::graph::GraphUpdate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::ImageQuality {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Low => 0.into_dart(),
+            Self::Medium => 1.into_dart(),
+            Self::High => 2.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scene::ImageQuality
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::ImageQuality>
+    for crate::api::scene::ImageQuality
+{
+    fn into_into_dart(self) -> crate::api::scene::ImageQuality {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::animation::Keyframe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.easing.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::animation::Keyframe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::animation::Keyframe>
+    for crate::api::animation::Keyframe
+{
+    fn into_into_dart(self) -> crate::api::animation::Keyframe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::animation::KeyframeValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::animation::KeyframeValue::Transform(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::animation::KeyframeValue::Number(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::animation::KeyframeValue::Color(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::animation::KeyframeValue::Path(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::animation::KeyframeValue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::animation::KeyframeValue>
+    for crate::api::animation::KeyframeValue
+{
+    fn into_into_dart(self) -> crate::api::animation::KeyframeValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::LineCap {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Butt => 0.into_dart(),
+            Self::Square => 1.into_dart(),
+            Self::Round => 2.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::LineCap {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::LineCap> for crate::api::scene::LineCap {
+    fn into_into_dart(self) -> crate::api::scene::LineCap {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::LineJoin {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Bevel => 0.into_dart(),
+            Self::Miter => 1.into_dart(),
+            Self::Round => 2.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::LineJoin {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::LineJoin>
+    for crate::api::scene::LineJoin
+{
+    fn into_into_dart(self) -> crate::api::scene::LineJoin {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::lottie::LottieInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.animation.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.frame_rate.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::lottie::LottieInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::lottie::LottieInfo>
+    for crate::api::lottie::LottieInfo
+{
+    fn into_into_dart(self) -> crate::api::lottie::LottieInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Mix {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::Multiply => 1.into_dart(),
+            Self::Screen => 2.into_dart(),
+            Self::Overlay => 3.into_dart(),
+            Self::Darken => 4.into_dart(),
+            Self::Lighten => 5.into_dart(),
+            Self::ColorDodge => 6.into_dart(),
+            Self::ColorBurn => 7.into_dart(),
+            Self::HardLight => 8.into_dart(),
+            Self::SoftLight => 9.into_dart(),
+            Self::Difference => 10.into_dart(),
+            Self::Exclusion => 11.into_dart(),
+            Self::Hue => 12.into_dart(),
+            Self::Saturation => 13.into_dart(),
+            Self::Color => 14.into_dart(),
+            Self::Luminosity => 15.into_dart(),
+            Self::Clip => 16.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Mix {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Mix> for crate::api::scene::Mix {
+    fn into_into_dart(self) -> crate::api::scene::Mix {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::PathElement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scene::PathElement::MoveTo { x, y } => [
+                0.into_dart(),
+                x.into_into_dart().into_dart(),
+                y.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::PathElement::LineTo { x, y } => [
+                1.into_dart(),
+                x.into_into_dart().into_dart(),
+                y.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::PathElement::QuadTo { x1, y1, x, y } => [
+                2.into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+                x.into_into_dart().into_dart(),
+                y.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::PathElement::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => [
+                3.into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+                x2.into_into_dart().into_dart(),
+                y2.into_into_dart().into_dart(),
+                x.into_into_dart().into_dart(),
+                y.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::PathElement::Close => [4.into_dart()].into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scene::PathElement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::PathElement>
+    for crate::api::scene::PathElement
+{
+    fn into_into_dart(self) -> crate::api::scene::PathElement {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::export::PngCompression {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fast => 0.into_dart(),
+            Self::Default => 1.into_dart(),
+            Self::Best => 2.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::export::PngCompression
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::export::PngCompression>
+    for crate::api::export::PngCompression
+{
+    fn into_into_dart(self) -> crate::api::export::PngCompression {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RenderOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.antialiasing.into_into_dart().into_dart(),
+            self.background.into_into_dart().into_dart(),
+            self.alpha_mode.into_into_dart().into_dart(),
+            self.channel_order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RenderOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RenderOptions>
+    for crate::api::simple::RenderOptions
+{
+    fn into_into_dart(self) -> crate::api::simple::RenderOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RenderQueuePolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fifo => 0.into_dart(),
+            Self::LatestWins => 1.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RenderQueuePolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RenderQueuePolicy>
+    for crate::api::simple::RenderQueuePolicy
+{
+    fn into_into_dart(self) -> crate::api::simple::RenderQueuePolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RendererBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Gpu => 0.into_dart(),
+            Self::Cpu => 1.into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RendererBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RendererBackend>
+    for crate::api::simple::RendererBackend
+{
+    fn into_into_dart(self) -> crate::api::simple::RendererBackend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::simple::RendererConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backend.into_into_dart().into_dart(),
+            self.queue_policy.into_into_dart().into_dart(),
+            self.antialiasing_support.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::RendererConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::RendererConfig>
+    for crate::api::simple::RendererConfig
+{
+    fn into_into_dart(self) -> crate::api::simple::RendererConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::error::RendererError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::error::RendererError::AdapterNotFound => [0.into_dart()].into_dart(),
+            crate::api::error::RendererError::DeviceCreation(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::RendererCreation(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::Render(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::UnsupportedAntialiasing(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::Unsupported(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::Readback(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::Encoding(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::RendererNotFound(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::NodeNotFound(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::NodeExists(field0) => {
+                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::NodeCycle(field0) => {
+                [11.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::ImageNotFound(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::Decoding(field0) => {
+                [13.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::FontNotFound(field0) => {
+                [14.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::InvalidFont(field0) => {
+                [15.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::InvalidGlyph(field0) => {
+                [16.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::InvalidLayers(field0) => {
+                [17.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::InvalidSvg(field0) => {
+                [18.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::LottieNotFound(field0) => {
+                [19.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::InvalidLottie(field0) => {
+                [20.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::InvalidTimeline(field0) => {
+                [21.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::error::RendererError::TimelineNotSet => [22.into_dart()].into_dart(),
+            crate::api::error::RendererError::SceneNotSet => [23.into_dart()].into_dart(),
+            crate::api::error::RendererError::Disconnected => [24.into_dart()].into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::error::RendererError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::error::RendererError>
+    for crate::api::error::RendererError
+{
+    fn into_into_dart(self) -> crate::api::error::RendererError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::SceneCommand {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scene::SceneCommand::Fill {
+                shape,
+                brush,
+                fill_rule,
+                transform,
+                brush_transform,
+            } => [
+                0.into_dart(),
+                shape.into_into_dart().into_dart(),
+                brush.into_into_dart().into_dart(),
+                fill_rule.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+                brush_transform.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::SceneCommand::Stroke {
+                shape,
+                brush,
+                style,
+                transform,
+                brush_transform,
+            } => [
+                1.into_dart(),
+                shape.into_into_dart().into_dart(),
+                brush.into_into_dart().into_dart(),
+                style.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+                brush_transform.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::SceneCommand::Image {
+                image,
+                transform,
+                opacity,
+                quality,
+            } => [
+                2.into_dart(),
+                image.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+                opacity.into_into_dart().into_dart(),
+                quality.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::SceneCommand::GlyphRun {
+                font,
+                size,
+                glyphs,
+                brush,
+                transform,
+            } => [
+                3.into_dart(),
+                font.into_into_dart().into_dart(),
+                size.into_into_dart().into_dart(),
+                glyphs.into_into_dart().into_dart(),
+                brush.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::SceneCommand::PushLayer {
+                clip,
+                blend,
+                alpha,
+                transform,
+            } => [
+                4.into_dart(),
+                clip.into_into_dart().into_dart(),
+                blend.into_into_dart().into_dart(),
+                alpha.into_into_dart().into_dart(),
+                transform.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::SceneCommand::PopLayer => [5.into_dart()].into_dart(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scene::SceneCommand
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::SceneCommand>
+    for crate::api::scene::SceneCommand
+{
+    fn into_into_dart(self) -> crate::api::scene::SceneCommand {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Shape {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scene::Shape::Rect { x0, y0, x1, y1 } => [
+                0.into_dart(),
+                x0.into_into_dart().into_dart(),
+                y0.into_into_dart().into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Shape::RoundedRect {
+                x0,
+                y0,
+                x1,
+                y1,
+                radius,
+            } => [
+                1.into_dart(),
+                x0.into_into_dart().into_dart(),
+                y0.into_into_dart().into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+                radius.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Shape::Circle { cx, cy, radius } => [
+                2.into_dart(),
+                cx.into_into_dart().into_dart(),
+                cy.into_into_dart().into_dart(),
+                radius.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Shape::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                rotation,
+            } => [
+                3.into_dart(),
+                cx.into_into_dart().into_dart(),
+                cy.into_into_dart().into_dart(),
+                rx.into_into_dart().into_dart(),
+                ry.into_into_dart().into_dart(),
+                rotation.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Shape::Line { x0, y0, x1, y1 } => [
+                4.into_dart(),
+                x0.into_into_dart().into_dart(),
+                y0.into_into_dart().into_dart(),
+                x1.into_into_dart().into_dart(),
+                y1.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scene::Shape::Path { elements } => {
+                [5.into_dart(), elements.into_into_dart().into_dart()].into_dart()
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Shape {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Shape> for crate::api::scene::Shape {
+    fn into_into_dart(self) -> crate::api::scene::Shape {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::StrokeStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.join.into_into_dart().into_dart(),
+            self.miter_limit.into_into_dart().into_dart(),
+            self.start_cap.into_into_dart().into_dart(),
+            self.end_cap.into_into_dart().into_dart(),
+            self.dash_pattern.into_into_dart().into_dart(),
+            self.dash_offset.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scene::StrokeStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::StrokeStyle>
+    for crate::api::scene::StrokeStyle
+{
+    fn into_into_dart(self) -> crate::api::scene::StrokeStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::svg::SvgScene {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.commands.into_into_dart().into_dart(),
+            self.view_box.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::svg::SvgScene {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::svg::SvgScene> for crate::api::svg::SvgScene {
+    fn into_into_dart(self) -> crate::api::svg::SvgScene {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::text::TextLayout {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.glyphs.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.ascent.into_into_dart().into_dart(),
+            self.descent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::text::TextLayout {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::text::TextLayout>
+    for crate::api::text::TextLayout
+{
+    fn into_into_dart(self) -> crate::api::text::TextLayout {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::animation::Timeline {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tracks.into_into_dart().into_dart(),
+            self.looping.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::animation::Timeline
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::animation::Timeline>
+    for crate::api::animation::Timeline
+{
+    fn into_into_dart(self) -> crate::api::animation::Timeline {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::animation::Track {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node.into_into_dart().into_dart(),
+            self.property.into_into_dart().into_dart(),
+            self.keyframes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::animation::Track {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::animation::Track>
+    for crate::api::animation::Track
+{
+    fn into_into_dart(self) -> crate::api::animation::Track {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scene::Transform {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.xx.into_into_dart().into_dart(),
+            self.yx.into_into_dart().into_dart(),
+            self.xy.into_into_dart().into_dart(),
+            self.yy.into_into_dart().into_dart(),
+            self.dx.into_into_dart().into_dart(),
+            self.dy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::scene::Transform {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scene::Transform>
+    for crate::api::scene::Transform
+{
+    fn into_into_dart(self) -> crate::api::scene::Transform {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::svg::ViewBox {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::svg::ViewBox {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::svg::ViewBox> for crate::api::svg::ViewBox {
+    fn into_into_dart(self) -> crate::api::svg::ViewBox {
+        self
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::simple::Frame, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::AlphaMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::AlphaMode::Straight => 0,
+                crate::api::simple::AlphaMode::Premultiplied => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::animation::AnimatedProperty {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::animation::AnimatedProperty::Transform => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::animation::AnimatedProperty::Opacity => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::animation::AnimatedProperty::Color { command } => {
+                <i32>::sse_encode(2, serializer);
+                <u32>::sse_encode(command, serializer);
+            }
+            crate::api::animation::AnimatedProperty::StrokeWidth { command } => {
+                <i32>::sse_encode(3, serializer);
+                <u32>::sse_encode(command, serializer);
+            }
+            crate::api::animation::AnimatedProperty::Path { command } => {
+                <i32>::sse_encode(4, serializer);
+                <u32>::sse_encode(command, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::Antialiasing {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::Antialiasing::Area => 0,
+                crate::api::simple::Antialiasing::Msaa8 => 1,
+                crate::api::simple::Antialiasing::Msaa16 => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::AntialiasingSupport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.area, serializer);
+        <bool>::sse_encode(self.msaa8, serializer);
+        <bool>::sse_encode(self.msaa16, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scene::BlendMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::scene::Mix>::sse_encode(self.mix, serializer);
+        <crate::api::scene::Compose>::sse_encode(self.compose, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::scene::Brush {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::scene::Brush::Solid { color } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::scene::Color>::sse_encode(color, serializer);
+            }
+            crate::api::scene::Brush::LinearGradient {
+                x0,
+                y0,
+                x1,
+                y1,
+                stops,
+                extend,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(x0, serializer);
+                <f64>::sse_encode(y0, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+                <Vec<crate::api::scene::ColorStop>>::sse_encode(stops, serializer);
+                <crate::api::scene::Extend>::sse_encode(extend, serializer);
+            }
+            crate::api::scene::Brush::RadialGradient {
+                x0,
+                y0,
+                r0,
+                x1,
+                y1,
+                r1,
+                stops,
+                extend,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <f64>::sse_encode(x0, serializer);
+                <f64>::sse_encode(y0, serializer);
+                <f64>::sse_encode(r0, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+                <f64>::sse_encode(r1, serializer);
+                <Vec<crate::api::scene::ColorStop>>::sse_encode(stops, serializer);
+                <crate::api::scene::Extend>::sse_encode(extend, serializer);
+            }
+            crate::api::scene::Brush::SweepGradient {
+                cx,
+                cy,
+                start_angle,
+                end_angle,
+                stops,
+                extend,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <f64>::sse_encode(cx, serializer);
+                <f64>::sse_encode(cy, serializer);
+                <f64>::sse_encode(start_angle, serializer);
+                <f64>::sse_encode(end_angle, serializer);
+                <Vec<crate::api::scene::ColorStop>>::sse_encode(stops, serializer);
+                <crate::api::scene::Extend>::sse_encode(extend, serializer);
+            }
+            crate::api::scene::Brush::Image { image } => {
+                <i32>::sse_encode(4, serializer);
+                <u64>::sse_encode(image, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::simple::ChannelOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::ChannelOrder::Rgba => 0,
+                crate::api::simple::ChannelOrder::Bgra => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::scene::Color {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.r, serializer);
+        <u8>::sse_encode(self.g, serializer);
+        <u8>::sse_encode(self.b, serializer);
+        <u8>::sse_encode(self.a, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scene::ColorStop {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.offset, serializer);
+        <crate::api::scene::Color>::sse_encode(self.color, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scene::Compose {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::Compose::Clear => 0,
+                crate::api::scene::Compose::Copy => 1,
+                crate::api::scene::Compose::Dest => 2,
+                crate::api::scene::Compose::SrcOver => 3,
+                crate::api::scene::Compose::DestOver => 4,
+                crate::api::scene::Compose::SrcIn => 5,
+                crate::api::scene::Compose::DestIn => 6,
+                crate::api::scene::Compose::SrcOut => 7,
+                crate::api::scene::Compose::DestOut => 8,
+                crate::api::scene::Compose::SrcAtop => 9,
+                crate::api::scene::Compose::DestAtop => 10,
+                crate::api::scene::Compose::Xor => 11,
+                crate::api::scene::Compose::Plus => 12,
+                crate::api::scene::Compose::PlusLighter => 13,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::animation::Easing {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::animation::Easing::Hold => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::animation::Easing::Linear => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::animation::Easing::CubicBezier { x1, y1, x2, y2 } => {
+                <i32>::sse_encode(2, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+                <f64>::sse_encode(x2, serializer);
+                <f64>::sse_encode(y2, serializer);
+            }
+            crate::api::animation::Easing::Spring {
+                stiffness,
+                damping,
+                mass,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <f64>::sse_encode(stiffness, serializer);
+                <f64>::sse_encode(damping, serializer);
+                <f64>::sse_encode(mass, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scene::Extend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::Extend::Pad => 0,
+                crate::api::scene::Extend::Repeat => 1,
+                crate::api::scene::Extend::Reflect => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::scene::FillRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::FillRule::NonZero => 0,
+                crate::api::scene::FillRule::EvenOdd => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::Frame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <crate::api::simple::ChannelOrder>::sse_encode(self.channel_order, serializer);
+        <crate::api::simple::AlphaMode>::sse_encode(self.alpha_mode, serializer);
+        <u32>::sse_encode(self.skipped_frames, serializer);
+        <u64>::sse_encode(self.frame_number, serializer);
+        <u64>::sse_encode(self.timestamp_micros, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scene::Glyph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <f64>::sse_encode(self.x, serializer);
+        <f64>::sse_encode(self.y, serializer);
+    }
+}
+
+impl SseEncode for crate::api::graph::GraphUpdate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::graph::GraphUpdate::Insert {
+                id,
+                parent,
+                commands,
+                transform,
+                z_index,
+                visible,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <u64>::sse_encode(id, serializer);
+                <Option<u64>>::sse_encode(parent, serializer);
+                <Vec<crate::api::scene::SceneCommand>>::sse_encode(commands, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+                <i32>::sse_encode(z_index, serializer);
+                <bool>::sse_encode(visible, serializer);
+            }
+            crate::api::graph::GraphUpdate::SetCommands { id, commands } => {
+                <i32>::sse_encode(1, serializer);
+                <u64>::sse_encode(id, serializer);
+                <Vec<crate::api::scene::SceneCommand>>::sse_encode(commands, serializer);
+            }
+            crate::api::graph::GraphUpdate::SetTransform { id, transform } => {
+                <i32>::sse_encode(2, serializer);
+                <u64>::sse_encode(id, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+            }
+            crate::api::graph::GraphUpdate::SetZIndex { id, z_index } => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(id, serializer);
+                <i32>::sse_encode(z_index, serializer);
+            }
+            crate::api::graph::GraphUpdate::SetVisible { id, visible } => {
+                <i32>::sse_encode(4, serializer);
+                <u64>::sse_encode(id, serializer);
+                <bool>::sse_encode(visible, serializer);
+            }
+            crate::api::graph::GraphUpdate::Reparent { id, parent } => {
+                <i32>::sse_encode(5, serializer);
+                <u64>::sse_encode(id, serializer);
+                <Option<u64>>::sse_encode(parent, serializer);
+            }
+            crate::api::graph::GraphUpdate::Remove { id } => {
+                <i32>::sse_encode(6, serializer);
+                <u64>::sse_encode(id, serializer);
+            }
+            crate::api::graph::GraphUpdate::Clear => {
+                <i32>::sse_encode(7, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::scene::ImageQuality {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::ImageQuality::Low => 0,
+                crate::api::scene::ImageQuality::Medium => 1,
+                crate::api::scene::ImageQuality::High => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::animation::Keyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.time, serializer);
+        <crate::api::animation::KeyframeValue>::sse_encode(self.value, serializer);
+        <crate::api::animation::Easing>::sse_encode(self.easing, serializer);
+    }
+}
+
+impl SseEncode for crate::api::animation::KeyframeValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::animation::KeyframeValue::Transform(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::scene::Transform>::sse_encode(field0, serializer);
+            }
+            crate::api::animation::KeyframeValue::Number(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(field0, serializer);
+            }
+            crate::api::animation::KeyframeValue::Color(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::scene::Color>::sse_encode(field0, serializer);
+            }
+            crate::api::animation::KeyframeValue::Path(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<crate::api::scene::PathElement>>::sse_encode(field0, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scene::LineCap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::LineCap::Butt => 0,
+                crate::api::scene::LineCap::Square => 1,
+                crate::api::scene::LineCap::Round => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::scene::LineJoin {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::LineJoin::Bevel => 0,
+                crate::api::scene::LineJoin::Miter => 1,
+                crate::api::scene::LineJoin::Round => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scene::ColorStop> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scene::ColorStop>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scene::Glyph> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scene::Glyph>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::graph::GraphUpdate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::graph::GraphUpdate>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::animation::Keyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::animation::Keyframe>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::scene::PathElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scene::PathElement>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u64>::sse_encode(item, serializer);
+        }
     }
 }
 
@@ -244,29 +4406,570 @@ impl SseEncode for Vec<u8> {
     }
 }
 
-impl SseEncode for u8 {
+impl SseEncode for Vec<crate::api::scene::SceneCommand> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scene::SceneCommand>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for () {
+impl SseEncode for Vec<crate::api::animation::Track> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::animation::Track>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::lottie::LottieInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <u64>::sse_encode(self.animation, serializer);
+        <f64>::sse_encode(self.width, serializer);
+        <f64>::sse_encode(self.height, serializer);
+        <f64>::sse_encode(self.frame_rate, serializer);
+        <f64>::sse_encode(self.duration, serializer);
+        <Vec<String>>::sse_encode(self.warnings, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::scene::Mix {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::scene::Mix::Normal => 0,
+                crate::api::scene::Mix::Multiply => 1,
+                crate::api::scene::Mix::Screen => 2,
+                crate::api::scene::Mix::Overlay => 3,
+                crate::api::scene::Mix::Darken => 4,
+                crate::api::scene::Mix::Lighten => 5,
+                crate::api::scene::Mix::ColorDodge => 6,
+                crate::api::scene::Mix::ColorBurn => 7,
+                crate::api::scene::Mix::HardLight => 8,
+                crate::api::scene::Mix::SoftLight => 9,
+                crate::api::scene::Mix::Difference => 10,
+                crate::api::scene::Mix::Exclusion => 11,
+                crate::api::scene::Mix::Hue => 12,
+                crate::api::scene::Mix::Saturation => 13,
+                crate::api::scene::Mix::Color => 14,
+                crate::api::scene::Mix::Luminosity => 15,
+                crate::api::scene::Mix::Clip => 16,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<crate::api::simple::Frame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::simple::Frame>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::export::PngCompression> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::export::PngCompression>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::scene::Transform> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::scene::Transform>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::scene::PathElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::scene::PathElement::MoveTo { x, y } => {
+                <i32>::sse_encode(0, serializer);
+                <f64>::sse_encode(x, serializer);
+                <f64>::sse_encode(y, serializer);
+            }
+            crate::api::scene::PathElement::LineTo { x, y } => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(x, serializer);
+                <f64>::sse_encode(y, serializer);
+            }
+            crate::api::scene::PathElement::QuadTo { x1, y1, x, y } => {
+                <i32>::sse_encode(2, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+                <f64>::sse_encode(x, serializer);
+                <f64>::sse_encode(y, serializer);
+            }
+            crate::api::scene::PathElement::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+                <f64>::sse_encode(x2, serializer);
+                <f64>::sse_encode(y2, serializer);
+                <f64>::sse_encode(x, serializer);
+                <f64>::sse_encode(y, serializer);
+            }
+            crate::api::scene::PathElement::Close => {
+                <i32>::sse_encode(4, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::export::PngCompression {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::export::PngCompression::Fast => 0,
+                crate::api::export::PngCompression::Default => 1,
+                crate::api::export::PngCompression::Best => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::RenderOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::Antialiasing>::sse_encode(self.antialiasing, serializer);
+        <crate::api::scene::Color>::sse_encode(self.background, serializer);
+        <crate::api::simple::AlphaMode>::sse_encode(self.alpha_mode, serializer);
+        <crate::api::simple::ChannelOrder>::sse_encode(self.channel_order, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::RenderQueuePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::RenderQueuePolicy::Fifo => 0,
+                crate::api::simple::RenderQueuePolicy::LatestWins => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::RendererBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::simple::RendererBackend::Gpu => 0,
+                crate::api::simple::RendererBackend::Cpu => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::simple::RendererConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::simple::RendererBackend>::sse_encode(self.backend, serializer);
+        <crate::api::simple::RenderQueuePolicy>::sse_encode(self.queue_policy, serializer);
+        <crate::api::simple::AntialiasingSupport>::sse_encode(
+            self.antialiasing_support,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::error::RendererError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::error::RendererError::AdapterNotFound => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::error::RendererError::DeviceCreation(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::RendererCreation(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::Render(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::UnsupportedAntialiasing(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <crate::api::simple::Antialiasing>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::Unsupported(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::Readback(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::Encoding(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::RendererNotFound(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::NodeNotFound(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::NodeExists(field0) => {
+                <i32>::sse_encode(10, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::NodeCycle(field0) => {
+                <i32>::sse_encode(11, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::ImageNotFound(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::Decoding(field0) => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::FontNotFound(field0) => {
+                <i32>::sse_encode(14, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::InvalidFont(field0) => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::InvalidGlyph(field0) => {
+                <i32>::sse_encode(16, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::InvalidLayers(field0) => {
+                <i32>::sse_encode(17, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::InvalidSvg(field0) => {
+                <i32>::sse_encode(18, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::LottieNotFound(field0) => {
+                <i32>::sse_encode(19, serializer);
+                <u64>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::InvalidLottie(field0) => {
+                <i32>::sse_encode(20, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::InvalidTimeline(field0) => {
+                <i32>::sse_encode(21, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::error::RendererError::TimelineNotSet => {
+                <i32>::sse_encode(22, serializer);
+            }
+            crate::api::error::RendererError::SceneNotSet => {
+                <i32>::sse_encode(23, serializer);
+            }
+            crate::api::error::RendererError::Disconnected => {
+                <i32>::sse_encode(24, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scene::SceneCommand {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::scene::SceneCommand::Fill {
+                shape,
+                brush,
+                fill_rule,
+                transform,
+                brush_transform,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::scene::Shape>::sse_encode(shape, serializer);
+                <crate::api::scene::Brush>::sse_encode(brush, serializer);
+                <crate::api::scene::FillRule>::sse_encode(fill_rule, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+                <Option<crate::api::scene::Transform>>::sse_encode(brush_transform, serializer);
+            }
+            crate::api::scene::SceneCommand::Stroke {
+                shape,
+                brush,
+                style,
+                transform,
+                brush_transform,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::scene::Shape>::sse_encode(shape, serializer);
+                <crate::api::scene::Brush>::sse_encode(brush, serializer);
+                <crate::api::scene::StrokeStyle>::sse_encode(style, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+                <Option<crate::api::scene::Transform>>::sse_encode(brush_transform, serializer);
+            }
+            crate::api::scene::SceneCommand::Image {
+                image,
+                transform,
+                opacity,
+                quality,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <u64>::sse_encode(image, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+                <f64>::sse_encode(opacity, serializer);
+                <crate::api::scene::ImageQuality>::sse_encode(quality, serializer);
+            }
+            crate::api::scene::SceneCommand::GlyphRun {
+                font,
+                size,
+                glyphs,
+                brush,
+                transform,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(font, serializer);
+                <f64>::sse_encode(size, serializer);
+                <Vec<crate::api::scene::Glyph>>::sse_encode(glyphs, serializer);
+                <crate::api::scene::Brush>::sse_encode(brush, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+            }
+            crate::api::scene::SceneCommand::PushLayer {
+                clip,
+                blend,
+                alpha,
+                transform,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <crate::api::scene::Shape>::sse_encode(clip, serializer);
+                <crate::api::scene::BlendMode>::sse_encode(blend, serializer);
+                <f64>::sse_encode(alpha, serializer);
+                <crate::api::scene::Transform>::sse_encode(transform, serializer);
+            }
+            crate::api::scene::SceneCommand::PopLayer => {
+                <i32>::sse_encode(5, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scene::Shape {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::scene::Shape::Rect { x0, y0, x1, y1 } => {
+                <i32>::sse_encode(0, serializer);
+                <f64>::sse_encode(x0, serializer);
+                <f64>::sse_encode(y0, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+            }
+            crate::api::scene::Shape::RoundedRect {
+                x0,
+                y0,
+                x1,
+                y1,
+                radius,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(x0, serializer);
+                <f64>::sse_encode(y0, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+                <f64>::sse_encode(radius, serializer);
+            }
+            crate::api::scene::Shape::Circle { cx, cy, radius } => {
+                <i32>::sse_encode(2, serializer);
+                <f64>::sse_encode(cx, serializer);
+                <f64>::sse_encode(cy, serializer);
+                <f64>::sse_encode(radius, serializer);
+            }
+            crate::api::scene::Shape::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                rotation,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <f64>::sse_encode(cx, serializer);
+                <f64>::sse_encode(cy, serializer);
+                <f64>::sse_encode(rx, serializer);
+                <f64>::sse_encode(ry, serializer);
+                <f64>::sse_encode(rotation, serializer);
+            }
+            crate::api::scene::Shape::Line { x0, y0, x1, y1 } => {
+                <i32>::sse_encode(4, serializer);
+                <f64>::sse_encode(x0, serializer);
+                <f64>::sse_encode(y0, serializer);
+                <f64>::sse_encode(x1, serializer);
+                <f64>::sse_encode(y1, serializer);
+            }
+            crate::api::scene::Shape::Path { elements } => {
+                <i32>::sse_encode(5, serializer);
+                <Vec<crate::api::scene::PathElement>>::sse_encode(elements, serializer);
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::scene::StrokeStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.width, serializer);
+        <crate::api::scene::LineJoin>::sse_encode(self.join, serializer);
+        <f64>::sse_encode(self.miter_limit, serializer);
+        <crate::api::scene::LineCap>::sse_encode(self.start_cap, serializer);
+        <crate::api::scene::LineCap>::sse_encode(self.end_cap, serializer);
+        <Vec<f64>>::sse_encode(self.dash_pattern, serializer);
+        <f64>::sse_encode(self.dash_offset, serializer);
+    }
+}
+
+impl SseEncode for crate::api::svg::SvgScene {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::scene::SceneCommand>>::sse_encode(self.commands, serializer);
+        <crate::api::svg::ViewBox>::sse_encode(self.view_box, serializer);
+        <Vec<String>>::sse_encode(self.warnings, serializer);
+    }
+}
+
+impl SseEncode for crate::api::text::TextLayout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::scene::Glyph>>::sse_encode(self.glyphs, serializer);
+        <f64>::sse_encode(self.width, serializer);
+        <f64>::sse_encode(self.height, serializer);
+        <f64>::sse_encode(self.ascent, serializer);
+        <f64>::sse_encode(self.descent, serializer);
+    }
+}
+
+impl SseEncode for crate::api::animation::Timeline {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::animation::Track>>::sse_encode(self.tracks, serializer);
+        <bool>::sse_encode(self.looping, serializer);
+    }
+}
+
+impl SseEncode for crate::api::animation::Track {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.node, serializer);
+        <crate::api::animation::AnimatedProperty>::sse_encode(self.property, serializer);
+        <Vec<crate::api::animation::Keyframe>>::sse_encode(self.keyframes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scene::Transform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.xx, serializer);
+        <f64>::sse_encode(self.yx, serializer);
+        <f64>::sse_encode(self.xy, serializer);
+        <f64>::sse_encode(self.yy, serializer);
+        <f64>::sse_encode(self.dx, serializer);
+        <f64>::sse_encode(self.dy, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self).unwrap();
+    }
+}
+
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for crate::api::svg::ViewBox {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.x, serializer);
+        <f64>::sse_encode(self.y, serializer);
+        <f64>::sse_encode(self.width, serializer);
+        <f64>::sse_encode(self.height, serializer);
     }
 }
 
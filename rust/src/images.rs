@@ -0,0 +1,173 @@
+//! Images uploaded to a renderer, which scene commands refer to by handle.
+
+use crate::api::error::RendererError;
+use crate::api::simple::AlphaMode;
+use crate::api::simple::ChannelOrder;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+use vello::peniko;
+use vello::peniko::Blob;
+use vello::peniko::Format;
+
+/// The images of one renderer, kept on its render thread.
+#[derive(Default)]
+pub(crate) struct ImageRegistry {
+    images: HashMap<u64, peniko::Image>,
+    next_handle: u64,
+}
+
+impl ImageRegistry {
+    /// Stores `image` under a new handle.
+    pub(crate) fn insert(&mut self, image: peniko::Image) -> u64 {
+        let handle = self.next_handle;
+        self.next_handle += 1;
+        self.images.insert(handle, image);
+        handle
+    }
+
+    pub(crate) fn remove(&mut self, handle: u64) -> Result<(), RendererError> {
+        self.images
+            .remove(&handle)
+            .map(|_| ())
+            .ok_or(RendererError::ImageNotFound(handle))
+    }
+
+    pub(crate) fn get(&self, handle: u64) -> Result<&peniko::Image, RendererError> {
+        self.images
+            .get(&handle)
+            .ok_or(RendererError::ImageNotFound(handle))
+    }
+}
+
+/// Decodes a PNG or JPEG file, told apart by its signature.
+pub(crate) fn decode(bytes: &[u8]) -> Result<peniko::Image, RendererError> {
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    const JPEG_SIGNATURE: &[u8] = b"\xff\xd8\xff";
+    if bytes.starts_with(PNG_SIGNATURE) {
+        decode_png(bytes)
+    } else if bytes.starts_with(JPEG_SIGNATURE) {
+        decode_jpeg(bytes)
+    } else {
+        Err(RendererError::Decoding(
+            "not a PNG or JPEG file".to_string(),
+        ))
+    }
+}
+
+/// Wraps straight-alpha RGBA pixels, checking that they fill the size.
+pub(crate) fn from_rgba(
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+) -> Result<peniko::Image, RendererError> {
+    let expected = Format::Rgba8.size_in_bytes(width, height);
+    if expected != Some(data.len()) {
+        return Err(RendererError::Decoding(format!(
+            "{} bytes don't make a {width}x{height} RGBA image",
+            data.len()
+        )));
+    }
+    Ok(peniko::Image::new(
+        Blob::new(Arc::new(data)),
+        Format::Rgba8,
+        width,
+        height,
+    ))
+}
+
+/// Encodes straight-alpha RGBA pixels as an 8 bit RGBA PNG file.
+/// Rearranges pixels laid out as `channel_order` and `alpha_mode` say into
+/// straight RGBA.
+pub(crate) fn to_straight_rgba(
+    data: &mut [u8],
+    channel_order: ChannelOrder,
+    alpha_mode: AlphaMode,
+) {
+    for pixel in data.chunks_exact_mut(4) {
+        if channel_order == ChannelOrder::Bgra {
+            pixel.swap(0, 2);
+        }
+        let alpha = u32::from(pixel[3]);
+        // Fully transparent pixels stay black.
+        if alpha_mode == AlphaMode::Premultiplied && alpha != 0 {
+            for channel in &mut pixel[..3] {
+                *channel = ((u32::from(*channel) * 255 + alpha / 2) / alpha).min(255) as u8;
+            }
+        }
+    }
+}
+
+pub(crate) fn encode_png(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    compression: png::Compression,
+) -> Result<Vec<u8>, RendererError> {
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_compression(compression);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(data))
+        .map_err(|e| RendererError::Encoding(e.to_string()))?;
+    Ok(png)
+}
+
+fn decode_png(bytes: &[u8]) -> Result<peniko::Image, RendererError> {
+    let error = |e: png::DecodingError| RendererError::Decoding(e.to_string());
+    let mut decoder = png::Decoder::new(Cursor::new(bytes));
+    // Palettes and low bit depths become 8 bit gray or RGB, 16 bit channels
+    // are cut down to 8.
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(error)?;
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data).map_err(error)?;
+    data.truncate(info.buffer_size());
+    let rgba = match info.color_type {
+        png::ColorType::Rgba => data,
+        png::ColorType::Rgb => data
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => data
+            .chunks_exact(2)
+            .flat_map(|la| [la[0], la[0], la[0], la[1]])
+            .collect(),
+        png::ColorType::Grayscale => data.iter().flat_map(|&l| [l, l, l, 255]).collect(),
+        png::ColorType::Indexed => unreachable!("EXPAND resolves palettes"),
+    };
+    from_rgba(rgba, info.width, info.height)
+}
+
+fn decode_jpeg(bytes: &[u8]) -> Result<peniko::Image, RendererError> {
+    let mut decoder = jpeg_decoder::Decoder::new(bytes);
+    let data = decoder
+        .decode()
+        .map_err(|e| RendererError::Decoding(e.to_string()))?;
+    let info = decoder.info().expect("decoded JPEGs have info");
+    let rgba = match info.pixel_format {
+        jpeg_decoder::PixelFormat::RGB24 => data
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        jpeg_decoder::PixelFormat::L8 => data.iter().flat_map(|&l| [l, l, l, 255]).collect(),
+        // Big endian, so the high byte comes first.
+        jpeg_decoder::PixelFormat::L16 => data
+            .chunks_exact(2)
+            .flat_map(|l| [l[0], l[0], l[0], 255])
+            .collect(),
+        // Adobe's CMYK JPEGs store inverted values.
+        jpeg_decoder::PixelFormat::CMYK32 => data
+            .chunks_exact(4)
+            .flat_map(|cmyk| {
+                let k = cmyk[3] as u32;
+                let channel = |c: u8| (c as u32 * k / 255) as u8;
+                [channel(cmyk[0]), channel(cmyk[1]), channel(cmyk[2]), 255]
+            })
+            .collect(),
+    };
+    from_rgba(rgba, info.width.into(), info.height.into())
+}
@@ -1,2 +1,13 @@
+mod animation;
 pub mod api;
+mod convert;
+mod easing;
+mod encode;
+mod fonts;
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+mod images;
+mod lottie;
+mod renderer;
+mod scene_graph;
+mod svg;
+mod svg_export;
@@ -0,0 +1,252 @@
+//! Lottie animations loaded from Dart, shared by every renderer, and their
+//! playback as scene commands.
+
+mod model;
+mod render;
+mod trim;
+
+use crate::api::error::RendererError;
+use crate::api::lottie::LottieInfo;
+use crate::api::scene::SceneCommand;
+use model::Composition;
+use model::Layer;
+use model::LayerKind;
+use model::MaskMode;
+use model::ShapeItem;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use vello::kurbo::Affine;
+
+pub(crate) struct Animation {
+    composition: Composition,
+    info: LottieInfo,
+}
+
+#[derive(Default)]
+struct AnimationRegistry {
+    animations: HashMap<u64, Arc<Animation>>,
+    next_handle: u64,
+}
+
+lazy_static::lazy_static!(
+    static ref ANIMATIONS: RwLock<AnimationRegistry> = RwLock::new(AnimationRegistry::default());
+);
+
+/// Parses a Lottie composition and stores it under a new handle.
+pub(crate) fn load(json: &str) -> Result<LottieInfo, RendererError> {
+    let invalid = |e: &dyn std::fmt::Display| RendererError::InvalidLottie(e.to_string());
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| invalid(&e))?;
+    let mut warnings = Warnings::default();
+    // Properties driven by expressions are read as their keyframes.
+    if has_expressions(&value) {
+        warnings.add("expressions aren't supported");
+    }
+    let composition: Composition = serde_json::from_value(value).map_err(|e| invalid(&e))?;
+    let valid = |value: f64| value.is_finite() && value > 0.0;
+    if !valid(composition.frame_rate)
+        || !valid(composition.width)
+        || !valid(composition.height)
+        || !valid(composition.out_point - composition.in_point)
+    {
+        return Err(invalid(&"the frame rate, size or duration is not positive"));
+    }
+    check_assets(&composition)?;
+    warnings.layers(&composition, &composition.layers);
+    for asset in &composition.assets {
+        if let Some(layers) = &asset.layers {
+            warnings.layers(&composition, layers);
+        }
+    }
+
+    // Nothing can panic while the registry is locked, so it's never poisoned.
+    let mut registry = ANIMATIONS.write().unwrap();
+    let handle = registry.next_handle;
+    registry.next_handle += 1;
+    let info = LottieInfo {
+        animation: handle,
+        width: composition.width,
+        height: composition.height,
+        frame_rate: composition.frame_rate,
+        duration: (composition.out_point - composition.in_point) / composition.frame_rate,
+        warnings: warnings.0,
+    };
+    registry.animations.insert(
+        handle,
+        Arc::new(Animation {
+            composition,
+            info: info.clone(),
+        }),
+    );
+    Ok(info)
+}
+
+pub(crate) fn release(handle: u64) -> Result<(), RendererError> {
+    ANIMATIONS
+        .write()
+        .unwrap()
+        .animations
+        .remove(&handle)
+        .map(|_| ())
+        .ok_or(RendererError::LottieNotFound(handle))
+}
+
+pub(crate) fn get(handle: u64) -> Result<Arc<Animation>, RendererError> {
+    ANIMATIONS
+        .read()
+        .unwrap()
+        .animations
+        .get(&handle)
+        .cloned()
+        .ok_or(RendererError::LottieNotFound(handle))
+}
+
+impl Animation {
+    pub(crate) fn info(&self) -> &LottieInfo {
+        &self.info
+    }
+
+    /// Draws the animation `time` seconds after its start, with its
+    /// coordinates mapped by `transform`. Times outside of the animation show
+    /// its first or last frame.
+    pub(crate) fn frame(&self, time: f64, transform: Affine) -> Vec<SceneCommand> {
+        let composition = &self.composition;
+        // The out point is the first frame that isn't shown.
+        let last_frame = (composition.out_point - 1.0).max(composition.in_point);
+        let frame = (composition.in_point + time * composition.frame_rate)
+            .clamp(composition.in_point, last_frame);
+        render::commands(composition, frame, transform)
+    }
+}
+
+/// Rejects precompositions that contain themselves, which would never finish
+/// drawing.
+fn check_assets(composition: &Composition) -> Result<(), RendererError> {
+    let layers_of = |id: &str| {
+        composition
+            .assets
+            .iter()
+            .find(|asset| asset.id == id)
+            .and_then(|asset| asset.layers.as_deref())
+            .unwrap_or_default()
+    };
+    let references = |layers: &[Layer]| -> Vec<String> {
+        layers
+            .iter()
+            .filter(|layer| layer.kind == LayerKind::Precomposition)
+            .filter_map(|layer| layer.asset.clone())
+            .collect()
+    };
+    for asset in &composition.assets {
+        let mut pending = references(layers_of(&asset.id));
+        let mut visited = Vec::new();
+        while let Some(id) = pending.pop() {
+            if id == asset.id {
+                return Err(RendererError::InvalidLottie(format!(
+                    "precomposition {id} contains itself"
+                )));
+            }
+            if !visited.contains(&id) {
+                pending.extend(references(layers_of(&id)));
+                visited.push(id);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether a property anywhere in `value` has an expression.
+fn has_expressions(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(object) => {
+            (object.contains_key("k") && object.get("x").is_some_and(|x| x.is_string()))
+                || object.values().any(has_expressions)
+        }
+        serde_json::Value::Array(values) => values.iter().any(has_expressions),
+        _ => false,
+    }
+}
+
+/// One message for every feature of an animation that is left out or drawn
+/// differently than in After Effects.
+#[derive(Default)]
+struct Warnings(Vec<String>);
+
+impl Warnings {
+    fn add(&mut self, warning: &str) {
+        if !self.0.iter().any(|w| w == warning) {
+            self.0.push(warning.to_string());
+        }
+    }
+
+    fn layers(&mut self, composition: &Composition, layers: &[Layer]) {
+        for layer in layers {
+            match layer.kind {
+                LayerKind::Image => self.add("image layers aren't supported"),
+                LayerKind::Text => self.add("text layers aren't supported"),
+                LayerKind::Other(_) => self.add("some layer types aren't supported"),
+                LayerKind::Precomposition => {
+                    let found = composition.assets.iter().any(|asset| {
+                        Some(&asset.id) == layer.asset.as_ref() && asset.layers.is_some()
+                    });
+                    if !found {
+                        self.add("precomposition layers without their asset are left out");
+                    }
+                }
+                LayerKind::Solid | LayerKind::Null | LayerKind::Shape => {}
+            }
+            if layer.matte_mode.is_some() || layer.matte_source.is_some() {
+                self.add("track mattes aren't supported");
+            }
+            if layer.time_remap.is_some() {
+                self.add("time remapping isn't supported");
+            }
+            if !layer.effects.is_empty() {
+                self.add("layer effects aren't supported");
+            }
+            for mask in &layer.masks {
+                if mask.mode == MaskMode::Other {
+                    self.add("lighten, darken and difference masks are drawn as added masks");
+                }
+                let expanded = mask.expansion.as_ref().is_some_and(|expansion| {
+                    expansion.is_animated() || expansion.at(0.0).get(0) != 0.0
+                });
+                if expanded {
+                    self.add("mask expansion isn't supported");
+                }
+            }
+            self.shapes(&layer.shapes);
+        }
+    }
+
+    fn shapes(&mut self, items: &[ShapeItem]) {
+        for item in items {
+            match item {
+                ShapeItem::Group { items, .. } => self.shapes(items),
+                ShapeItem::Star {
+                    outer_roundness,
+                    inner_roundness,
+                    ..
+                } => {
+                    let rounded = [outer_roundness, inner_roundness]
+                        .into_iter()
+                        .flatten()
+                        .any(|roundness| {
+                            roundness.is_animated() || roundness.at(0.0).get(0) != 0.0
+                        });
+                    if rounded {
+                        self.add("rounded stars and polygons are drawn with sharp corners");
+                    }
+                }
+                ShapeItem::Trim { mode: 2, .. } => {
+                    self.add("trim paths trim every path on its own");
+                }
+                ShapeItem::Merge {} => self.add("merge paths aren't supported"),
+                ShapeItem::Repeater {} => self.add("repeaters aren't supported"),
+                ShapeItem::RoundCorners {} => self.add("round corners aren't supported"),
+                ShapeItem::Other => self.add("some shape types aren't supported"),
+                _ => {}
+            }
+        }
+    }
+}
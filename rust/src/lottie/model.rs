@@ -0,0 +1,728 @@
+//! The subset of the Lottie JSON format the player reads, and evaluation of
+//! its animated properties.
+
+use crate::easing::cubic_bezier;
+use serde::Deserialize;
+use serde::Deserializer;
+use vello::kurbo::BezPath;
+use vello::kurbo::CubicBez;
+use vello::kurbo::ParamCurve;
+use vello::kurbo::Point;
+use vello::kurbo::Vec2;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Composition {
+    #[serde(rename = "fr")]
+    pub(crate) frame_rate: f64,
+    #[serde(rename = "ip")]
+    pub(crate) in_point: f64,
+    #[serde(rename = "op")]
+    pub(crate) out_point: f64,
+    #[serde(rename = "w")]
+    pub(crate) width: f64,
+    #[serde(rename = "h")]
+    pub(crate) height: f64,
+    #[serde(default)]
+    pub(crate) layers: Vec<Layer>,
+    #[serde(default)]
+    pub(crate) assets: Vec<Asset>,
+}
+
+/// Precompositions have layers, image assets don't.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Asset {
+    pub(crate) id: String,
+    pub(crate) layers: Option<Vec<Layer>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Layer {
+    #[serde(rename = "ty")]
+    pub(crate) kind: LayerKind,
+    #[serde(rename = "ind")]
+    pub(crate) index: Option<i64>,
+    pub(crate) parent: Option<i64>,
+    #[serde(rename = "ip")]
+    pub(crate) in_point: f64,
+    #[serde(rename = "op")]
+    pub(crate) out_point: f64,
+    /// Composition frame at which the layer's own time is 0.
+    #[serde(rename = "st", default)]
+    pub(crate) start_time: f64,
+    /// How much slower than the composition the layer's time runs.
+    #[serde(rename = "sr", default = "one")]
+    pub(crate) stretch: f64,
+    #[serde(rename = "ks", default)]
+    pub(crate) transform: TransformProperties,
+    #[serde(rename = "hd", default)]
+    pub(crate) hidden: bool,
+    #[serde(rename = "masksProperties", default)]
+    pub(crate) masks: Vec<Mask>,
+    /// Shape layers only.
+    #[serde(default)]
+    pub(crate) shapes: Vec<ShapeItem>,
+    /// Precomposition layers only.
+    #[serde(rename = "refId")]
+    pub(crate) asset: Option<String>,
+    /// Precomposition layers only.
+    #[serde(rename = "w")]
+    pub(crate) width: Option<f64>,
+    /// Precomposition layers only.
+    #[serde(rename = "h")]
+    pub(crate) height: Option<f64>,
+    /// Precomposition layers only.
+    #[serde(rename = "tm")]
+    pub(crate) time_remap: Option<Property>,
+    /// Solid layers only, as `#rrggbb`.
+    #[serde(rename = "sc")]
+    pub(crate) solid_color: Option<String>,
+    #[serde(rename = "sw", default)]
+    pub(crate) solid_width: f64,
+    #[serde(rename = "sh", default)]
+    pub(crate) solid_height: f64,
+    /// Set when the layer below is matted by this one.
+    #[serde(rename = "tt")]
+    pub(crate) matte_mode: Option<u32>,
+    /// Set when the layer is the matte of the layer above.
+    #[serde(rename = "td")]
+    pub(crate) matte_source: Option<u32>,
+    #[serde(rename = "ef", default)]
+    pub(crate) effects: Vec<serde_json::Value>,
+}
+
+/// Lottie identifies layer types by number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LayerKind {
+    Precomposition,
+    Solid,
+    Image,
+    Null,
+    Shape,
+    Text,
+    Other(u64),
+}
+
+impl<'de> Deserialize<'de> for LayerKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match u64::deserialize(deserializer)? {
+            0 => Self::Precomposition,
+            1 => Self::Solid,
+            2 => Self::Image,
+            3 => Self::Null,
+            4 => Self::Shape,
+            5 => Self::Text,
+            other => Self::Other(other),
+        })
+    }
+}
+
+/// The transform of a layer (`ks`) or a shape group (`tr`). Properties that
+/// are left out don't transform.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct TransformProperties {
+    #[serde(rename = "a")]
+    pub(crate) anchor: Option<Property>,
+    #[serde(rename = "p")]
+    pub(crate) position: Option<Position>,
+    /// In percent.
+    #[serde(rename = "s")]
+    pub(crate) scale: Option<Property>,
+    /// In degrees, clockwise.
+    #[serde(rename = "r")]
+    pub(crate) rotation: Option<Property>,
+    /// What 3D layers call `rotation`.
+    #[serde(rename = "rz")]
+    pub(crate) rotation_z: Option<Property>,
+    /// In percent.
+    #[serde(rename = "o")]
+    pub(crate) opacity: Option<Property>,
+    /// In degrees.
+    #[serde(rename = "sk")]
+    pub(crate) skew: Option<Property>,
+    /// Direction of `skew`, in degrees.
+    #[serde(rename = "sa")]
+    pub(crate) skew_axis: Option<Property>,
+}
+
+/// Positions can be animated as a point, or with separate x and y properties.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Position {
+    Split { x: Property, y: Property },
+    Combined(Property),
+}
+
+impl Position {
+    pub(crate) fn at(&self, frame: f64) -> Point {
+        match self {
+            Self::Split { x, y } => Point::new(x.at(frame).get(0), y.at(frame).get(0)),
+            Self::Combined(position) => position.point_at(frame),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Mask {
+    pub(crate) mode: MaskMode,
+    #[serde(rename = "pt")]
+    pub(crate) path: ShapeProperty,
+    /// In percent.
+    #[serde(rename = "o")]
+    pub(crate) opacity: Option<Property>,
+    #[serde(rename = "inv", default)]
+    pub(crate) inverted: bool,
+    /// Grows the mask by this many units.
+    #[serde(rename = "x")]
+    pub(crate) expansion: Option<Property>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub(crate) enum MaskMode {
+    #[serde(rename = "n")]
+    None,
+    #[serde(rename = "a")]
+    Add,
+    #[serde(rename = "s")]
+    Subtract,
+    #[serde(rename = "i")]
+    Intersect,
+    /// Lighten, darken and difference.
+    #[serde(other)]
+    Other,
+}
+
+/// An element of a shape layer or group, which are drawn from the last to
+/// the first. Paints and modifiers apply to the shapes before them in the
+/// same group and its nested groups.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "ty")]
+pub(crate) enum ShapeItem {
+    #[serde(rename = "gr")]
+    Group {
+        #[serde(rename = "it", default)]
+        items: Vec<ShapeItem>,
+        #[serde(rename = "hd", default)]
+        hidden: bool,
+    },
+    #[serde(rename = "rc")]
+    Rect {
+        #[serde(rename = "p")]
+        center: Property,
+        #[serde(rename = "s")]
+        size: Property,
+        #[serde(rename = "r")]
+        roundness: Option<Property>,
+        #[serde(rename = "d", default)]
+        direction: Direction,
+    },
+    #[serde(rename = "el")]
+    Ellipse {
+        #[serde(rename = "p")]
+        center: Property,
+        #[serde(rename = "s")]
+        size: Property,
+        #[serde(rename = "d", default)]
+        direction: Direction,
+    },
+    #[serde(rename = "sr")]
+    Star {
+        #[serde(rename = "p")]
+        center: Property,
+        /// 1 for a star, 2 for a polygon.
+        #[serde(rename = "sy")]
+        star_type: u32,
+        #[serde(rename = "pt")]
+        points: Property,
+        #[serde(rename = "or")]
+        outer_radius: Property,
+        #[serde(rename = "ir")]
+        inner_radius: Option<Property>,
+        #[serde(rename = "r")]
+        rotation: Option<Property>,
+        #[serde(rename = "os")]
+        outer_roundness: Option<Property>,
+        #[serde(rename = "is")]
+        inner_roundness: Option<Property>,
+        #[serde(rename = "d", default)]
+        direction: Direction,
+    },
+    #[serde(rename = "sh")]
+    Path {
+        #[serde(rename = "ks")]
+        path: ShapeProperty,
+    },
+    #[serde(rename = "fl")]
+    Fill {
+        #[serde(rename = "c")]
+        color: Property,
+        #[serde(rename = "o")]
+        opacity: Option<Property>,
+        /// 1 for non-zero, 2 for even-odd.
+        #[serde(rename = "r", default = "one_u32")]
+        fill_rule: u32,
+    },
+    #[serde(rename = "st")]
+    Stroke {
+        #[serde(rename = "c")]
+        color: Property,
+        #[serde(rename = "o")]
+        opacity: Option<Property>,
+        #[serde(flatten)]
+        style: StrokeProperties,
+    },
+    #[serde(rename = "gf")]
+    GradientFill {
+        #[serde(flatten)]
+        gradient: Gradient,
+        #[serde(rename = "o")]
+        opacity: Option<Property>,
+        #[serde(rename = "r", default = "one_u32")]
+        fill_rule: u32,
+    },
+    #[serde(rename = "gs")]
+    GradientStroke {
+        #[serde(flatten)]
+        gradient: Gradient,
+        #[serde(rename = "o")]
+        opacity: Option<Property>,
+        #[serde(flatten)]
+        style: StrokeProperties,
+    },
+    #[serde(rename = "tr")]
+    Transform(TransformProperties),
+    #[serde(rename = "tm")]
+    Trim {
+        /// In percent.
+        #[serde(rename = "s")]
+        start: Property,
+        /// In percent.
+        #[serde(rename = "e")]
+        end: Property,
+        /// In degrees, 360 being the path's length.
+        #[serde(rename = "o")]
+        offset: Option<Property>,
+        /// 1 trims every path on its own, 2 trims them as if they were one.
+        #[serde(rename = "m", default = "one_u32")]
+        mode: u32,
+    },
+    #[serde(rename = "mm")]
+    Merge {},
+    #[serde(rename = "rp")]
+    Repeater {},
+    #[serde(rename = "rd")]
+    RoundCorners {},
+    #[serde(other)]
+    Other,
+}
+
+/// 3 reverses the direction rects, ellipses and stars are drawn in, which
+/// trim paths follow.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub(crate) struct Direction(pub(crate) u32);
+
+impl Direction {
+    pub(crate) fn reversed(self) -> bool {
+        self.0 == 3
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StrokeProperties {
+    #[serde(rename = "w")]
+    pub(crate) width: Property,
+    /// 1 butt, 2 round, 3 square.
+    #[serde(rename = "lc", default = "one_u32")]
+    pub(crate) cap: u32,
+    /// 1 miter, 2 round, 3 bevel.
+    #[serde(rename = "lj", default = "one_u32")]
+    pub(crate) join: u32,
+    #[serde(rename = "ml")]
+    pub(crate) miter_limit: Option<f64>,
+    #[serde(rename = "d", default)]
+    pub(crate) dashes: Vec<Dash>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Dash {
+    /// `d` for a dash, `g` for a gap, `o` for the offset.
+    #[serde(rename = "n")]
+    pub(crate) kind: String,
+    #[serde(rename = "v")]
+    pub(crate) length: Property,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Gradient {
+    /// 1 for linear, 2 for radial.
+    #[serde(rename = "t", default = "one_u32")]
+    pub(crate) kind: u32,
+    #[serde(rename = "s")]
+    pub(crate) start: Property,
+    #[serde(rename = "e")]
+    pub(crate) end: Property,
+    /// Distance of a radial gradient's focal point from its center, from -100
+    /// to 100 percent of its radius.
+    #[serde(rename = "h")]
+    pub(crate) highlight_length: Option<Property>,
+    /// Direction of the focal point, in degrees relative to the gradient's.
+    #[serde(rename = "a")]
+    pub(crate) highlight_angle: Option<Property>,
+    #[serde(rename = "g")]
+    pub(crate) colors: GradientColors,
+}
+
+/// `colors` holds `count` stops as offset, red, green and blue, optionally
+/// followed by any number of offset and opacity pairs, all from 0 to 1.
+#[derive(Debug, Deserialize)]
+pub(crate) struct GradientColors {
+    #[serde(rename = "p")]
+    pub(crate) count: usize,
+    #[serde(rename = "k")]
+    pub(crate) colors: Property,
+}
+
+/// A property that is either fixed or animated with keyframes.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Property<T = Values> {
+    #[serde(rename = "k")]
+    value: Animated<T>,
+}
+
+pub(crate) type ShapeProperty = Property<ShapeValue>;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Animated<T> {
+    Keyframes(Vec<AnimatedKeyframe<T>>),
+    Fixed(T),
+}
+
+#[derive(Debug, Deserialize)]
+struct AnimatedKeyframe<T> {
+    #[serde(rename = "t")]
+    time: f64,
+    #[serde(rename = "s")]
+    start: Option<T>,
+    /// Only older files repeat the next keyframe's `start` here.
+    #[serde(rename = "e")]
+    end: Option<T>,
+    /// Easing out of this keyframe.
+    #[serde(rename = "o")]
+    out_tangent: Option<Tangent>,
+    /// Easing into the next keyframe.
+    #[serde(rename = "i")]
+    in_tangent: Option<Tangent>,
+    /// Holds `start` until the next keyframe if non-zero.
+    #[serde(rename = "h", default)]
+    hold: f64,
+    /// Control points of a curved motion path, relative to `start`...
+    #[serde(rename = "to")]
+    spatial_out: Option<Values>,
+    /// ...and to the end.
+    #[serde(rename = "ti")]
+    spatial_in: Option<Values>,
+}
+
+/// A control point of a keyframe's easing curve, per dimension of the
+/// property or the same for all of them.
+#[derive(Debug, Deserialize)]
+struct Tangent {
+    x: Values,
+    y: Values,
+}
+
+/// The value of most properties: a number, or a point, size or color as
+/// a list of them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Values(pub(crate) Vec<f64>);
+
+impl Values {
+    /// Component `i`, or 0 if there are fewer.
+    pub(crate) fn get(&self, i: usize) -> f64 {
+        self.0.get(i).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn point(&self) -> Point {
+        Point::new(self.get(0), self.get(1))
+    }
+
+    pub(crate) fn vec2(&self) -> Vec2 {
+        Vec2::new(self.get(0), self.get(1))
+    }
+
+    /// Component `i`, or the last one if there are fewer.
+    fn get_or_last(&self, i: usize) -> f64 {
+        self.0.get(i).or(self.0.last()).copied().unwrap_or_default()
+    }
+}
+
+impl<'de> Deserialize<'de> for Values {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(f64),
+            Many(Vec<f64>),
+        }
+        Ok(match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(value) => Self(vec![value]),
+            OneOrMany::Many(values) => Self(values),
+        })
+    }
+}
+
+/// A path as a list of vertices with the control points on either side of
+/// them, relative to the vertex.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct ShapeValue {
+    pub(crate) closed: bool,
+    pub(crate) vertices: Vec<[f64; 2]>,
+    pub(crate) in_tangents: Vec<[f64; 2]>,
+    pub(crate) out_tangents: Vec<[f64; 2]>,
+}
+
+impl ShapeValue {
+    pub(crate) fn to_path(&self) -> BezPath {
+        let mut path = BezPath::new();
+        let Some(&first) = self.vertices.first() else {
+            return path;
+        };
+        path.move_to((first[0], first[1]));
+        for i in 1..self.vertices.len() {
+            self.push_segment(&mut path, i - 1, i);
+        }
+        if self.closed {
+            self.push_segment(&mut path, self.vertices.len() - 1, 0);
+            path.close_path();
+        }
+        path
+    }
+
+    fn push_segment(&self, path: &mut BezPath, from: usize, to: usize) {
+        let tangent = |tangents: &[[f64; 2]], i: usize| {
+            let [x, y] = tangents.get(i).copied().unwrap_or_default();
+            Vec2::new(x, y)
+        };
+        let (out_tangent, in_tangent) = (
+            tangent(&self.out_tangents, from),
+            tangent(&self.in_tangents, to),
+        );
+        let vertex = |i: usize| Point::new(self.vertices[i][0], self.vertices[i][1]);
+        let (from, to) = (vertex(from), vertex(to));
+        if out_tangent == Vec2::ZERO && in_tangent == Vec2::ZERO {
+            path.line_to(to);
+        } else {
+            path.curve_to(from + out_tangent, to + in_tangent, to);
+        }
+    }
+}
+
+/// Keyframes hold shapes in a list of one, fixed properties don't.
+impl<'de> Deserialize<'de> for ShapeValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawShape {
+            #[serde(rename = "c", default)]
+            closed: bool,
+            #[serde(rename = "v", default)]
+            vertices: Vec<[f64; 2]>,
+            #[serde(rename = "i", default)]
+            in_tangents: Vec<[f64; 2]>,
+            #[serde(rename = "o", default)]
+            out_tangents: Vec<[f64; 2]>,
+        }
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrList {
+            One(RawShape),
+            List(Vec<RawShape>),
+        }
+        let shape = match OneOrList::deserialize(deserializer)? {
+            OneOrList::One(shape) => shape,
+            OneOrList::List(shapes) => shapes
+                .into_iter()
+                .next()
+                .ok_or_else(|| serde::de::Error::custom("empty shape keyframe"))?,
+        };
+        Ok(Self {
+            closed: shape.closed,
+            vertices: shape.vertices,
+            in_tangents: shape.in_tangents,
+            out_tangents: shape.out_tangents,
+        })
+    }
+}
+
+fn one() -> f64 {
+    1.0
+}
+
+fn one_u32() -> u32 {
+    1
+}
+
+/// Values that keyframes interpolate between.
+pub(crate) trait Interpolate: Clone + Default {
+    /// `progress` is the eased progress for each dimension.
+    fn interpolate(start: &Self, end: &Self, progress: &[f64]) -> Self;
+}
+
+impl Interpolate for Values {
+    fn interpolate(start: &Self, end: &Self, progress: &[f64]) -> Self {
+        Self(
+            start
+                .0
+                .iter()
+                .zip(&end.0)
+                .enumerate()
+                .map(|(i, (start, end))| {
+                    let t = progress.get(i).or(progress.last()).copied().unwrap_or(0.0);
+                    start + (end - start) * t
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Interpolate for ShapeValue {
+    /// Shapes with different vertex counts can't be interpolated and jump.
+    fn interpolate(start: &Self, end: &Self, progress: &[f64]) -> Self {
+        let t = progress.first().copied().unwrap_or(0.0);
+        if start.vertices.len() != end.vertices.len() {
+            return if t < 1.0 { start.clone() } else { end.clone() };
+        }
+        let lerp = |a: &[[f64; 2]], b: &[[f64; 2]]| -> Vec<[f64; 2]> {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t])
+                .collect()
+        };
+        Self {
+            closed: start.closed,
+            vertices: lerp(&start.vertices, &end.vertices),
+            in_tangents: lerp(&start.in_tangents, &end.in_tangents),
+            out_tangents: lerp(&start.out_tangents, &end.out_tangents),
+        }
+    }
+}
+
+impl<T: Interpolate> Property<T> {
+    /// The value at `frame`, in the time of the layer the property belongs
+    /// to.
+    pub(crate) fn at(&self, frame: f64) -> T {
+        match &self.value {
+            Animated::Fixed(value) => value.clone(),
+            Animated::Keyframes(keyframes) => match sample(keyframes, frame) {
+                Sample::At(value) => value.clone(),
+                Sample::Missing => T::default(),
+                Sample::Between {
+                    start,
+                    end,
+                    progress,
+                    ..
+                } => T::interpolate(start, end, &progress),
+            },
+        }
+    }
+
+    pub(crate) fn is_animated(&self) -> bool {
+        matches!(&self.value, Animated::Keyframes(keyframes) if keyframes.len() > 1)
+    }
+}
+
+impl Property {
+    /// Like `at`, but moves along the curved motion path of a position.
+    pub(crate) fn point_at(&self, frame: f64) -> Point {
+        let Animated::Keyframes(keyframes) = &self.value else {
+            return self.at(frame).point();
+        };
+        match sample(keyframes, frame) {
+            Sample::At(value) => value.point(),
+            Sample::Missing => Point::ZERO,
+            Sample::Between {
+                start,
+                end,
+                progress,
+                keyframe,
+            } => match (&keyframe.spatial_out, &keyframe.spatial_in) {
+                (Some(spatial_out), Some(spatial_in))
+                    if spatial_out.vec2() != Vec2::ZERO || spatial_in.vec2() != Vec2::ZERO =>
+                {
+                    let (start, end) = (start.point(), end.point());
+                    CubicBez::new(
+                        start,
+                        start + spatial_out.vec2(),
+                        end + spatial_in.vec2(),
+                        end,
+                    )
+                    .eval(progress[0])
+                }
+                _ => Values::interpolate(start, end, &progress).point(),
+            },
+        }
+    }
+}
+
+enum Sample<'a, T> {
+    At(&'a T),
+    /// No keyframe has a value.
+    Missing,
+    Between {
+        start: &'a T,
+        end: &'a T,
+        progress: Vec<f64>,
+        keyframe: &'a AnimatedKeyframe<T>,
+    },
+}
+
+fn sample<T>(keyframes: &[AnimatedKeyframe<T>], frame: f64) -> Sample<'_, T> {
+    // Older files end with a keyframe that only has a time. Empty keyframe
+    // lists have no value at all.
+    let start_value = |i: usize| {
+        keyframes
+            .get(i)?
+            .start
+            .as_ref()
+            .or_else(|| i.checked_sub(1).and_then(|i| keyframes[i].end.as_ref()))
+    };
+    let next = keyframes.partition_point(|keyframe| keyframe.time <= frame);
+    let Some(i) = next.checked_sub(1) else {
+        return start_value(0).map_or(Sample::Missing, Sample::At);
+    };
+    let keyframe = &keyframes[i];
+    let start = start_value(i);
+    let end = keyframe
+        .end
+        .as_ref()
+        .or_else(|| keyframes.get(i + 1).and_then(|next| next.start.as_ref()));
+    let (Some(start), Some(end), Some(next)) = (start, end, keyframes.get(i + 1)) else {
+        return start.or(end).map_or(Sample::Missing, Sample::At);
+    };
+    if keyframe.hold != 0.0 || next.time <= keyframe.time {
+        return Sample::At(start);
+    }
+    let linear = (frame - keyframe.time) / (next.time - keyframe.time);
+    let progress = match (&keyframe.out_tangent, &keyframe.in_tangent) {
+        (Some(out_tangent), Some(in_tangent)) => {
+            let dimensions = out_tangent.x.0.len().max(in_tangent.x.0.len()).max(1);
+            (0..dimensions)
+                .map(|i| {
+                    cubic_bezier(
+                        out_tangent.x.get_or_last(i),
+                        out_tangent.y.get_or_last(i),
+                        in_tangent.x.get_or_last(i),
+                        in_tangent.y.get_or_last(i),
+                        linear,
+                    )
+                })
+                .collect()
+        }
+        _ => vec![linear],
+    };
+    Sample::Between {
+        start,
+        end,
+        progress,
+        keyframe,
+    }
+}
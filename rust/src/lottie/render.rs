@@ -0,0 +1,868 @@
+//! Evaluating a composition at a frame into scene commands.
+
+use crate::api::scene::BlendMode;
+use crate::api::scene::Brush;
+use crate::api::scene::Color;
+use crate::api::scene::ColorStop;
+use crate::api::scene::Compose;
+use crate::api::scene::Extend;
+use crate::api::scene::FillRule;
+use crate::api::scene::LineCap;
+use crate::api::scene::LineJoin;
+use crate::api::scene::Mix;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Shape;
+use crate::api::scene::StrokeStyle;
+use crate::encode::MAX_LAYER_DEPTH;
+use crate::lottie::model::Composition;
+use crate::lottie::model::Direction;
+use crate::lottie::model::Gradient;
+use crate::lottie::model::Layer;
+use crate::lottie::model::LayerKind;
+use crate::lottie::model::Mask;
+use crate::lottie::model::MaskMode;
+use crate::lottie::model::Property;
+use crate::lottie::model::ShapeItem;
+use crate::lottie::model::StrokeProperties;
+use crate::lottie::model::TransformProperties;
+use crate::lottie::trim::trim;
+use std::f64::consts::FRAC_PI_2;
+use std::f64::consts::PI;
+use vello::kurbo::Affine;
+use vello::kurbo::BezPath;
+use vello::kurbo::Point;
+use vello::kurbo::Rect;
+use vello::kurbo::Shape as _;
+use vello::kurbo::Vec2;
+
+/// How far the control points of a quarter circle are from its ends, in
+/// radii.
+const KAPPA: f64 = 0.5519150244935105;
+
+/// Draws `composition` at `frame`, with its coordinates mapped by
+/// `transform`.
+pub(crate) fn commands(
+    composition: &Composition,
+    frame: f64,
+    transform: Affine,
+) -> Vec<SceneCommand> {
+    let mut renderer = Renderer {
+        composition,
+        commands: Vec::new(),
+    };
+    let bounds = Rect::new(0.0, 0.0, composition.width, composition.height);
+    renderer.layers(&composition.layers, frame, transform, bounds, 1.0, 0);
+    renderer.commands
+}
+
+struct Renderer<'a> {
+    composition: &'a Composition,
+    commands: Vec<SceneCommand>,
+}
+
+/// A trim path's values at a frame, as fractions of a path's length.
+#[derive(Clone, Copy)]
+struct Trim {
+    start: f64,
+    end: f64,
+    offset: f64,
+}
+
+impl Renderer<'_> {
+    /// Draws a composition's `layers` at its `frame`. `transform` maps the
+    /// composition's coordinates, in which it covers `bounds`, and `depth`
+    /// counts the layers already pushed around it.
+    fn layers(
+        &mut self,
+        layers: &[Layer],
+        frame: f64,
+        transform: Affine,
+        bounds: Rect,
+        alpha: f64,
+        depth: usize,
+    ) {
+        // The first layer is the topmost one.
+        for layer in layers.iter().rev() {
+            let visible = !layer.hidden
+                && layer.matte_source.is_none()
+                && (layer.in_point..layer.out_point).contains(&frame);
+            if visible {
+                self.layer(layers, layer, frame, transform, bounds, alpha, depth);
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn layer(
+        &mut self,
+        layers: &[Layer],
+        layer: &Layer,
+        frame: f64,
+        transform: Affine,
+        bounds: Rect,
+        alpha: f64,
+        depth: usize,
+    ) {
+        let local_frame = local_frame(layer, frame);
+        let matrix = layer_matrix(layers, layer, frame);
+        let opacity = percent(layer.transform.opacity.as_ref(), local_frame, 100.0);
+        if opacity <= 0.0 || matrix.determinant().abs() < 1e-12 {
+            return;
+        }
+        let masks: Vec<&Mask> = layer
+            .masks
+            .iter()
+            .filter(|mask| mask.mode != MaskMode::None)
+            .collect();
+
+        // Layers needed around the content, which fall back to no masks and
+        // to fading every paint when vello can't nest that many.
+        let simple_mask = match masks[..] {
+            [mask] => {
+                mask.mode != MaskMode::Subtract
+                    && !mask.inverted
+                    && percent(mask.opacity.as_ref(), local_frame, 100.0) >= 1.0
+            }
+            _ => false,
+        };
+        let mask_layers = match masks.len() {
+            0 => 0,
+            _ if simple_mask => 1,
+            _ => {
+                let inner = masks.iter().enumerate().any(|(i, mask)| {
+                    mask.mode == MaskMode::Subtract || (i > 0 && mask.mode == MaskMode::Intersect)
+                });
+                2 + usize::from(inner)
+            }
+        };
+        let use_masks = mask_layers > 0 && depth + mask_layers <= MAX_LAYER_DEPTH;
+        let isolate = use_masks || (opacity < 1.0 && depth < MAX_LAYER_DEPTH);
+        let content_alpha = if isolate { alpha } else { alpha * opacity };
+        let layer_alpha = if isolate { opacity } else { 1.0 };
+
+        let clip = bounds_shape(bounds);
+        if use_masks && simple_mask {
+            let path = matrix * masks[0].path.at(local_frame).to_path();
+            self.push_layer(to_shape(&path), transform, layer_alpha);
+        } else if isolate {
+            self.push_layer(clip.clone(), transform, layer_alpha);
+        }
+        let content_depth = depth + usize::from(isolate);
+
+        let transform_to_layer = transform * matrix;
+        match layer.kind {
+            LayerKind::Shape => {
+                let (commands, _) = group(
+                    &layer.shapes,
+                    local_frame,
+                    transform_to_layer,
+                    content_alpha,
+                    &[],
+                );
+                self.commands.extend(commands);
+            }
+            LayerKind::Solid => {
+                let color = layer
+                    .solid_color
+                    .as_deref()
+                    .and_then(parse_hex_color)
+                    .unwrap_or([0.0; 3]);
+                let rect = Rect::new(0.0, 0.0, layer.solid_width, layer.solid_height);
+                self.commands.push(SceneCommand::Fill {
+                    shape: to_shape(&rect.to_path(0.1)),
+                    brush: color_brush(&color, content_alpha),
+                    fill_rule: FillRule::NonZero,
+                    transform: transform_to_layer.into(),
+                    brush_transform: None,
+                });
+            }
+            LayerKind::Precomposition => {
+                let asset = self.composition.assets.iter().find(|asset| {
+                    Some(&asset.id) == layer.asset.as_ref() && asset.layers.is_some()
+                });
+                if let Some(layers) = asset.and_then(|asset| asset.layers.as_ref()) {
+                    let bounds = Rect::new(
+                        0.0,
+                        0.0,
+                        layer.width.unwrap_or(self.composition.width),
+                        layer.height.unwrap_or(self.composition.height),
+                    );
+                    self.layers(
+                        layers,
+                        local_frame,
+                        transform_to_layer,
+                        bounds,
+                        content_alpha,
+                        content_depth,
+                    );
+                }
+            }
+            // Nulls only parent other layers, the rest isn't supported.
+            LayerKind::Null | LayerKind::Image | LayerKind::Text | LayerKind::Other(_) => {}
+        }
+
+        if use_masks && !simple_mask {
+            self.masks(&masks, local_frame, transform, matrix, bounds);
+        }
+        if isolate {
+            self.commands.push(SceneCommand::PopLayer);
+        }
+    }
+
+    /// Keeps what the layer drew where its masks cover it, by drawing them
+    /// into a layer that is composed with `Compose::DestIn`. `matrix` maps
+    /// the layer's coordinates, which masks are in, to the composition's.
+    fn masks(
+        &mut self,
+        masks: &[&Mask],
+        frame: f64,
+        transform: Affine,
+        matrix: Affine,
+        bounds: Rect,
+    ) {
+        let clip = bounds_shape(bounds);
+        let white = |alpha: f64| Color {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: to_u8(alpha),
+        };
+        let keep_in = BlendMode {
+            mix: Mix::Normal,
+            compose: Compose::DestIn,
+        };
+        self.commands.push(SceneCommand::PushLayer {
+            clip: clip.clone(),
+            blend: keep_in,
+            alpha: 1.0,
+            transform: transform.into(),
+        });
+        for (i, mask) in masks.iter().enumerate() {
+            let mut path = matrix * mask.path.at(frame).to_path();
+            let mut fill_rule = FillRule::NonZero;
+            if mask.inverted {
+                let mut inverted = bounds.to_path(0.1);
+                inverted.extend(path);
+                path = inverted;
+                fill_rule = FillRule::EvenOdd;
+            }
+            let fill = SceneCommand::Fill {
+                shape: to_shape(&path),
+                brush: white(percent(mask.opacity.as_ref(), frame, 100.0)).into(),
+                fill_rule,
+                transform: transform.into(),
+                brush_transform: None,
+            };
+            let compose = match mask.mode {
+                MaskMode::Subtract => Compose::DestOut,
+                MaskMode::Intersect if i > 0 => Compose::DestIn,
+                _ => {
+                    self.commands.push(fill);
+                    continue;
+                }
+            };
+            // Subtracting from nothing leaves nothing, so the first mask
+            // subtracts from everything.
+            if i == 0 {
+                self.commands.push(SceneCommand::Fill {
+                    shape: clip.clone(),
+                    brush: white(1.0).into(),
+                    fill_rule: FillRule::NonZero,
+                    transform: transform.into(),
+                    brush_transform: None,
+                });
+            }
+            self.commands.push(SceneCommand::PushLayer {
+                clip: clip.clone(),
+                blend: BlendMode {
+                    mix: Mix::Normal,
+                    compose,
+                },
+                alpha: 1.0,
+                transform: transform.into(),
+            });
+            self.commands.push(fill);
+            self.commands.push(SceneCommand::PopLayer);
+        }
+        self.commands.push(SceneCommand::PopLayer);
+    }
+
+    fn push_layer(&mut self, clip: Shape, transform: Affine, alpha: f64) {
+        self.commands.push(SceneCommand::PushLayer {
+            clip,
+            blend: BlendMode {
+                mix: Mix::Normal,
+                compose: Compose::SrcOver,
+            },
+            alpha,
+            transform: transform.into(),
+        });
+    }
+}
+
+/// Draws the shape `items` of a layer or group, and returns the paths of its
+/// shapes for the paints of enclosing groups. `transform` maps the group's
+/// coordinates to the output and `trims` are the enclosing groups' trim paths
+/// that come after it.
+fn group(
+    items: &[ShapeItem],
+    frame: f64,
+    transform: Affine,
+    alpha: f64,
+    trims: &[Trim],
+) -> (Vec<SceneCommand>, Vec<BezPath>) {
+    let local_trims: Vec<(usize, Trim)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| match item {
+            ShapeItem::Trim {
+                start, end, offset, ..
+            } => Some((
+                i,
+                Trim {
+                    start: percent(Some(start), frame, 0.0),
+                    end: percent(Some(end), frame, 100.0),
+                    offset: number(offset.as_ref(), frame, 0.0) / 360.0,
+                },
+            )),
+            _ => None,
+        })
+        .collect();
+    // Trims apply to the shapes before them, closest first.
+    let trims_after = |i: usize| -> Vec<Trim> {
+        local_trims
+            .iter()
+            .filter(|&&(j, _)| j > i)
+            .map(|&(_, trim)| trim)
+            .chain(trims.iter().copied())
+            .collect()
+    };
+
+    let mut paths: Vec<BezPath> = Vec::new();
+    // What each item draws, in item order.
+    let mut drawn: Vec<Vec<SceneCommand>> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        match item {
+            ShapeItem::Group {
+                items,
+                hidden: false,
+            } => {
+                let (matrix, opacity) = match items.iter().rev().find_map(|item| match item {
+                    ShapeItem::Transform(transform) => Some(transform),
+                    _ => None,
+                }) {
+                    Some(group_transform) => (
+                        transform_matrix(group_transform, frame),
+                        percent(group_transform.opacity.as_ref(), frame, 100.0),
+                    ),
+                    None => (Affine::IDENTITY, 1.0),
+                };
+                let (commands, group_paths) = group(
+                    items,
+                    frame,
+                    transform * matrix,
+                    alpha * opacity,
+                    &trims_after(i),
+                );
+                drawn.push(commands);
+                paths.extend(group_paths.into_iter().map(|path| matrix * path));
+            }
+            ShapeItem::Fill {
+                color,
+                opacity,
+                fill_rule,
+            } => {
+                let alpha = alpha * percent(opacity.as_ref(), frame, 100.0);
+                drawn.push(fill(
+                    &paths,
+                    color_brush(&color.at(frame).0, alpha),
+                    *fill_rule,
+                    transform,
+                ));
+            }
+            ShapeItem::GradientFill {
+                gradient,
+                opacity,
+                fill_rule,
+            } => {
+                let alpha = alpha * percent(opacity.as_ref(), frame, 100.0);
+                drawn.push(fill(
+                    &paths,
+                    gradient_brush(gradient, frame, alpha),
+                    *fill_rule,
+                    transform,
+                ));
+            }
+            ShapeItem::Stroke {
+                color,
+                opacity,
+                style,
+            } => {
+                let alpha = alpha * percent(opacity.as_ref(), frame, 100.0);
+                drawn.push(stroke(
+                    &paths,
+                    color_brush(&color.at(frame).0, alpha),
+                    style,
+                    frame,
+                    transform,
+                ));
+            }
+            ShapeItem::GradientStroke {
+                gradient,
+                opacity,
+                style,
+            } => {
+                let alpha = alpha * percent(opacity.as_ref(), frame, 100.0);
+                drawn.push(stroke(
+                    &paths,
+                    gradient_brush(gradient, frame, alpha),
+                    style,
+                    frame,
+                    transform,
+                ));
+            }
+            item => {
+                if let Some(mut path) = shape_path(item, frame) {
+                    for trim_path in trims_after(i) {
+                        path = trim(&path, trim_path.start, trim_path.end, trim_path.offset);
+                    }
+                    paths.push(path);
+                }
+            }
+        }
+    }
+    // Later items are drawn below earlier ones.
+    (drawn.into_iter().rev().flatten().collect(), paths)
+}
+
+fn fill(paths: &[BezPath], brush: Brush, fill_rule: u32, transform: Affine) -> Vec<SceneCommand> {
+    let path = combine(paths);
+    if path.elements().is_empty() {
+        return Vec::new();
+    }
+    vec![SceneCommand::Fill {
+        shape: to_shape(&path),
+        brush,
+        fill_rule: if fill_rule == 2 {
+            FillRule::EvenOdd
+        } else {
+            FillRule::NonZero
+        },
+        transform: transform.into(),
+        brush_transform: None,
+    }]
+}
+
+fn stroke(
+    paths: &[BezPath],
+    brush: Brush,
+    style: &StrokeProperties,
+    frame: f64,
+    transform: Affine,
+) -> Vec<SceneCommand> {
+    let path = combine(paths);
+    let width = style.width.at(frame).get(0);
+    if path.elements().is_empty() || width <= 0.0 {
+        return Vec::new();
+    }
+    let cap = match style.cap {
+        2 => LineCap::Round,
+        3 => LineCap::Square,
+        _ => LineCap::Butt,
+    };
+    let mut dash_pattern = Vec::new();
+    let mut dash_offset = 0.0;
+    for dash in &style.dashes {
+        let length = dash.length.at(frame).get(0);
+        match dash.kind.as_str() {
+            "o" => dash_offset = length,
+            _ => dash_pattern.push(length.max(0.0)),
+        }
+    }
+    if dash_pattern.iter().all(|&length| length == 0.0) {
+        dash_pattern.clear();
+    } else if dash_pattern.len() % 2 == 1 {
+        // Like in SVG, an odd number of lengths is repeated.
+        dash_pattern.extend_from_within(..);
+    }
+    vec![SceneCommand::Stroke {
+        shape: to_shape(&path),
+        brush,
+        style: StrokeStyle {
+            width,
+            join: match style.join {
+                2 => LineJoin::Round,
+                3 => LineJoin::Bevel,
+                _ => LineJoin::Miter,
+            },
+            miter_limit: style.miter_limit.unwrap_or(4.0),
+            start_cap: cap,
+            end_cap: cap,
+            dash_pattern,
+            dash_offset,
+        },
+        transform: transform.into(),
+        brush_transform: None,
+    }]
+}
+
+/// The path of a shape item, or `None` for paints and modifiers.
+fn shape_path(item: &ShapeItem, frame: f64) -> Option<BezPath> {
+    let (path, direction) = match item {
+        ShapeItem::Path { path } => return Some(path.at(frame).to_path()),
+        ShapeItem::Rect {
+            center,
+            size,
+            roundness,
+            direction,
+        } => (
+            rect_path(
+                center.at(frame).point(),
+                size.at(frame).vec2(),
+                number(roundness.as_ref(), frame, 0.0),
+            ),
+            direction,
+        ),
+        ShapeItem::Ellipse {
+            center,
+            size,
+            direction,
+        } => (
+            ellipse_path(center.at(frame).point(), size.at(frame).vec2() / 2.0),
+            direction,
+        ),
+        ShapeItem::Star {
+            center,
+            star_type,
+            points,
+            outer_radius,
+            inner_radius,
+            rotation,
+            direction,
+            ..
+        } => (
+            star_path(
+                center.at(frame).point(),
+                points.at(frame).get(0),
+                outer_radius.at(frame).get(0),
+                (*star_type == 1).then(|| number(inner_radius.as_ref(), frame, 0.0)),
+                number(rotation.as_ref(), frame, 0.0),
+            ),
+            direction,
+        ),
+        _ => return None,
+    };
+    Some(reverse_if(path, *direction))
+}
+
+fn reverse_if(path: BezPath, direction: Direction) -> BezPath {
+    if direction.reversed() {
+        path.reverse_subpaths()
+    } else {
+        path
+    }
+}
+
+/// A rect drawn clockwise from its top right corner, like After Effects
+/// does.
+fn rect_path(center: Point, size: Vec2, roundness: f64) -> BezPath {
+    let (half_width, half_height) = (size.x.abs() / 2.0, size.y.abs() / 2.0);
+    let radius = roundness.clamp(0.0, half_width.min(half_height));
+    let (left, top) = (center.x - half_width, center.y - half_height);
+    let (right, bottom) = (center.x + half_width, center.y + half_height);
+    // Every side, followed by the rounded corner after it.
+    let sides = [
+        (
+            (right, bottom - radius),
+            (right, bottom),
+            (right - radius, bottom),
+        ),
+        (
+            (left + radius, bottom),
+            (left, bottom),
+            (left, bottom - radius),
+        ),
+        ((left, top + radius), (left, top), (left + radius, top)),
+        ((right - radius, top), (right, top), (right, top + radius)),
+    ];
+    let mut path = BezPath::new();
+    path.move_to((right, top + radius));
+    for (from, corner, to) in sides {
+        let (from, corner, to) = (Point::from(from), Point::from(corner), Point::from(to));
+        path.line_to(from);
+        if radius > 0.0 {
+            path.curve_to(
+                from + (corner - from) * KAPPA,
+                to + (corner - to) * KAPPA,
+                to,
+            );
+        }
+    }
+    path.close_path();
+    path
+}
+
+/// An ellipse drawn clockwise from its top, like After Effects does.
+fn ellipse_path(center: Point, radii: Vec2) -> BezPath {
+    let (rx, ry) = (radii.x, radii.y);
+    let (cx, cy) = (center.x, center.y);
+    let mut path = BezPath::new();
+    path.move_to((cx, cy - ry));
+    path.curve_to(
+        (cx + rx * KAPPA, cy - ry),
+        (cx + rx, cy - ry * KAPPA),
+        (cx + rx, cy),
+    );
+    path.curve_to(
+        (cx + rx, cy + ry * KAPPA),
+        (cx + rx * KAPPA, cy + ry),
+        (cx, cy + ry),
+    );
+    path.curve_to(
+        (cx - rx * KAPPA, cy + ry),
+        (cx - rx, cy + ry * KAPPA),
+        (cx - rx, cy),
+    );
+    path.curve_to(
+        (cx - rx, cy - ry * KAPPA),
+        (cx - rx * KAPPA, cy - ry),
+        (cx, cy - ry),
+    );
+    path.close_path();
+    path
+}
+
+/// A polygon, or a star if it has an `inner_radius`, with its first point
+/// straight above the center when `rotation` is 0 degrees.
+fn star_path(
+    center: Point,
+    points: f64,
+    outer_radius: f64,
+    inner_radius: Option<f64>,
+    rotation: f64,
+) -> BezPath {
+    let points = points.floor().max(0.0) as usize;
+    let mut path = BezPath::new();
+    if points == 0 {
+        return path;
+    }
+    let vertices = if inner_radius.is_some() {
+        points * 2
+    } else {
+        points
+    };
+    let step = 2.0 * PI / vertices as f64;
+    for i in 0..vertices {
+        let radius = match inner_radius {
+            Some(inner_radius) if i % 2 == 1 => inner_radius,
+            _ => outer_radius,
+        };
+        let angle = rotation.to_radians() - FRAC_PI_2 + step * i as f64;
+        let point = center + Vec2::from_angle(angle) * radius;
+        if i == 0 {
+            path.move_to(point);
+        } else {
+            path.line_to(point);
+        }
+    }
+    path.close_path();
+    path
+}
+
+/// The time of `layer` at the frame of its composition.
+fn local_frame(layer: &Layer, frame: f64) -> f64 {
+    let stretch = if layer.stretch == 0.0 {
+        1.0
+    } else {
+        layer.stretch
+    };
+    (frame - layer.start_time) / stretch
+}
+
+/// Maps the coordinates of `layer` to its composition's, through its
+/// parents.
+fn layer_matrix(layers: &[Layer], layer: &Layer, frame: f64) -> Affine {
+    let mut matrix = transform_matrix(&layer.transform, local_frame(layer, frame));
+    let mut parent = layer.parent;
+    // Bounded in case of a cycle.
+    for _ in 0..layers.len() {
+        let Some(index) = parent else {
+            break;
+        };
+        let Some(parent_layer) = layers.iter().find(|layer| layer.index == Some(index)) else {
+            break;
+        };
+        matrix =
+            transform_matrix(&parent_layer.transform, local_frame(parent_layer, frame)) * matrix;
+        parent = parent_layer.parent;
+    }
+    matrix
+}
+
+fn transform_matrix(transform: &TransformProperties, frame: f64) -> Affine {
+    let anchor = transform
+        .anchor
+        .as_ref()
+        .map_or(Point::ZERO, |anchor| anchor.at(frame).point());
+    let position = transform
+        .position
+        .as_ref()
+        .map_or(Point::ZERO, |position| position.at(frame));
+    let scale = transform
+        .scale
+        .as_ref()
+        .map_or(Vec2::new(100.0, 100.0), |scale| scale.at(frame).vec2())
+        / 100.0;
+    let rotation = number(
+        transform
+            .rotation
+            .as_ref()
+            .or(transform.rotation_z.as_ref()),
+        frame,
+        0.0,
+    );
+    let skew = number(transform.skew.as_ref(), frame, 0.0);
+    let mut matrix = Affine::translate(position.to_vec2()) * Affine::rotate(rotation.to_radians());
+    if skew != 0.0 {
+        let axis = number(transform.skew_axis.as_ref(), frame, 0.0).to_radians();
+        matrix = matrix
+            * Affine::rotate(axis)
+            * Affine::skew(-skew.to_radians().tan(), 0.0)
+            * Affine::rotate(-axis);
+    }
+    matrix * Affine::scale_non_uniform(scale.x, scale.y) * Affine::translate(-anchor.to_vec2())
+}
+
+fn color_brush(color: &[f64], alpha: f64) -> Brush {
+    let component = |i: usize| color.get(i).copied().unwrap_or(1.0);
+    Color {
+        r: to_u8(component(0)),
+        g: to_u8(component(1)),
+        b: to_u8(component(2)),
+        a: to_u8(component(3) * alpha),
+    }
+    .into()
+}
+
+fn gradient_brush(gradient: &Gradient, frame: f64, alpha: f64) -> Brush {
+    let values = gradient.colors.colors.at(frame).0;
+    let count = gradient.colors.count.min(values.len() / 4);
+    let opacities: Vec<(f64, f64)> = values[count * 4..]
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .collect();
+    let stops = values[..count * 4]
+        .chunks_exact(4)
+        .map(|stop| {
+            let opacity = interpolate_opacity(&opacities, stop[0]);
+            let Brush::Solid { color } = color_brush(&stop[1..], opacity * alpha) else {
+                unreachable!();
+            };
+            ColorStop {
+                offset: stop[0],
+                color,
+            }
+        })
+        .collect();
+    let start = gradient.start.at(frame).point();
+    let end = gradient.end.at(frame).point();
+    if gradient.kind == 2 {
+        let radius = (end - start).hypot();
+        let highlight =
+            (number(gradient.highlight_length.as_ref(), frame, 0.0) / 100.0).clamp(-0.99, 0.99);
+        let angle = number(gradient.highlight_angle.as_ref(), frame, 0.0).to_radians()
+            + (end - start).atan2();
+        let focus = start + Vec2::from_angle(angle) * radius * highlight;
+        Brush::RadialGradient {
+            x0: focus.x,
+            y0: focus.y,
+            r0: 0.0,
+            x1: start.x,
+            y1: start.y,
+            r1: radius,
+            stops,
+            extend: Extend::Pad,
+        }
+    } else {
+        Brush::LinearGradient {
+            x0: start.x,
+            y0: start.y,
+            x1: end.x,
+            y1: end.y,
+            stops,
+            extend: Extend::Pad,
+        }
+    }
+}
+
+/// The opacity of a gradient at `offset`, from its sorted opacity stops.
+fn interpolate_opacity(opacities: &[(f64, f64)], offset: f64) -> f64 {
+    let (Some(first), Some(last)) = (opacities.first(), opacities.last()) else {
+        return 1.0;
+    };
+    if offset <= first.0 {
+        return first.1;
+    }
+    for pair in opacities.windows(2) {
+        let ((from, a), (to, b)) = (pair[0], pair[1]);
+        if offset <= to {
+            return if to > from {
+                a + (b - a) * (offset - from) / (to - from)
+            } else {
+                b
+            };
+        }
+    }
+    last.1
+}
+
+/// `[r, g, b]` from 0 to 1 of a `#rrggbb` color.
+fn parse_hex_color(color: &str) -> Option<[f64; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let component = |i: usize| {
+        u8::from_str_radix(hex.get(i..i + 2)?, 16)
+            .ok()
+            .map(|c| f64::from(c) / 255.0)
+    };
+    Some([component(0)?, component(2)?, component(4)?])
+}
+
+/// The value of an optional property, with `default` when it's left out.
+fn number(property: Option<&Property>, frame: f64, default: f64) -> f64 {
+    property.map_or(default, |property| property.at(frame).get(0))
+}
+
+/// A percentage property as a fraction from 0 to 1.
+fn percent(property: Option<&Property>, frame: f64, default: f64) -> f64 {
+    (number(property, frame, default) / 100.0).clamp(0.0, 1.0)
+}
+
+fn combine(paths: &[BezPath]) -> BezPath {
+    let mut combined = BezPath::new();
+    for path in paths {
+        combined.extend(path.elements().iter().copied());
+    }
+    combined
+}
+
+fn to_shape(path: &BezPath) -> Shape {
+    Shape::Path {
+        elements: path.elements().iter().map(|&el| el.into()).collect(),
+    }
+}
+
+fn bounds_shape(bounds: Rect) -> Shape {
+    Shape::Rect {
+        x0: bounds.x0,
+        y0: bounds.y0,
+        x1: bounds.x1,
+        y1: bounds.y1,
+    }
+}
+
+fn to_u8(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
@@ -0,0 +1,83 @@
+//! Trim paths: keeping only a stretch of a path, measured along its length.
+
+use vello::kurbo::BezPath;
+use vello::kurbo::ParamCurve;
+use vello::kurbo::ParamCurveArclen;
+use vello::kurbo::PathSeg;
+
+/// Accuracy of the arc lengths that trims are measured in.
+const ARCLEN_ACCURACY: f64 = 1e-3;
+
+/// The part of `path` from `start` to `end`, as fractions of its length.
+///
+/// `offset` shifts both, and the part wraps around the end of the path
+/// back to its start. The result has no closed subpaths.
+pub(crate) fn trim(path: &BezPath, start: f64, end: f64, offset: f64) -> BezPath {
+    let (start, end) = (start.clamp(0.0, 1.0), end.clamp(0.0, 1.0));
+    let (start, end) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+    if end - start >= 1.0 {
+        return path.clone();
+    }
+    if end - start <= 0.0 {
+        return BezPath::new();
+    }
+    let start = start + offset.rem_euclid(1.0);
+    let end = end + offset.rem_euclid(1.0);
+
+    let segments: Vec<(PathSeg, f64)> = path
+        .segments()
+        .map(|segment| (segment, segment.arclen(ARCLEN_ACCURACY)))
+        .collect();
+    let length: f64 = segments.iter().map(|(_, length)| length).sum();
+    let mut trimmed = BezPath::new();
+    if end <= 1.0 {
+        push_range(&mut trimmed, &segments, start * length, end * length);
+    } else if start >= 1.0 {
+        push_range(
+            &mut trimmed,
+            &segments,
+            (start - 1.0) * length,
+            (end - 1.0) * length,
+        );
+    } else {
+        // Drawn from the wrapped end first, so that a trimmed closed path
+        // stays connected where it starts.
+        push_range(&mut trimmed, &segments, start * length, length);
+        push_range(&mut trimmed, &segments, 0.0, (end - 1.0) * length);
+    }
+    trimmed
+}
+
+/// Appends the part of `segments` between the distances `from` and `to`
+/// along them.
+fn push_range(path: &mut BezPath, segments: &[(PathSeg, f64)], from: f64, to: f64) {
+    let mut distance = 0.0;
+    for &(segment, length) in segments {
+        let (segment_start, segment_end) = (distance, distance + length);
+        distance = segment_end;
+        let (low, high) = (from.max(segment_start), to.min(segment_end));
+        if length <= 0.0 || low >= high {
+            continue;
+        }
+        let t0 = segment.inv_arclen(low - segment_start, ARCLEN_ACCURACY);
+        let t1 = segment.inv_arclen(high - segment_start, ARCLEN_ACCURACY);
+        let part = segment.subsegment(t0..t1);
+        let connected = path
+            .elements()
+            .last()
+            .and_then(|element| element.end_point())
+            .is_some_and(|end| (end - part.start()).hypot() < 1e-9);
+        if !connected {
+            path.move_to(part.start());
+        }
+        match part {
+            PathSeg::Line(line) => path.line_to(line.p1),
+            PathSeg::Quad(quad) => path.quad_to(quad.p1, quad.p2),
+            PathSeg::Cubic(cubic) => path.curve_to(cubic.p1, cubic.p2, cubic.p3),
+        }
+    }
+}
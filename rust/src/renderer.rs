@@ -0,0 +1,964 @@
+//! The render thread behind each renderer registered in `api::simple`.
+
+use crate::animation::Clock;
+use crate::animation::Playback;
+use crate::animation::Player;
+use crate::api::animation::Timeline;
+use crate::api::error::RendererError;
+use crate::api::graph::GraphUpdate;
+use crate::api::scene::SceneCommand;
+use crate::api::simple::AlphaMode;
+use crate::api::simple::AntialiasingSupport;
+use crate::api::simple::ChannelOrder;
+use crate::api::simple::Frame;
+use crate::api::simple::RenderOptions;
+use crate::api::simple::RenderQueuePolicy;
+use crate::api::simple::RendererBackend;
+use crate::api::simple::RendererConfig;
+use crate::convert::Conversion;
+use crate::convert::ConversionBuffers;
+use crate::convert::FormatConverter;
+use crate::encode::encode_scene;
+use crate::frb_generated::StreamSink;
+use crate::images::ImageRegistry;
+use crate::scene_graph::SceneGraph;
+use crate::svg_export;
+use core::num::NonZeroUsize;
+use futures_intrusive::channel::shared::oneshot_channel;
+use futures_intrusive::channel::shared::OneshotReceiver;
+use futures_intrusive::channel::shared::OneshotSender;
+use pollster::FutureExt;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use vello::block_on_wgpu;
+use vello::kurbo::Affine;
+use vello::kurbo::Point;
+use vello::kurbo::Rect;
+use vello::peniko;
+use vello::peniko::Color;
+use vello::peniko::Fill;
+use vello::Renderer;
+use vello::RendererOptions;
+use vello::Scene;
+use vello_encoding::DrawTag;
+use wgpu::BufferDescriptor;
+use wgpu::BufferUsages;
+use wgpu::CommandEncoderDescriptor;
+use wgpu::Extent3d;
+use wgpu::ImageCopyBuffer;
+use wgpu::TextureDescriptor;
+use wgpu::TextureFormat;
+use wgpu::TextureUsages;
+
+/// Number of staging buffers per render target. While one frame is being
+/// copied back from the GPU, the next one can already be rendered into the
+/// target and copied into another buffer.
+const STAGING_BUFFERS: usize = 2;
+
+/// How often a playing timeline pushes a frame of the streamed scene graph.
+const TICK_INTERVAL: Duration = Duration::from_micros(16_667);
+
+pub(crate) enum RenderCommand {
+    Render {
+        scene: Vec<SceneCommand>,
+        width: u32,
+        height: u32,
+        options: RenderOptions,
+    },
+    /// Replaces the scene streamed to subscribers and pushes a frame of it.
+    SetScene {
+        scene: Vec<SceneCommand>,
+        width: u32,
+        height: u32,
+        options: RenderOptions,
+    },
+    /// Streams the scene graph to subscribers instead, and pushes a frame of
+    /// it.
+    StreamGraph {
+        width: u32,
+        height: u32,
+        options: RenderOptions,
+    },
+    /// Adds a subscriber, which right away receives a frame of the current
+    /// scene if there is one.
+    Subscribe {
+        subscriber: Subscriber,
+    },
+    UpdateGraph {
+        updates: Vec<GraphUpdate>,
+    },
+    /// Renders the scene graph with the timeline evaluated at
+    /// `timestamp_micros`, or at the frame's own timestamp.
+    RenderGraph {
+        width: u32,
+        height: u32,
+        options: RenderOptions,
+        timestamp_micros: Option<u64>,
+    },
+    /// Replaces or removes the timeline animating the scene graph.
+    SetTimeline {
+        timeline: Option<Timeline>,
+    },
+    ControlTimeline {
+        playback: Playback,
+    },
+    /// Sends the ids of the scene graph nodes under a point to `hits`.
+    HitTest {
+        x: f64,
+        y: f64,
+        hits: OneshotSender<Vec<u64>>,
+    },
+    /// Adds a decoded image to the registry and sends its new handle to
+    /// `handle`.
+    UploadImage {
+        image: peniko::Image,
+        handle: OneshotSender<u64>,
+    },
+    ReleaseImage {
+        image: u64,
+    },
+    /// Sends the current scene, written as an SVG document, to `svg`.
+    ExportSvg {
+        svg: OneshotSender<Result<String, RendererError>>,
+    },
+}
+
+impl RenderCommand {
+    /// Which kind of frame the command draws, if any. Under `LatestWins`
+    /// only the newest frame of each kind in a batch is drawn.
+    fn frame_kind(&self) -> Option<FrameKind> {
+        match self {
+            Self::Render { .. } => Some(FrameKind::Render),
+            Self::SetScene { .. } | Self::StreamGraph { .. } => Some(FrameKind::Published),
+            Self::RenderGraph { .. } => Some(FrameKind::RenderGraph),
+            _ => None,
+        }
+    }
+}
+
+/// Frames that replace one another.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum FrameKind {
+    /// Replies to `Render`.
+    Render,
+    /// Pushed to subscribers when the streamed scene is replaced.
+    Published,
+    /// Replies to `RenderGraph`.
+    RenderGraph,
+}
+
+/// Where published frames go.
+pub(crate) enum Subscriber {
+    /// A Dart stream, see `subscribe_frames`.
+    Stream(StreamSink<Frame>),
+    /// See `subscribe_frames_to`.
+    Channel(Sender<Frame>),
+}
+
+impl Subscriber {
+    /// Returns whether the receiving end is still open.
+    fn send(&self, frame: Frame) -> bool {
+        match self {
+            Subscriber::Stream(sink) => sink.add(frame).is_ok(),
+            Subscriber::Channel(sender) => sender.send(frame).is_ok(),
+        }
+    }
+}
+
+/// What a frame draws.
+enum FrameContent<'a> {
+    Commands(&'a [SceneCommand]),
+    /// The renderer's scene graph.
+    Graph,
+}
+
+pub(crate) struct RenderRequest {
+    /// Unique per request, echoed back in the response.
+    pub(crate) id: u64,
+    pub(crate) command: RenderCommand,
+    /// Where the render thread sends the response for this request.
+    pub(crate) reply: OneshotSender<RenderResponse>,
+}
+
+pub(crate) struct RenderResponse {
+    pub(crate) request_id: u64,
+    pub(crate) data: Result<Option<Frame>, RendererError>,
+}
+
+/// State owned by a render thread: the wgpu device and everything rendered
+/// with it.
+pub(crate) struct RenderThread {
+    backend: RendererBackend,
+    queue_policy: RenderQueuePolicy,
+    /// The antialiasing methods vello compiled pipelines for.
+    antialiasing_support: AntialiasingSupport,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    renderer: Renderer,
+    scene: Scene,
+    /// The target texture and its staging buffers are only recreated when the
+    /// requested size changes.
+    target_cache: Option<RenderTarget>,
+    /// Frames that have been rendered but not read back yet, oldest first.
+    in_flight: VecDeque<InFlightFrame>,
+    /// What `SetScene` or `StreamGraph` last asked to stream.
+    current_scene: Option<CurrentScene>,
+    graph: SceneGraph,
+    /// Animates `graph` on every frame of it.
+    timeline: Option<Player>,
+    images: ImageRegistry,
+    /// Receive every frame of `current_scene`. Closed ones are dropped the
+    /// next time a frame is published.
+    subscribers: Vec<Subscriber>,
+    /// When the streamed scene graph was last published.
+    last_published_graph: Instant,
+    /// Number of frames rendered so far.
+    frame_count: u64,
+    /// Frame timestamps are measured from this instant.
+    created_at: Instant,
+    /// Created on the first frame that needs converting.
+    converter: Option<FormatConverter>,
+}
+
+impl RenderThread {
+    pub(crate) fn new(config: RendererConfig) -> Result<Self, RendererError> {
+        let RendererConfig {
+            backend,
+            queue_policy,
+            antialiasing_support,
+        } = config;
+        let (device, queue) = request_device(backend)?;
+        let renderer = Renderer::new(
+            &device,
+            RendererOptions {
+                surface_format: None,
+                use_cpu: backend == RendererBackend::Cpu,
+                antialiasing_support: antialiasing_support.into(),
+                num_init_threads: NonZeroUsize::new(1),
+            },
+        )
+        .map_err(|e| RendererError::RendererCreation(e.to_string()))?;
+
+        Ok(Self {
+            backend,
+            queue_policy,
+            antialiasing_support,
+            device,
+            queue,
+            renderer,
+            scene: Scene::new(),
+            target_cache: None,
+            in_flight: VecDeque::with_capacity(STAGING_BUFFERS),
+            current_scene: None,
+            graph: SceneGraph::default(),
+            timeline: None,
+            images: ImageRegistry::default(),
+            subscribers: Vec::new(),
+            last_published_graph: Instant::now(),
+            frame_count: 0,
+            created_at: Instant::now(),
+            converter: None,
+        })
+    }
+
+    /// Answers render requests until `destroy_renderer` drops the request
+    /// sender. Failed frames are reported to the caller and don't stop the
+    /// thread.
+    pub(crate) fn run(mut self, renderer_request_rx: Receiver<RenderRequest>) {
+        loop {
+            let request = match self.next_tick() {
+                Some(tick) => {
+                    match renderer_request_rx
+                        .recv_timeout(tick.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(request) => Some(request),
+                        Err(RecvTimeoutError::Timeout) => None,
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+                None => match renderer_request_rx.recv() {
+                    Ok(request) => Some(request),
+                    Err(_) => break,
+                },
+            };
+            if let Some(request) = request {
+                self.handle_batch(request, &renderer_request_rx);
+            }
+            // A steady stream of requests must not hold up the animation.
+            if self.next_tick().is_some_and(|tick| tick <= Instant::now()) {
+                self.publish_graph(FrameInfo::unrequested());
+            }
+
+            // Nothing else is queued, so hand out every frame still in flight.
+            self.finish_frames(0);
+        }
+    }
+
+    /// Answers `request` and every request queued behind it.
+    fn handle_batch(
+        &mut self,
+        request: RenderRequest,
+        renderer_request_rx: &Receiver<RenderRequest>,
+    ) {
+        let mut batch = vec![request];
+        batch.extend(renderer_request_rx.try_iter());
+
+        // With `LatestWins` a frame is dropped when a newer one of the same
+        // kind is in the batch, as it would be overwritten before anyone saw
+        // it.
+        let kinds: Vec<_> = batch
+            .iter()
+            .map(|request| request.command.frame_kind())
+            .collect();
+        let mut skipped_frames = HashMap::new();
+        for (index, request) in batch.into_iter().enumerate() {
+            let kind = kinds[index];
+            let superseded = self.queue_policy == RenderQueuePolicy::LatestWins
+                && kind.is_some()
+                && kinds[index + 1..].contains(&kind);
+            if superseded {
+                *skipped_frames.entry(kind).or_default() += 1;
+            }
+            let frame = FrameInfo {
+                request_id: request.id,
+                reply: Some(request.reply),
+                publish: false,
+                skipped_frames: skipped_frames.get(&kind).copied().unwrap_or(0),
+            };
+            match request.command {
+                RenderCommand::Render { .. } if superseded => {
+                    frame.finish(Ok(None));
+                }
+                RenderCommand::Render {
+                    scene,
+                    width,
+                    height,
+                    options,
+                } => {
+                    let content = FrameContent::Commands(&scene);
+                    self.render(frame, content, width, height, options);
+                }
+                RenderCommand::SetScene {
+                    scene,
+                    width,
+                    height,
+                    options,
+                } => {
+                    self.current_scene = Some(CurrentScene {
+                        content: StreamedContent::Commands(scene),
+                        width,
+                        height,
+                        options,
+                    });
+                    if superseded {
+                        frame.finish(Ok(None));
+                    } else {
+                        self.publish_current(frame);
+                    }
+                }
+                RenderCommand::StreamGraph {
+                    width,
+                    height,
+                    options,
+                } => {
+                    self.current_scene = Some(CurrentScene {
+                        content: StreamedContent::Graph,
+                        width,
+                        height,
+                        options,
+                    });
+                    if superseded {
+                        frame.finish(Ok(None));
+                    } else {
+                        self.publish_current(frame);
+                    }
+                }
+                RenderCommand::Subscribe { subscriber } => {
+                    self.subscribers.push(subscriber);
+                    self.publish_current(frame);
+                }
+                RenderCommand::UpdateGraph { updates } => {
+                    let result = updates
+                        .into_iter()
+                        .try_for_each(|update| self.graph.apply(update));
+                    match result {
+                        Ok(()) => self.publish_graph(frame),
+                        Err(e) => {
+                            // The updates before the failing one are
+                            // applied.
+                            self.publish_graph(FrameInfo::unrequested());
+                            frame.finish(Err(e));
+                        }
+                    }
+                }
+                RenderCommand::RenderGraph { .. } if superseded => {
+                    frame.finish(Ok(None));
+                }
+                RenderCommand::RenderGraph {
+                    width,
+                    height,
+                    options,
+                    timestamp_micros,
+                } => {
+                    self.animate_graph(timestamp_micros);
+                    self.render(frame, FrameContent::Graph, width, height, options)
+                }
+                RenderCommand::SetTimeline { timeline } => {
+                    self.timeline = timeline.map(Player::new);
+                    if self.timeline.is_none() {
+                        self.graph.animate(HashMap::new());
+                    }
+                    self.publish_graph(frame);
+                }
+                RenderCommand::ControlTimeline { playback } => match &mut self.timeline {
+                    Some(timeline) => {
+                        timeline.control(playback);
+                        self.publish_graph(frame);
+                    }
+                    None => frame.finish(Err(RendererError::TimelineNotSet)),
+                },
+                RenderCommand::UploadImage { image, handle } => {
+                    handle.send(self.images.insert(image)).ok();
+                    frame.finish(Ok(None));
+                }
+                RenderCommand::ReleaseImage { image } => {
+                    frame.finish(self.images.remove(image).map(|()| None));
+                }
+                RenderCommand::HitTest { x, y, hits } => {
+                    let point = Point::new(x, y);
+                    hits.send(self.graph.hit_test(point, &self.images)).ok();
+                    frame.finish(Ok(None));
+                }
+                RenderCommand::ExportSvg { svg } => {
+                    svg.send(self.export_svg()).ok();
+                    frame.finish(Ok(None));
+                }
+            }
+        }
+    }
+
+    /// When the timeline next moves the streamed scene graph, if it is
+    /// playing and anyone is watching.
+    fn next_tick(&self) -> Option<Instant> {
+        let animating = self.timeline.as_ref().is_some_and(Player::is_animating);
+        (self.streams_graph() && animating && !self.subscribers.is_empty())
+            .then_some(self.last_published_graph + TICK_INTERVAL)
+    }
+
+    /// Applies the timeline at `timestamp_micros`, or at the renderer's own
+    /// clock, to the scene graph.
+    fn animate_graph(&mut self, timestamp_micros: Option<u64>) {
+        if let Some(timeline) = &mut self.timeline {
+            let (clock, timestamp_micros) = match timestamp_micros {
+                Some(timestamp_micros) => (Clock::Caller, timestamp_micros),
+                None => (
+                    Clock::Renderer,
+                    self.created_at.elapsed().as_micros() as u64,
+                ),
+            };
+            self.graph
+                .animate(timeline.evaluate(clock, timestamp_micros));
+        }
+    }
+
+    /// Pushes a frame of the scene graph to the subscribers if it is being
+    /// streamed, and answers `frame` either way.
+    fn publish_graph(&mut self, frame: FrameInfo) {
+        if self.streams_graph() && !self.subscribers.is_empty() {
+            self.publish_current(frame);
+        } else {
+            frame.finish(Ok(None));
+        }
+    }
+
+    /// Pushes a frame of `current_scene` to the subscribers, and answers
+    /// `frame` once it has been published.
+    fn publish_current(&mut self, frame: FrameInfo) {
+        let Some(current) = self.current_scene.take() else {
+            frame.finish(Ok(None));
+            return;
+        };
+        let frame = FrameInfo {
+            publish: true,
+            ..frame
+        };
+        let content = match &current.content {
+            StreamedContent::Commands(commands) => FrameContent::Commands(commands),
+            StreamedContent::Graph => {
+                self.animate_graph(None);
+                self.last_published_graph = Instant::now();
+                FrameContent::Graph
+            }
+        };
+        self.render(
+            frame,
+            content,
+            current.width,
+            current.height,
+            current.options,
+        );
+        self.current_scene = Some(current);
+    }
+
+    /// Writes `current_scene`, with the scene graph where the timeline last
+    /// put it.
+    fn export_svg(&self) -> Result<String, RendererError> {
+        let current = self
+            .current_scene
+            .as_ref()
+            .ok_or(RendererError::SceneNotSet)?;
+        let graph_commands;
+        let commands = match &current.content {
+            StreamedContent::Commands(commands) => commands,
+            StreamedContent::Graph => {
+                graph_commands = self.graph.flatten();
+                &graph_commands
+            }
+        };
+        svg_export::export(
+            commands,
+            current.width,
+            current.height,
+            current.options.background,
+            &self.images,
+        )
+    }
+
+    fn streams_graph(&self) -> bool {
+        self.current_scene
+            .as_ref()
+            .is_some_and(|current| matches!(current.content, StreamedContent::Graph))
+    }
+
+    /// Renders `scene` and queues the copy of the result into a staging
+    /// buffer. The reply is sent once the copy has been read back, which may
+    /// only happen after the next frame has been submitted.
+    fn render(
+        &mut self,
+        frame: FrameInfo,
+        content: FrameContent,
+        width: u32,
+        height: u32,
+        options: RenderOptions,
+    ) {
+        match self.submit(content, width, height, options) {
+            Ok(readback) => {
+                let frame_number = self.frame_count;
+                self.frame_count += 1;
+                self.in_flight.push_back(InFlightFrame {
+                    frame,
+                    readback,
+                    channel_order: options.channel_order,
+                    alpha_mode: options.alpha_mode,
+                    frame_number,
+                    timestamp_micros: self.created_at.elapsed().as_micros() as u64,
+                });
+                self.finish_frames(STAGING_BUFFERS - 1);
+            }
+            Err(e) => frame.finish(Err(e)),
+        }
+    }
+
+    fn submit(
+        &mut self,
+        content: FrameContent,
+        width: u32,
+        height: u32,
+        options: RenderOptions,
+    ) -> Result<Readback, RendererError> {
+        // vello panics when asked for a method it has no pipeline for.
+        if !self.antialiasing_support.supports(options.antialiasing) {
+            return Err(RendererError::UnsupportedAntialiasing(options.antialiasing));
+        }
+
+        self.scene.reset();
+        match content {
+            FrameContent::Commands(commands) => {
+                encode_scene(&mut self.scene, commands, &self.images)?
+            }
+            FrameContent::Graph => self.graph.encode(&mut self.scene, &self.images)?,
+        }
+        // vello's CPU shaders still write radial gradients in a layout its
+        // fine shader no longer reads, which garbles them.
+        if self.backend == RendererBackend::Cpu
+            && self
+                .scene
+                .encoding()
+                .draw_tags
+                .contains(&DrawTag::RADIAL_GRADIENT)
+        {
+            return Err(RendererError::Unsupported(
+                "radial gradients on the Cpu backend".to_string(),
+            ));
+        }
+        if self.scene.encoding().is_empty() {
+            // vello can't render a scene without any paths, so give it an
+            // invisible one.
+            self.scene.fill(
+                Fill::NonZero,
+                Affine::IDENTITY,
+                Color::TRANSPARENT,
+                None,
+                &Rect::new(0.0, 0.0, 1.0, 1.0),
+            );
+        }
+
+        // wgpu rejects zero-sized textures, so an empty layout still renders
+        // a single pixel.
+        let (width, height) = (width.max(1), height.max(1));
+        // Creating a larger texture panics outside of an error scope.
+        let max_size = self.device.limits().max_texture_dimension_2d;
+        if width > max_size || height > max_size {
+            return Err(RendererError::Unsupported(format!(
+                "{width}x{height} frames, textures are at most {max_size} pixels wide"
+            )));
+        }
+        let target = match self.target_cache.take() {
+            Some(target) if target.width == width && target.height == height => target,
+            _ => {
+                // In-flight frames read from the old target's staging buffers.
+                self.finish_frames(0);
+                RenderTarget::new(&self.device, width, height)
+            }
+        };
+        let target = self.target_cache.insert(target);
+
+        // wgpu reports validation errors through a panicking default handler
+        // unless they are captured in an error scope.
+        self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let result = self
+            .renderer
+            .render_to_texture(
+                &self.device,
+                &self.queue,
+                &self.scene,
+                &target.view,
+                &vello::RenderParams {
+                    base_color: options.background.into(),
+                    width,
+                    height,
+                    antialiasing_method: options.antialiasing.into(),
+                },
+            )
+            .map_err(|e| RendererError::Render(e.to_string()))
+            .map(|()| {
+                let conversion = Conversion::for_options(&options).map(|conversion| {
+                    let converter = self
+                        .converter
+                        .get_or_insert_with(|| FormatConverter::new(&self.device));
+                    (&*converter, conversion)
+                });
+                target.copy_to_staging(&self.device, &self.queue, conversion)
+            });
+        if let Some(e) = self.device.pop_error_scope().block_on() {
+            if let Ok(readback) = result {
+                // Release the staging buffer again, its contents are garbage.
+                let _ = readback.read(&self.device);
+            }
+            return Err(RendererError::Render(e.to_string()));
+        }
+        result
+    }
+
+    /// Reads back and replies with the oldest in-flight frames until at most
+    /// `keep` are left.
+    fn finish_frames(&mut self, keep: usize) {
+        while self.in_flight.len() > keep {
+            let Some(InFlightFrame {
+                frame,
+                readback,
+                channel_order,
+                alpha_mode,
+                frame_number,
+                timestamp_micros,
+            }) = self.in_flight.pop_front()
+            else {
+                break;
+            };
+            let (width, height) = (readback.width, readback.height);
+            let data = readback.read(&self.device).map(|data| Frame {
+                data,
+                width,
+                height,
+                channel_order,
+                alpha_mode,
+                skipped_frames: frame.skipped_frames,
+                frame_number,
+                timestamp_micros,
+            });
+            let data = match data {
+                Ok(data) if frame.publish => {
+                    self.publish(data);
+                    Ok(None)
+                }
+                data => data.map(Some),
+            };
+            frame.finish(data);
+        }
+    }
+
+    /// Sends `frame` to every subscriber, forgetting those whose stream was
+    /// closed on the Dart side.
+    fn publish(&mut self, frame: Frame) {
+        self.subscribers
+            .retain(|subscriber| subscriber.send(frame.clone()));
+    }
+}
+
+/// Creates the device and queue a render thread draws with.
+///
+/// `Cpu` asks wgpu for its fallback adapter, which on headless Linux is a
+/// software rasterizer such as llvmpipe. Those are frequently only exposed
+/// through the GL backend, so every backend is enabled for that mode. As with
+/// vello's `RenderContext`, `WGPU_BACKEND` overrides the backend selection.
+fn request_device(backend: RendererBackend) -> Result<(wgpu::Device, wgpu::Queue), RendererError> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::util::backend_bits_from_env().unwrap_or(match backend {
+            RendererBackend::Gpu => wgpu::Backends::PRIMARY,
+            RendererBackend::Cpu => wgpu::Backends::all(),
+        }),
+        dx12_shader_compiler: wgpu::Dx12Compiler::Fxc,
+        ..Default::default()
+    });
+    let adapter = match backend {
+        RendererBackend::Gpu => {
+            wgpu::util::initialize_adapter_from_env_or_default(&instance, None).block_on()
+        }
+        RendererBackend::Cpu => instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::default(),
+                force_fallback_adapter: true,
+                compatible_surface: None,
+            })
+            .block_on(),
+    }
+    .ok_or(RendererError::AdapterNotFound)?;
+    adapter
+        .request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                required_features: adapter.features() & wgpu::Features::CLEAR_TEXTURE,
+                required_limits: wgpu::Limits::default(),
+            },
+            None,
+        )
+        .block_on()
+        .map_err(|e| RendererError::DeviceCreation(e.to_string()))
+}
+
+struct CurrentScene {
+    content: StreamedContent,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+}
+
+/// What `CurrentScene` draws.
+enum StreamedContent {
+    Commands(Vec<SceneCommand>),
+    /// The renderer's scene graph, as it changes.
+    Graph,
+}
+
+/// Who is waiting for a frame.
+struct FrameInfo {
+    request_id: u64,
+    /// `None` for frames nobody asked for.
+    reply: Option<OneshotSender<RenderResponse>>,
+    /// Whether the frame goes to the subscribers instead of the reply.
+    publish: bool,
+    skipped_frames: u32,
+}
+
+impl FrameInfo {
+    /// A frame only for the subscribers, such as a timeline tick.
+    fn unrequested() -> Self {
+        Self {
+            request_id: 0,
+            reply: None,
+            publish: true,
+            skipped_frames: 0,
+        }
+    }
+
+    fn finish(self, data: Result<Option<Frame>, RendererError>) {
+        if let Some(reply) = self.reply {
+            // The caller may have stopped waiting, which is fine.
+            let _ = reply.send(RenderResponse {
+                request_id: self.request_id,
+                data,
+            });
+        }
+    }
+}
+
+struct InFlightFrame {
+    frame: FrameInfo,
+    readback: Readback,
+    channel_order: ChannelOrder,
+    alpha_mode: AlphaMode,
+    frame_number: u64,
+    timestamp_micros: u64,
+}
+
+/// Render target texture plus a ring of staging buffers it is copied into
+/// for readback, all sized for a single `width` x `height` frame.
+struct RenderTarget {
+    width: u32,
+    height: u32,
+    /// Bytes per row in the staging buffers, padded to wgpu's copy alignment.
+    padded_byte_width: u32,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    /// A buffer is free while no `Readback` holds a reference to it.
+    staging_buffers: [Arc<wgpu::Buffer>; STAGING_BUFFERS],
+    /// Created on the first frame that needs converting.
+    conversion_buffers: Option<ConversionBuffers>,
+}
+
+impl RenderTarget {
+    fn new(device: &wgpu::Device, width: u32, height: u32) -> Self {
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("Target texture"),
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: TextureFormat::Rgba8Unorm,
+            usage: TextureUsages::STORAGE_BINDING
+                | TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let padded_byte_width = (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let staging_buffers = std::array::from_fn(|_| {
+            Arc::new(device.create_buffer(&BufferDescriptor {
+                label: Some("Staging buffer"),
+                size: padded_byte_width as u64 * height as u64,
+                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }))
+        });
+
+        Self {
+            width,
+            height,
+            padded_byte_width,
+            texture,
+            view,
+            staging_buffers,
+            conversion_buffers: None,
+        }
+    }
+
+    /// Queues a copy of the rendered texture into a free staging buffer and
+    /// starts mapping it. With a `conversion`, the texture is first converted
+    /// by a compute pass and the result copied instead.
+    ///
+    /// Callers must keep fewer than `STAGING_BUFFERS` readbacks alive.
+    fn copy_to_staging(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        conversion: Option<(&FormatConverter, Conversion)>,
+    ) -> Readback {
+        let buffer = self
+            .staging_buffers
+            .iter()
+            .find(|buffer| Arc::strong_count(buffer) == 1)
+            .expect("all staging buffers are in flight")
+            .clone();
+
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("Copy out buffer"),
+        });
+        match conversion {
+            Some((converter, conversion)) => {
+                let buffers = self.conversion_buffers.get_or_insert_with(|| {
+                    converter.create_buffers(
+                        device,
+                        &self.view,
+                        self.width,
+                        self.height,
+                        self.padded_byte_width,
+                    )
+                });
+                converter.encode(queue, &mut encoder, buffers, conversion);
+                encoder.copy_buffer_to_buffer(&buffers.output, 0, &buffer, 0, buffer.size());
+            }
+            None => encoder.copy_texture_to_buffer(
+                self.texture.as_image_copy(),
+                ImageCopyBuffer {
+                    buffer: &buffer,
+                    layout: wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: Some(self.padded_byte_width),
+                        rows_per_image: None,
+                    },
+                },
+                self.texture.size(),
+            ),
+        }
+        queue.submit([encoder.finish()]);
+
+        let (sender, mapped) = oneshot_channel();
+        buffer.slice(..).map_async(wgpu::MapMode::Read, move |v| {
+            let _ = sender.send(v);
+        });
+
+        Readback {
+            width: self.width,
+            height: self.height,
+            padded_byte_width: self.padded_byte_width,
+            buffer,
+            mapped,
+        }
+    }
+}
+
+/// A staging buffer that a frame is being copied into.
+struct Readback {
+    width: u32,
+    height: u32,
+    padded_byte_width: u32,
+    buffer: Arc<wgpu::Buffer>,
+    mapped: OneshotReceiver<Result<(), wgpu::BufferAsyncError>>,
+}
+
+impl Readback {
+    /// Waits for the copy to finish and returns the frame as tightly packed
+    /// rows of 4 byte pixels.
+    fn read(self, device: &wgpu::Device) -> Result<Vec<u8>, RendererError> {
+        block_on_wgpu(device, self.mapped.receive())
+            .ok_or_else(|| RendererError::Readback("Buffer mapping was cancelled".to_string()))?
+            .map_err(|e| RendererError::Readback(e.to_string()))?;
+
+        let byte_width = (self.width * 4) as usize;
+        let data = self.buffer.slice(..).get_mapped_range();
+        let result_unpadded = if byte_width == self.padded_byte_width as usize {
+            data.to_vec()
+        } else {
+            let mut result_unpadded = Vec::<u8>::with_capacity(byte_width * self.height as usize);
+            for row in data.chunks(self.padded_byte_width as usize) {
+                result_unpadded.extend_from_slice(&row[..byte_width]);
+            }
+            result_unpadded
+        };
+        drop(data);
+        self.buffer.unmap();
+
+        Ok(result_unpadded)
+    }
+}
@@ -0,0 +1,468 @@
+//! The render thread's side of `api::graph`.
+
+use crate::animation::NodeAnimation;
+use crate::api::error::RendererError;
+use crate::api::graph::GraphUpdate;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Transform;
+use crate::encode::check_layers;
+use crate::encode::encode_scene;
+use crate::encode::to_path;
+use crate::fonts;
+use crate::images::ImageRegistry;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use vello::kurbo;
+use vello::kurbo::Affine;
+use vello::kurbo::BezPath;
+use vello::kurbo::PathEl;
+use vello::kurbo::Point;
+use vello::kurbo::Rect;
+use vello::kurbo::Shape as _;
+use vello::peniko::Fill;
+use vello::Scene;
+
+/// Accuracy of the stroke outlines hit tests are done against.
+const HIT_TOLERANCE: f64 = 0.1;
+
+#[derive(Default)]
+pub(crate) struct SceneGraph {
+    nodes: HashMap<u64, Node>,
+    /// Top level nodes, in insertion order.
+    roots: Vec<u64>,
+    /// Source of `Node::order`.
+    next_order: u64,
+    /// Values set by the timeline, which override the nodes' own.
+    animations: HashMap<u64, NodeAnimation>,
+}
+
+struct Node {
+    parent: Option<u64>,
+    /// In insertion order.
+    children: Vec<u64>,
+    commands: Vec<SceneCommand>,
+    transform: Transform,
+    z_index: i32,
+    visible: bool,
+    /// Breaks `z_index` ties between siblings.
+    order: u64,
+    /// The node's commands followed by its visible children, in the node's
+    /// own coordinates. Only valid while `dirty` is false.
+    fragment: Scene,
+    /// Set when the node or one of its descendants changed. A dirty node's
+    /// ancestors are always dirty as well.
+    dirty: bool,
+}
+
+impl SceneGraph {
+    pub(crate) fn apply(&mut self, update: GraphUpdate) -> Result<(), RendererError> {
+        match update {
+            GraphUpdate::Insert {
+                id,
+                parent,
+                commands,
+                transform,
+                z_index,
+                visible,
+            } => {
+                if self.nodes.contains_key(&id) {
+                    return Err(RendererError::NodeExists(id));
+                }
+                check_layers(&commands)?;
+                self.attach(id, parent)?;
+                self.nodes.insert(
+                    id,
+                    Node {
+                        parent,
+                        children: Vec::new(),
+                        commands,
+                        transform,
+                        z_index,
+                        visible,
+                        order: self.next_order,
+                        fragment: Scene::new(),
+                        dirty: true,
+                    },
+                );
+                self.next_order += 1;
+            }
+            GraphUpdate::SetCommands { id, commands } => {
+                check_layers(&commands)?;
+                self.node_mut(id)?.commands = commands;
+                self.invalidate(Some(id));
+            }
+            // The node's own fragment is in its own coordinates and stays
+            // valid, only the parent has to place it again.
+            GraphUpdate::SetTransform { id, transform } => {
+                let node = self.node_mut(id)?;
+                node.transform = transform;
+                let parent = node.parent;
+                self.invalidate(parent);
+            }
+            GraphUpdate::SetZIndex { id, z_index } => {
+                let node = self.node_mut(id)?;
+                node.z_index = z_index;
+                let parent = node.parent;
+                self.invalidate(parent);
+            }
+            GraphUpdate::SetVisible { id, visible } => {
+                let node = self.node_mut(id)?;
+                node.visible = visible;
+                let parent = node.parent;
+                self.invalidate(parent);
+            }
+            GraphUpdate::Reparent { id, parent } => {
+                let old_parent = self.node_mut(id)?.parent;
+                let mut ancestor = parent;
+                while let Some(ancestor_id) = ancestor {
+                    if ancestor_id == id {
+                        return Err(RendererError::NodeCycle(id));
+                    }
+                    ancestor = self.node_mut(ancestor_id)?.parent;
+                }
+                self.detach(id, old_parent);
+                self.attach(id, parent)?;
+                let order = self.next_order;
+                self.next_order += 1;
+                let node = self.node_mut(id)?;
+                node.parent = parent;
+                node.order = order;
+            }
+            GraphUpdate::Remove { id } => {
+                let parent = self.node_mut(id)?.parent;
+                self.detach(id, parent);
+                let mut removed = vec![id];
+                while let Some(id) = removed.pop() {
+                    if let Some(node) = self.nodes.remove(&id) {
+                        removed.extend(node.children);
+                    }
+                }
+            }
+            // The timeline outlives the nodes it animates.
+            GraphUpdate::Clear => {
+                *self = Self {
+                    animations: std::mem::take(&mut self.animations),
+                    ..Self::default()
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the values set by the timeline, invalidating the nodes whose
+    /// values changed.
+    pub(crate) fn animate(&mut self, animations: HashMap<u64, NodeAnimation>) {
+        let old = std::mem::replace(&mut self.animations, animations);
+        let ids: Vec<u64> = old.keys().chain(self.animations.keys()).copied().collect();
+        for id in ids {
+            let Some(parent) = self.nodes.get(&id).map(|node| node.parent) else {
+                continue;
+            };
+            let (old, new) = (old.get(&id), self.animations.get(&id));
+            let transform_changed = old.and_then(|a| a.transform) != new.and_then(|a| a.transform);
+            let commands_changed = old.map(|a| &a.commands) != new.map(|a| &a.commands);
+            if transform_changed {
+                self.invalidate(parent);
+            }
+            if commands_changed {
+                self.invalidate(Some(id));
+            }
+        }
+    }
+
+    /// Appends the visible nodes to `scene`, re-encoding the subtrees that
+    /// changed since the last call.
+    pub(crate) fn encode(
+        &mut self,
+        scene: &mut Scene,
+        images: &ImageRegistry,
+    ) -> Result<(), RendererError> {
+        for id in self.sorted(&self.roots) {
+            self.update_fragment(id, images)?;
+            scene.append(&self.nodes[&id].fragment, Some(self.transform(id).into()));
+        }
+        Ok(())
+    }
+
+    /// Ids of the visible nodes whose own commands cover `point`, topmost
+    /// first.
+    pub(crate) fn hit_test(&self, point: Point, images: &ImageRegistry) -> Vec<u64> {
+        self.drawn()
+            .into_iter()
+            .rev()
+            .filter(|&(id, transform)| {
+                commands_contain(&self.commands(id), transform, point, images)
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// The commands of the visible nodes in drawing order, moved into scene
+    /// coordinates.
+    pub(crate) fn flatten(&self) -> Vec<SceneCommand> {
+        self.drawn()
+            .into_iter()
+            .flat_map(|(id, transform)| {
+                self.commands(id)
+                    .iter()
+                    .map(|command| transformed(command, transform))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// The visible nodes in drawing order, with their transforms to scene
+    /// coordinates.
+    fn drawn(&self) -> Vec<(u64, Affine)> {
+        let mut drawn = Vec::new();
+        let mut pending: Vec<(u64, Affine)> = self
+            .sorted(&self.roots)
+            .into_iter()
+            .rev()
+            .map(|id| (id, Affine::IDENTITY))
+            .collect();
+        while let Some((id, parent_transform)) = pending.pop() {
+            let node = &self.nodes[&id];
+            let transform = parent_transform * Affine::from(self.transform(id));
+            drawn.push((id, transform));
+            pending.extend(
+                self.sorted(&node.children)
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, transform)),
+            );
+        }
+
+        drawn
+    }
+
+    fn update_fragment(&mut self, id: u64, images: &ImageRegistry) -> Result<(), RendererError> {
+        let node = &self.nodes[&id];
+        if !node.dirty {
+            return Ok(());
+        }
+        let children = self.sorted(&node.children);
+        for &child in &children {
+            self.update_fragment(child, images)?;
+        }
+
+        let mut fragment = std::mem::take(&mut self.nodes.get_mut(&id).unwrap().fragment);
+        fragment.reset();
+        encode_scene(&mut fragment, &self.commands(id), images)?;
+        for child in children {
+            fragment.append(
+                &self.nodes[&child].fragment,
+                Some(self.transform(child).into()),
+            );
+        }
+        let node = self.nodes.get_mut(&id).unwrap();
+        node.fragment = fragment;
+        node.dirty = false;
+        Ok(())
+    }
+
+    /// The transform `id` is drawn with, which the timeline may set.
+    fn transform(&self, id: u64) -> Transform {
+        self.animations
+            .get(&id)
+            .and_then(|animation| animation.transform)
+            .unwrap_or(self.nodes[&id].transform)
+    }
+
+    /// The commands `id` draws, with the values the timeline sets.
+    fn commands(&self, id: u64) -> Cow<'_, [SceneCommand]> {
+        let commands = &self.nodes[&id].commands;
+        match self.animations.get(&id) {
+            Some(animation) if !animation.commands.is_empty() => {
+                Cow::Owned(animation.commands.apply(commands))
+            }
+            _ => Cow::Borrowed(commands),
+        }
+    }
+
+    /// The visible nodes of `ids`, in drawing order.
+    fn sorted(&self, ids: &[u64]) -> Vec<u64> {
+        let mut sorted: Vec<u64> = ids
+            .iter()
+            .copied()
+            .filter(|id| self.nodes[id].visible)
+            .collect();
+        sorted.sort_by_key(|id| {
+            let node = &self.nodes[id];
+            (node.z_index, node.order)
+        });
+        sorted
+    }
+
+    fn node_mut(&mut self, id: u64) -> Result<&mut Node, RendererError> {
+        self.nodes
+            .get_mut(&id)
+            .ok_or(RendererError::NodeNotFound(id))
+    }
+
+    /// Adds `id` to the children of `parent`.
+    fn attach(&mut self, id: u64, parent: Option<u64>) -> Result<(), RendererError> {
+        match parent {
+            Some(parent) => self.node_mut(parent)?.children.push(id),
+            None => self.roots.push(id),
+        }
+        self.invalidate(parent);
+        Ok(())
+    }
+
+    fn detach(&mut self, id: u64, parent: Option<u64>) {
+        let siblings = match parent {
+            Some(parent) => &mut self.nodes.get_mut(&parent).unwrap().children,
+            None => &mut self.roots,
+        };
+        siblings.retain(|&sibling| sibling != id);
+        self.invalidate(parent);
+    }
+
+    /// Marks `id` and its ancestors as dirty.
+    fn invalidate(&mut self, mut id: Option<u64>) {
+        while let Some(node) = id.and_then(|id| self.nodes.get_mut(&id)) {
+            if node.dirty {
+                // Its ancestors are dirty already.
+                break;
+            }
+            node.dirty = true;
+            id = node.parent;
+        }
+    }
+}
+
+/// `command` drawn with `transform` applied after its own.
+fn transformed(command: &SceneCommand, transform: Affine) -> SceneCommand {
+    let mut command = command.clone();
+    match &mut command {
+        SceneCommand::Fill { transform: own, .. }
+        | SceneCommand::Stroke { transform: own, .. }
+        | SceneCommand::Image { transform: own, .. }
+        | SceneCommand::GlyphRun { transform: own, .. }
+        | SceneCommand::PushLayer { transform: own, .. } => {
+            *own = (transform * Affine::from(*own)).into()
+        }
+        SceneCommand::PopLayer => {}
+    }
+    command
+}
+
+/// Whether any of `commands` draws over `point`, where layers clip it.
+fn commands_contain(
+    commands: &[SceneCommand],
+    transform: Affine,
+    point: Point,
+    images: &ImageRegistry,
+) -> bool {
+    // Whether `point` is inside every clip pushed so far.
+    let mut clips = vec![true];
+    for command in commands {
+        let inside = *clips.last().unwrap();
+        match command {
+            SceneCommand::PushLayer {
+                clip,
+                transform: clip_transform,
+                ..
+            } => {
+                let local = (transform * Affine::from(*clip_transform)).inverse() * point;
+                // vello clips layers with the non-zero rule.
+                clips.push(inside && closed(&to_path(clip)).winding(local) != 0);
+            }
+            SceneCommand::PopLayer => {
+                clips.pop();
+            }
+            _ if inside && command_contains(command, transform, point, images) => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn command_contains(
+    command: &SceneCommand,
+    transform: Affine,
+    point: Point,
+    images: &ImageRegistry,
+) -> bool {
+    match command {
+        SceneCommand::Fill {
+            shape,
+            fill_rule,
+            transform: shape_transform,
+            ..
+        } => {
+            let local = (transform * Affine::from(*shape_transform)).inverse() * point;
+            let winding = closed(&to_path(shape)).winding(local);
+            match Fill::from(*fill_rule) {
+                Fill::NonZero => winding != 0,
+                Fill::EvenOdd => winding % 2 != 0,
+            }
+        }
+        SceneCommand::Stroke {
+            shape,
+            style,
+            transform: shape_transform,
+            ..
+        } => {
+            let local = (transform * Affine::from(*shape_transform)).inverse() * point;
+            let outline = kurbo::stroke(
+                to_path(shape),
+                &style.into(),
+                &Default::default(),
+                HIT_TOLERANCE,
+            );
+            outline.winding(local) != 0
+        }
+        SceneCommand::Image {
+            image,
+            transform: image_transform,
+            ..
+        } => {
+            let Ok(image) = images.get(*image) else {
+                return false;
+            };
+            let local = (transform * Affine::from(*image_transform)).inverse() * point;
+            Rect::new(0.0, 0.0, image.width.into(), image.height.into()).contains(local)
+        }
+        // Text is hit anywhere in the advance box of one of its glyphs.
+        SceneCommand::GlyphRun {
+            font,
+            size,
+            glyphs,
+            transform: run_transform,
+            ..
+        } => {
+            let Ok(font) = fonts::get(*font) else {
+                return false;
+            };
+            let local = (transform * Affine::from(*run_transform)).inverse() * point;
+            fonts::glyph_boxes(&font, *size, glyphs)
+                .is_ok_and(|boxes| boxes.iter().any(|glyph| glyph.contains(local)))
+        }
+        // Layers draw nothing themselves, `commands_contain` clips with them.
+        SceneCommand::PushLayer { .. } | SceneCommand::PopLayer => false,
+    }
+}
+
+/// `path` with every subpath closed, the way fills treat it.
+fn closed(path: &BezPath) -> BezPath {
+    let mut closed = BezPath::new();
+    let mut open = false;
+    for element in path.elements() {
+        match element {
+            PathEl::MoveTo(_) if open => closed.close_path(),
+            PathEl::MoveTo(_) => {}
+            PathEl::ClosePath => open = false,
+            _ => open = true,
+        }
+        closed.push(*element);
+        if matches!(element, PathEl::MoveTo(_)) {
+            open = false;
+        }
+    }
+    if open {
+        closed.close_path();
+    }
+    closed
+}
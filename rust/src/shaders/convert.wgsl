@@ -0,0 +1,38 @@
+// Copies the straight-alpha RGBA target texture into a buffer of packed
+// 8 bit pixels, premultiplying alpha and swapping red and blue on request.
+
+struct Params {
+    width: u32,
+    height: u32,
+    // Row stride of `output` in pixels.
+    row_pixels: u32,
+    flags: u32,
+}
+
+const PREMULTIPLY: u32 = 1u;
+const BGRA: u32 = 2u;
+
+@group(0) @binding(0)
+var<uniform> params: Params;
+
+@group(0) @binding(1)
+var source: texture_2d<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> output: array<u32>;
+
+@compute @workgroup_size(8, 8)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x >= params.width || id.y >= params.height {
+        return;
+    }
+    var pixel = vec4<u32>(round(textureLoad(source, id.xy, 0) * 255.0));
+    if (params.flags & PREMULTIPLY) != 0u {
+        pixel = vec4<u32>((pixel.rgb * pixel.a + 127u) / 255u, pixel.a);
+    }
+    if (params.flags & BGRA) != 0u {
+        pixel = pixel.bgra;
+    }
+    output[id.y * params.row_pixels + id.x] =
+        pixel.r | (pixel.g << 8u) | (pixel.b << 16u) | (pixel.a << 24u);
+}
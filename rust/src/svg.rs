@@ -0,0 +1,524 @@
+//! The conversion behind `api::svg`. usvg parses the document and resolves
+//! styles, `use` elements, units and basic shapes into paths and groups,
+//! which are then mapped onto scene commands.
+
+use crate::api::error::RendererError;
+use crate::api::scene::BlendMode;
+use crate::api::scene::Brush;
+use crate::api::scene::Color;
+use crate::api::scene::ColorStop;
+use crate::api::scene::Compose;
+use crate::api::scene::Extend;
+use crate::api::scene::FillRule;
+use crate::api::scene::LineCap;
+use crate::api::scene::LineJoin;
+use crate::api::scene::Mix;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::Shape;
+use crate::api::scene::StrokeStyle;
+use crate::api::scene::Transform;
+use crate::api::svg::SvgScene;
+use crate::api::svg::ViewBox;
+use crate::encode::MAX_LAYER_DEPTH;
+use std::collections::HashMap;
+use usvg::roxmltree;
+use usvg::tiny_skia_path::PathSegment;
+use vello::kurbo::Affine;
+use vello::kurbo::BezPath;
+use vello::kurbo::Rect;
+use vello::kurbo::Shape as _;
+
+pub(crate) fn import(svg: &str) -> Result<SvgScene, RendererError> {
+    let tree = usvg::Tree::from_str(svg, &usvg::Options::default())
+        .map_err(|e| RendererError::InvalidSvg(e.to_string()))?;
+    // usvg maps the viewBox onto the document's size without saying how, so
+    // the attributes are read again to undo that.
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let document = roxmltree::Document::parse_with_options(svg, options)
+        .map_err(|e| RendererError::InvalidSvg(e.to_string()))?;
+    let root = document.root_element();
+    let size = tree.size();
+    let (view_box, viewport) = match root.attribute("viewBox").and_then(parse_view_box) {
+        Some(view_box) => {
+            let aspect = root.attribute("preserveAspectRatio").unwrap_or_default();
+            let viewport =
+                view_box_transform(view_box, size.width().into(), size.height().into(), aspect);
+            (view_box, viewport)
+        }
+        None => (
+            Rect::new(0.0, 0.0, size.width().into(), size.height().into()),
+            Affine::IDENTITY,
+        ),
+    };
+
+    let mut converter = Converter::default();
+    // usvg drops text without a font database, and says nothing about it.
+    if document
+        .descendants()
+        .any(|node| node.tag_name().name() == "text")
+    {
+        converter.warn("text isn't supported");
+    }
+    converter.read_focal_radii(&document, view_box);
+    converter.find_copied_gradients(tree.root());
+    converter.group_children(tree.root(), viewport.inverse());
+    Ok(SvgScene {
+        commands: converter.commands,
+        view_box: ViewBox {
+            x: view_box.x0,
+            y: view_box.y0,
+            width: view_box.width(),
+            height: view_box.height(),
+        },
+        warnings: converter.warnings,
+    })
+}
+
+#[derive(Default)]
+struct Converter {
+    commands: Vec<SceneCommand>,
+    warnings: Vec<String>,
+    /// Layers pushed for the groups being converted.
+    depth: usize,
+    /// The `fr` of radial gradients by id, which usvg doesn't keep.
+    focal_radii: HashMap<String, f64>,
+    /// The same, by the geometry of the gradient. usvg copies gradients that
+    /// are used more than once under new ids, see `find_copied_gradients`.
+    copied_focal_radii: HashMap<[u32; 5], f64>,
+}
+
+impl Converter {
+    fn warn(&mut self, warning: &str) {
+        if !self.warnings.iter().any(|w| w == warning) {
+            self.warnings.push(warning.to_string());
+        }
+    }
+
+    fn read_focal_radii(&mut self, document: &roxmltree::Document, view_box: Rect) {
+        const XLINK: &str = "http://www.w3.org/1999/xlink";
+        let gradients: HashMap<&str, roxmltree::Node> = document
+            .descendants()
+            .filter(|node| {
+                node.has_tag_name("linearGradient") || node.has_tag_name("radialGradient")
+            })
+            .filter_map(|node| Some((node.attribute("id")?, node)))
+            .collect();
+        for gradient in gradients
+            .values()
+            .filter(|node| node.has_tag_name("radialGradient"))
+        {
+            // Unset attributes are taken from the gradient `href` points to.
+            let attribute = |name| {
+                let mut node = *gradient;
+                for _ in 0..gradients.len() {
+                    if let Some(value) = node.attribute(name) {
+                        return Some(value);
+                    }
+                    let href = node.attribute((XLINK, "href")).or(node.attribute("href"))?;
+                    node = *gradients.get(href.strip_prefix('#')?)?;
+                }
+                None
+            };
+            let Some(fr) = attribute("fr").map(str::trim) else {
+                continue;
+            };
+            let radius = match fr.strip_suffix('%') {
+                Some(percent) if attribute("gradientUnits") == Some("userSpaceOnUse") => {
+                    let diagonal = (view_box.width().powi(2) + view_box.height().powi(2)) / 2.0;
+                    percent
+                        .parse::<f64>()
+                        .ok()
+                        .map(|percent| percent / 100.0 * diagonal.sqrt())
+                }
+                Some(percent) => percent.parse::<f64>().ok().map(|percent| percent / 100.0),
+                None => fr.strip_suffix("px").unwrap_or(fr).parse().ok(),
+            };
+            match radius {
+                Some(radius) if radius > 0.0 => {
+                    let id = gradient.attribute("id").unwrap_or_default();
+                    self.focal_radii.insert(id.to_string(), radius);
+                }
+                Some(_) => {}
+                None => self.warn("focal radii in units other than px and % aren't supported"),
+            }
+        }
+    }
+
+    /// Records the focal radii of the gradients in `group` by geometry. The
+    /// copies usvg makes of a gradient keep the geometry of the one that
+    /// keeps its id.
+    fn find_copied_gradients(&mut self, group: &usvg::Group) {
+        for node in group.children() {
+            match node {
+                usvg::Node::Group(group) => self.find_copied_gradients(group),
+                usvg::Node::Path(path) => {
+                    let paints = path.fill().map(usvg::Fill::paint).into_iter();
+                    for paint in paints.chain(path.stroke().map(usvg::Stroke::paint)) {
+                        if let usvg::Paint::RadialGradient(gradient) = paint {
+                            if let Some(&radius) = self.focal_radii.get(gradient.id()) {
+                                self.copied_focal_radii.insert(geometry(gradient), radius);
+                            }
+                        }
+                    }
+                }
+                usvg::Node::Image(_) | usvg::Node::Text(_) => {}
+            }
+        }
+    }
+
+    fn focal_radius(&self, gradient: &usvg::RadialGradient) -> f64 {
+        self.focal_radii
+            .get(gradient.id())
+            .or_else(|| self.copied_focal_radii.get(&geometry(gradient)))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Converts the children of `group`, whose coordinates `transform` maps
+    /// to the view box's.
+    fn group_children(&mut self, group: &usvg::Group, transform: Affine) {
+        for node in group.children() {
+            match node {
+                usvg::Node::Group(group) => self.group(group, transform),
+                usvg::Node::Path(path) => self.path(path, transform),
+                usvg::Node::Image(_) => self.warn("images aren't supported"),
+                usvg::Node::Text(_) => self.warn("text isn't supported"),
+            }
+        }
+    }
+
+    fn group(&mut self, group: &usvg::Group, parent_transform: Affine) {
+        let transform = parent_transform * affine(group.transform());
+        if !group.filters().is_empty() {
+            self.warn("filters aren't supported");
+        }
+        if group.mask().is_some() {
+            self.warn("masks aren't supported");
+        }
+
+        let clip = group.clip_path().map(|clip| self.clip_shape(clip));
+        let opacity = group.opacity().get();
+        let mix = mix(group.blend_mode());
+        let needs_layer = clip.is_some() || opacity < 1.0 || mix != Mix::Normal;
+        let pushed = needs_layer && self.depth < MAX_LAYER_DEPTH;
+        if needs_layer && !pushed {
+            self.warn("deeply nested groups lose their opacity, blend mode and clip path");
+        }
+        if pushed {
+            let clip = clip.unwrap_or_else(|| {
+                let bounds = group.layer_bounding_box();
+                Shape::Rect {
+                    x0: bounds.left().into(),
+                    y0: bounds.top().into(),
+                    x1: bounds.right().into(),
+                    y1: bounds.bottom().into(),
+                }
+            });
+            self.commands.push(SceneCommand::PushLayer {
+                clip,
+                blend: BlendMode {
+                    // Without opacity or blending the layer only clips, which
+                    // vello draws faster.
+                    mix: match mix {
+                        Mix::Normal if opacity == 1.0 => Mix::Clip,
+                        mix => mix,
+                    },
+                    compose: Compose::SrcOver,
+                },
+                alpha: opacity.into(),
+                transform: transform.into(),
+            });
+            self.depth += 1;
+        }
+        self.group_children(group, transform);
+        if pushed {
+            self.commands.push(SceneCommand::PopLayer);
+            self.depth -= 1;
+        }
+    }
+
+    /// The union of the paths in `clip`, in the coordinates of the group it
+    /// clips.
+    fn clip_shape(&mut self, clip: &usvg::ClipPath) -> Shape {
+        if clip.clip_path().is_some() {
+            self.warn("clip paths of clip paths aren't supported");
+        }
+        let mut union = BezPath::new();
+        self.collect_clip(clip.root(), affine(clip.transform()), &mut union);
+        Shape::Path {
+            elements: union.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    fn collect_clip(&mut self, group: &usvg::Group, parent_transform: Affine, union: &mut BezPath) {
+        let transform = parent_transform * affine(group.transform());
+        for node in group.children() {
+            match node {
+                usvg::Node::Group(group) => self.collect_clip(group, transform, union),
+                usvg::Node::Path(path) => {
+                    if path
+                        .fill()
+                        .is_some_and(|fill| fill.rule() == usvg::FillRule::EvenOdd)
+                    {
+                        // Layers always clip with the non-zero rule.
+                        self.warn("even-odd clip rules are drawn as non-zero");
+                    }
+                    let mut outline = bez_path(path.data());
+                    outline.apply_affine(transform);
+                    // The union is filled with the non-zero rule, so outlines
+                    // winding the other way would cut holes where they
+                    // overlap.
+                    if outline.area() < 0.0 {
+                        outline = outline.reverse_subpaths();
+                    }
+                    union.extend(outline);
+                }
+                usvg::Node::Image(_) => self.warn("images aren't supported"),
+                usvg::Node::Text(_) => self.warn("text isn't supported"),
+            }
+        }
+    }
+
+    fn path(&mut self, path: &usvg::Path, transform: Affine) {
+        if !path.is_visible() {
+            return;
+        }
+        let shape = Shape::Path {
+            elements: bez_path(path.data()).into_iter().map(Into::into).collect(),
+        };
+        let fill = path.fill().and_then(|fill| {
+            let (brush, brush_transform) = self.brush(fill.paint(), fill.opacity().get())?;
+            Some(SceneCommand::Fill {
+                shape: shape.clone(),
+                brush,
+                fill_rule: match fill.rule() {
+                    usvg::FillRule::NonZero => FillRule::NonZero,
+                    usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+                },
+                transform: transform.into(),
+                brush_transform,
+            })
+        });
+        let stroke = path.stroke().and_then(|stroke| {
+            let (brush, brush_transform) = self.brush(stroke.paint(), stroke.opacity().get())?;
+            let style = self.stroke_style(stroke);
+            Some(SceneCommand::Stroke {
+                shape: shape.clone(),
+                brush,
+                style,
+                transform: transform.into(),
+                brush_transform,
+            })
+        });
+        let (first, second) = match path.paint_order() {
+            usvg::PaintOrder::FillAndStroke => (fill, stroke),
+            usvg::PaintOrder::StrokeAndFill => (stroke, fill),
+        };
+        self.commands.extend(first);
+        self.commands.extend(second);
+    }
+
+    /// The brush and brush transform for `paint`, made `opacity` times as
+    /// opaque. `None` for paints that can't be converted.
+    fn brush(&mut self, paint: &usvg::Paint, opacity: f32) -> Option<(Brush, Option<Transform>)> {
+        match paint {
+            usvg::Paint::Color(color) => Some((color_with_opacity(*color, opacity).into(), None)),
+            usvg::Paint::LinearGradient(gradient) => Some((
+                Brush::LinearGradient {
+                    x0: gradient.x1().into(),
+                    y0: gradient.y1().into(),
+                    x1: gradient.x2().into(),
+                    y1: gradient.y2().into(),
+                    stops: stops(gradient, opacity),
+                    extend: extend(gradient.spread_method()),
+                },
+                brush_transform(gradient),
+            )),
+            usvg::Paint::RadialGradient(gradient) => Some((
+                Brush::RadialGradient {
+                    x0: gradient.fx().into(),
+                    y0: gradient.fy().into(),
+                    r0: self.focal_radius(gradient),
+                    x1: gradient.cx().into(),
+                    y1: gradient.cy().into(),
+                    r1: gradient.r().get().into(),
+                    stops: stops(gradient, opacity),
+                    extend: extend(gradient.spread_method()),
+                },
+                brush_transform(gradient),
+            )),
+            usvg::Paint::Pattern(_) => {
+                self.warn("patterns aren't supported");
+                None
+            }
+        }
+    }
+
+    fn stroke_style(&mut self, stroke: &usvg::Stroke) -> StrokeStyle {
+        let cap = match stroke.linecap() {
+            usvg::LineCap::Butt => LineCap::Butt,
+            usvg::LineCap::Round => LineCap::Round,
+            usvg::LineCap::Square => LineCap::Square,
+        };
+        StrokeStyle {
+            width: stroke.width().get().into(),
+            join: match stroke.linejoin() {
+                usvg::LineJoin::Miter => LineJoin::Miter,
+                usvg::LineJoin::MiterClip => {
+                    self.warn("miter-clip joins are drawn as miter joins");
+                    LineJoin::Miter
+                }
+                usvg::LineJoin::Round => LineJoin::Round,
+                usvg::LineJoin::Bevel => LineJoin::Bevel,
+            },
+            miter_limit: stroke.miterlimit().get().into(),
+            start_cap: cap,
+            end_cap: cap,
+            dash_pattern: stroke
+                .dasharray()
+                .map(|dashes| dashes.iter().map(|&dash| dash.into()).collect())
+                .unwrap_or_default(),
+            dash_offset: stroke.dashoffset().into(),
+        }
+    }
+}
+
+fn stops(gradient: &usvg::BaseGradient, opacity: f32) -> Vec<ColorStop> {
+    gradient
+        .stops()
+        .iter()
+        .map(|stop| ColorStop {
+            offset: stop.offset().get().into(),
+            color: color_with_opacity(stop.color(), stop.opacity().get() * opacity),
+        })
+        .collect()
+}
+
+fn geometry(gradient: &usvg::RadialGradient) -> [u32; 5] {
+    [
+        gradient.cx(),
+        gradient.cy(),
+        gradient.r().get(),
+        gradient.fx(),
+        gradient.fy(),
+    ]
+    .map(f32::to_bits)
+}
+
+fn brush_transform(gradient: &usvg::BaseGradient) -> Option<Transform> {
+    let transform = gradient.transform();
+    (!transform.is_identity()).then(|| affine(transform).into())
+}
+
+fn extend(spread_method: usvg::SpreadMethod) -> Extend {
+    match spread_method {
+        usvg::SpreadMethod::Pad => Extend::Pad,
+        usvg::SpreadMethod::Reflect => Extend::Reflect,
+        usvg::SpreadMethod::Repeat => Extend::Repeat,
+    }
+}
+
+fn mix(blend_mode: usvg::BlendMode) -> Mix {
+    match blend_mode {
+        usvg::BlendMode::Normal => Mix::Normal,
+        usvg::BlendMode::Multiply => Mix::Multiply,
+        usvg::BlendMode::Screen => Mix::Screen,
+        usvg::BlendMode::Overlay => Mix::Overlay,
+        usvg::BlendMode::Darken => Mix::Darken,
+        usvg::BlendMode::Lighten => Mix::Lighten,
+        usvg::BlendMode::ColorDodge => Mix::ColorDodge,
+        usvg::BlendMode::ColorBurn => Mix::ColorBurn,
+        usvg::BlendMode::HardLight => Mix::HardLight,
+        usvg::BlendMode::SoftLight => Mix::SoftLight,
+        usvg::BlendMode::Difference => Mix::Difference,
+        usvg::BlendMode::Exclusion => Mix::Exclusion,
+        usvg::BlendMode::Hue => Mix::Hue,
+        usvg::BlendMode::Saturation => Mix::Saturation,
+        usvg::BlendMode::Color => Mix::Color,
+        usvg::BlendMode::Luminosity => Mix::Luminosity,
+    }
+}
+
+fn color_with_opacity(color: usvg::Color, opacity: f32) -> Color {
+    Color {
+        r: color.red,
+        g: color.green,
+        b: color.blue,
+        a: (opacity.clamp(0.0, 1.0) * 255.0).round() as u8,
+    }
+}
+
+fn affine(transform: usvg::Transform) -> Affine {
+    Affine::new(
+        [
+            transform.sx,
+            transform.ky,
+            transform.kx,
+            transform.sy,
+            transform.tx,
+            transform.ty,
+        ]
+        .map(f64::from),
+    )
+}
+
+fn bez_path(path: &usvg::tiny_skia_path::Path) -> BezPath {
+    let point = |p: usvg::tiny_skia_path::Point| (f64::from(p.x), f64::from(p.y));
+    let mut bez_path = BezPath::new();
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => bez_path.move_to(point(p)),
+            PathSegment::LineTo(p) => bez_path.line_to(point(p)),
+            PathSegment::QuadTo(p1, p) => bez_path.quad_to(point(p1), point(p)),
+            PathSegment::CubicTo(p1, p2, p) => bez_path.curve_to(point(p1), point(p2), point(p)),
+            PathSegment::Close => bez_path.close_path(),
+        }
+    }
+    bez_path
+}
+
+/// Four numbers separated by whitespace or commas, with a positive size.
+fn parse_view_box(view_box: &str) -> Option<Rect> {
+    let numbers: Vec<f64> = view_box
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|number| !number.is_empty())
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    match numbers[..] {
+        [x, y, width, height] if width > 0.0 && height > 0.0 => {
+            Some(Rect::new(x, y, x + width, y + height))
+        }
+        _ => None,
+    }
+}
+
+/// Maps `view_box` onto a `width` x `height` viewport the way
+/// `preserveAspectRatio` says to.
+fn view_box_transform(view_box: Rect, width: f64, height: f64, aspect: &str) -> Affine {
+    let mut parts = aspect.split_whitespace().filter(|part| *part != "defer");
+    let align = parts.next().unwrap_or("xMidYMid");
+    let slice = parts.next() == Some("slice");
+    let origin = Affine::translate(-view_box.origin().to_vec2());
+    let (sx, sy) = (width / view_box.width(), height / view_box.height());
+    if align == "none" {
+        return Affine::scale_non_uniform(sx, sy) * origin;
+    }
+
+    let scale = if slice { sx.max(sy) } else { sx.min(sy) };
+    let offset = |free: f64, min: &str, mid: &str| {
+        if align.contains(mid) {
+            free / 2.0
+        } else if align.contains(min) {
+            0.0
+        } else {
+            free
+        }
+    };
+    let dx = offset(width - view_box.width() * scale, "xMin", "xMid");
+    let dy = offset(height - view_box.height() * scale, "YMin", "YMid");
+    Affine::translate((dx, dy)) * Affine::scale(scale) * origin
+}
@@ -0,0 +1,472 @@
+//! The writer behind `api::export::export_svg`.
+
+use crate::api::error::RendererError;
+use crate::api::scene::BlendMode;
+use crate::api::scene::Brush;
+use crate::api::scene::Color;
+use crate::api::scene::ColorStop;
+use crate::api::scene::Compose;
+use crate::api::scene::Extend;
+use crate::api::scene::FillRule;
+use crate::api::scene::LineCap;
+use crate::api::scene::LineJoin;
+use crate::api::scene::Mix;
+use crate::api::scene::SceneCommand;
+use crate::api::scene::StrokeStyle;
+use crate::encode::check_layers;
+use crate::encode::stroke_bounds;
+use crate::encode::sweep_wedges;
+use crate::encode::to_path;
+use crate::encode::TOLERANCE;
+use crate::fonts;
+use crate::images;
+use crate::images::ImageRegistry;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use std::fmt::Write as _;
+use vello::kurbo;
+use vello::kurbo::Affine;
+use vello::kurbo::BezPath;
+use vello::kurbo::Shape as _;
+
+pub(crate) fn export(
+    commands: &[SceneCommand],
+    width: u32,
+    height: u32,
+    background: Color,
+    images: &ImageRegistry,
+) -> Result<String, RendererError> {
+    check_layers(commands)?;
+    let mut writer = SvgWriter {
+        svg: String::new(),
+        next_id: 0,
+        images,
+    };
+    writeln!(
+        writer.svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    )
+    .unwrap();
+    if background.a > 0 {
+        writeln!(
+            writer.svg,
+            r#"<rect width="{width}" height="{height}"{}/>"#,
+            paint_attributes("fill", background)
+        )
+        .unwrap();
+    }
+    for command in commands {
+        writer.command(command)?;
+    }
+    writer.svg.push_str("</svg>\n");
+    Ok(writer.svg)
+}
+
+/// How a path is painted.
+#[derive(Clone, Copy)]
+enum Paint<'a> {
+    Fill(FillRule),
+    Stroke(&'a StrokeStyle),
+}
+
+struct SvgWriter<'a> {
+    svg: String,
+    /// Source of the ids of gradients, clip paths and masks.
+    next_id: usize,
+    images: &'a ImageRegistry,
+}
+
+// Writing to a `String` can't fail, so the results of `write!` are unwrapped.
+impl SvgWriter<'_> {
+    fn id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{prefix}{}", self.next_id)
+    }
+
+    fn command(&mut self, command: &SceneCommand) -> Result<(), RendererError> {
+        match command {
+            SceneCommand::Fill {
+                shape,
+                brush,
+                fill_rule,
+                transform,
+                brush_transform,
+            } => self.draw(
+                &to_path(shape),
+                (*transform).into(),
+                brush,
+                brush_transform.map(Into::into),
+                Paint::Fill(*fill_rule),
+            ),
+            SceneCommand::Stroke {
+                shape,
+                brush,
+                style,
+                transform,
+                brush_transform,
+            } => self.draw(
+                &to_path(shape),
+                (*transform).into(),
+                brush,
+                brush_transform.map(Into::into),
+                Paint::Stroke(style),
+            ),
+            SceneCommand::Image {
+                image,
+                transform,
+                opacity,
+                quality: _,
+            } => {
+                let image = self.images.get(*image)?;
+                let png = images::encode_png(
+                    image.data.data(),
+                    image.width,
+                    image.height,
+                    png::Compression::Default,
+                )?;
+                write!(
+                    self.svg,
+                    r#"<image width="{}" height="{}" href="data:image/png;base64,{}"{}"#,
+                    image.width,
+                    image.height,
+                    BASE64.encode(png),
+                    transform_attribute((*transform).into())
+                )
+                .unwrap();
+                if *opacity < 1.0 {
+                    write!(self.svg, r#" opacity="{}""#, opacity.max(0.0)).unwrap();
+                }
+                self.svg.push_str("/>\n");
+                Ok(())
+            }
+            SceneCommand::GlyphRun {
+                font,
+                size,
+                glyphs,
+                brush,
+                transform,
+            } => {
+                let outlines = fonts::glyph_outlines(&fonts::get(*font)?, *size, glyphs)?;
+                self.draw(
+                    &outlines,
+                    (*transform).into(),
+                    brush,
+                    None,
+                    Paint::Fill(FillRule::NonZero),
+                )
+            }
+            SceneCommand::PushLayer {
+                clip,
+                blend,
+                alpha,
+                transform,
+            } => {
+                let id = self.id("clip");
+                writeln!(
+                    self.svg,
+                    r#"<clipPath id="{id}"><path d="{}"{}/></clipPath>"#,
+                    to_path(clip).to_svg(),
+                    transform_attribute((*transform).into())
+                )
+                .unwrap();
+                write!(self.svg, r#"<g clip-path="url(#{id})""#).unwrap();
+                if *alpha < 1.0 {
+                    write!(self.svg, r#" opacity="{}""#, alpha.max(0.0)).unwrap();
+                }
+                if let Some(mix) = blend_mode(*blend)? {
+                    write!(self.svg, r#" style="mix-blend-mode:{mix}""#).unwrap();
+                }
+                self.svg.push_str(">\n");
+                Ok(())
+            }
+            SceneCommand::PopLayer => {
+                self.svg.push_str("</g>\n");
+                Ok(())
+            }
+        }
+    }
+
+    fn draw(
+        &mut self,
+        path: &BezPath,
+        transform: Affine,
+        brush: &Brush,
+        brush_transform: Option<Affine>,
+        paint: Paint,
+    ) -> Result<(), RendererError> {
+        // SVG gives both ends of a line the same cap, so other strokes are
+        // filled outlines instead.
+        if let Paint::Stroke(style) = paint {
+            if style.start_cap != style.end_cap {
+                let outline =
+                    kurbo::stroke(path.iter(), &style.into(), &Default::default(), TOLERANCE);
+                let fill = Paint::Fill(FillRule::NonZero);
+                return self.draw(&outline, transform, brush, brush_transform, fill);
+            }
+        }
+        let property = match paint {
+            Paint::Fill(_) => "fill",
+            Paint::Stroke(_) => "stroke",
+        };
+        let paint_attributes = match *brush {
+            Brush::Solid { color } => paint_attributes(property, color),
+            Brush::LinearGradient {
+                x0,
+                y0,
+                x1,
+                y1,
+                ref stops,
+                extend,
+            } => {
+                let id = self.id("gradient");
+                let geometry = format!(r#"x1="{x0}" y1="{y0}" x2="{x1}" y2="{y1}""#);
+                self.gradient(
+                    "linearGradient",
+                    &id,
+                    &geometry,
+                    stops,
+                    extend,
+                    brush_transform,
+                );
+                format!(r#" {property}="url(#{id})""#)
+            }
+            Brush::RadialGradient {
+                x0,
+                y0,
+                r0,
+                x1,
+                y1,
+                r1,
+                ref stops,
+                extend,
+            } => {
+                let id = self.id("gradient");
+                let geometry =
+                    format!(r#"fx="{x0}" fy="{y0}" fr="{r0}" cx="{x1}" cy="{y1}" r="{r1}""#);
+                self.gradient(
+                    "radialGradient",
+                    &id,
+                    &geometry,
+                    stops,
+                    extend,
+                    brush_transform,
+                );
+                format!(r#" {property}="url(#{id})""#)
+            }
+            Brush::SweepGradient {
+                cx,
+                cy,
+                start_angle,
+                end_angle,
+                ref stops,
+                extend,
+            } => {
+                // SVG has no sweep gradients. Like the renderer, solid wedges
+                // are drawn instead, and the path masks them.
+                let id = self.id("mask");
+                let white = Color {
+                    r: 255,
+                    g: 255,
+                    b: 255,
+                    a: 255,
+                };
+                write!(self.svg, r#"<mask id="{id}">"#).unwrap();
+                self.path(path, transform, &paint_attributes(property, white), paint);
+                self.svg.push_str("</mask>\n");
+
+                let bounds = match paint {
+                    Paint::Fill(_) => path.bounding_box(),
+                    Paint::Stroke(style) => stroke_bounds(path, style),
+                };
+                let brush_transform = brush_transform.unwrap_or(Affine::IDENTITY);
+                let wedges = sweep_wedges(
+                    (cx, cy).into(),
+                    start_angle,
+                    end_angle,
+                    stops,
+                    extend,
+                    brush_transform.inverse().transform_rect_bbox(bounds),
+                );
+                writeln!(
+                    self.svg,
+                    // Antialiasing every wedge on its own would leave seams
+                    // between them. The mask antialiases the outline.
+                    r#"<g mask="url(#{id})" shape-rendering="crispEdges"{}>"#,
+                    transform_attribute(transform * brush_transform)
+                )
+                .unwrap();
+                for (wedge, color) in wedges {
+                    let color = Color {
+                        r: color.r,
+                        g: color.g,
+                        b: color.b,
+                        a: color.a,
+                    };
+                    let attributes = paint_attributes("fill", color);
+                    writeln!(self.svg, r#"<path d="{}"{attributes}/>"#, wedge.to_svg()).unwrap();
+                }
+                self.svg.push_str("</g>\n");
+                return Ok(());
+            }
+            Brush::Image { .. } => {
+                return Err(RendererError::Unsupported(
+                    "image brushes in SVG exports".to_string(),
+                ))
+            }
+        };
+        self.path(path, transform, &paint_attributes, paint);
+        Ok(())
+    }
+
+    fn path(&mut self, path: &BezPath, transform: Affine, paint_attributes: &str, paint: Paint) {
+        write!(
+            self.svg,
+            r#"<path d="{}"{}{paint_attributes}"#,
+            path.to_svg(),
+            transform_attribute(transform)
+        )
+        .unwrap();
+        match paint {
+            Paint::Fill(FillRule::NonZero) => {}
+            Paint::Fill(FillRule::EvenOdd) => self.svg.push_str(r#" fill-rule="evenodd""#),
+            Paint::Stroke(style) => {
+                self.svg.push_str(r#" fill="none""#);
+                self.svg.push_str(&stroke_attributes(style));
+            }
+        }
+        self.svg.push_str("/>\n");
+    }
+
+    fn gradient(
+        &mut self,
+        element: &str,
+        id: &str,
+        geometry: &str,
+        stops: &[ColorStop],
+        extend: Extend,
+        brush_transform: Option<Affine>,
+    ) {
+        let spread_method = match extend {
+            Extend::Pad => "pad",
+            Extend::Repeat => "repeat",
+            Extend::Reflect => "reflect",
+        };
+        write!(
+            self.svg,
+            r#"<{element} id="{id}" gradientUnits="userSpaceOnUse" {geometry} spreadMethod="{spread_method}""#
+        )
+        .unwrap();
+        if let Some(brush_transform) = brush_transform {
+            write!(
+                self.svg,
+                r#" gradientTransform="{}""#,
+                matrix(brush_transform)
+            )
+            .unwrap();
+        }
+        self.svg.push('>');
+        for stop in stops {
+            write!(
+                self.svg,
+                r#"<stop offset="{}"{}/>"#,
+                stop.offset,
+                color_attributes("stop-color", "stop-opacity", stop.color)
+            )
+            .unwrap();
+        }
+        writeln!(self.svg, "</{element}>").unwrap();
+    }
+}
+
+/// `fill` or `stroke` set to `color`.
+fn paint_attributes(property: &str, color: Color) -> String {
+    color_attributes(property, &format!("{property}-opacity"), color)
+}
+
+/// The `name` attribute set to `color`, and `opacity_name` to its alpha if
+/// it isn't opaque.
+fn color_attributes(name: &str, opacity_name: &str, color: Color) -> String {
+    let mut attributes = format!(
+        r##" {name}="#{:02x}{:02x}{:02x}""##,
+        color.r, color.g, color.b
+    );
+    if color.a < 255 {
+        write!(
+            attributes,
+            r#" {opacity_name}="{}""#,
+            color.a as f64 / 255.0
+        )
+        .unwrap();
+    }
+    attributes
+}
+
+fn stroke_attributes(style: &StrokeStyle) -> String {
+    // `SvgWriter::draw` only strokes paths whose caps are the same.
+    let cap = match style.start_cap {
+        LineCap::Butt => "butt",
+        LineCap::Square => "square",
+        LineCap::Round => "round",
+    };
+    let join = match style.join {
+        LineJoin::Bevel => "bevel",
+        LineJoin::Miter => "miter",
+        LineJoin::Round => "round",
+    };
+    let mut attributes = format!(
+        r#" stroke-width="{}" stroke-linecap="{cap}" stroke-linejoin="{join}" stroke-miterlimit="{}""#,
+        style.width, style.miter_limit
+    );
+    if !style.dash_pattern.is_empty() {
+        let dashes: Vec<String> = style.dash_pattern.iter().map(f64::to_string).collect();
+        write!(
+            attributes,
+            r#" stroke-dasharray="{}" stroke-dashoffset="{}""#,
+            dashes.join(" "),
+            style.dash_offset
+        )
+        .unwrap();
+    }
+    attributes
+}
+
+/// The CSS `mix-blend-mode` of a layer, `None` for normal blending.
+fn blend_mode(blend: BlendMode) -> Result<Option<&'static str>, RendererError> {
+    if blend.compose != Compose::SrcOver {
+        return Err(RendererError::Unsupported(format!(
+            "{:?} layers in SVG exports",
+            blend.compose
+        )));
+    }
+    Ok(match blend.mix {
+        Mix::Normal | Mix::Clip => None,
+        Mix::Multiply => Some("multiply"),
+        Mix::Screen => Some("screen"),
+        Mix::Overlay => Some("overlay"),
+        Mix::Darken => Some("darken"),
+        Mix::Lighten => Some("lighten"),
+        Mix::ColorDodge => Some("color-dodge"),
+        Mix::ColorBurn => Some("color-burn"),
+        Mix::HardLight => Some("hard-light"),
+        Mix::SoftLight => Some("soft-light"),
+        Mix::Difference => Some("difference"),
+        Mix::Exclusion => Some("exclusion"),
+        Mix::Hue => Some("hue"),
+        Mix::Saturation => Some("saturation"),
+        Mix::Color => Some("color"),
+        Mix::Luminosity => Some("luminosity"),
+    })
+}
+
+fn transform_attribute(transform: Affine) -> String {
+    if transform == Affine::IDENTITY {
+        String::new()
+    } else {
+        format!(r#" transform="{}""#, matrix(transform))
+    }
+}
+
+fn matrix(transform: Affine) -> String {
+    let [a, b, c, d, e, f] = transform.as_coeffs();
+    format!("matrix({a} {b} {c} {d} {e} {f})")
+}
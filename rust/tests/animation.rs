@@ -0,0 +1,421 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::animation::clear_timeline;
+use rust_lib_flutter_vello::api::animation::pause_timeline;
+use rust_lib_flutter_vello::api::animation::play_timeline;
+use rust_lib_flutter_vello::api::animation::render_graph_at;
+use rust_lib_flutter_vello::api::animation::seek_timeline;
+use rust_lib_flutter_vello::api::animation::set_timeline;
+use rust_lib_flutter_vello::api::animation::AnimatedProperty;
+use rust_lib_flutter_vello::api::animation::Easing;
+use rust_lib_flutter_vello::api::animation::Keyframe;
+use rust_lib_flutter_vello::api::animation::KeyframeValue;
+use rust_lib_flutter_vello::api::animation::Timeline;
+use rust_lib_flutter_vello::api::animation::Track;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::graph::hit_test;
+use rust_lib_flutter_vello::api::graph::render_graph;
+use rust_lib_flutter_vello::api::graph::update_graph;
+use rust_lib_flutter_vello::api::graph::GraphUpdate;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::FillRule;
+use rust_lib_flutter_vello::api::scene::LineCap;
+use rust_lib_flutter_vello::api::scene::LineJoin;
+use rust_lib_flutter_vello::api::scene::PathElement;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::scene::StrokeStyle;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+
+mod common;
+
+const NODE: u64 = 1;
+const RED: Color = Color {
+    r: 255,
+    g: 0,
+    b: 0,
+    a: 255,
+};
+const BLUE: Color = Color {
+    r: 0,
+    g: 0,
+    b: 255,
+    a: 255,
+};
+
+fn translate(dx: f64) -> KeyframeValue {
+    KeyframeValue::Transform(Transform {
+        dx,
+        ..identity_transform()
+    })
+}
+
+fn keyframe(time: f64, value: KeyframeValue, easing: Easing) -> Keyframe {
+    Keyframe {
+        time,
+        value,
+        easing,
+    }
+}
+
+fn track(property: AnimatedProperty, keyframes: Vec<Keyframe>) -> Track {
+    Track {
+        node: NODE,
+        property,
+        keyframes,
+    }
+}
+
+/// Moves the node from x 0 to 50 in a second.
+fn slide(easing: Easing) -> Track {
+    track(
+        AnimatedProperty::Transform,
+        vec![
+            keyframe(0.0, translate(0.0), Easing::Linear),
+            keyframe(1.0, translate(50.0), easing),
+        ],
+    )
+}
+
+/// A renderer whose scene graph has `NODE`, a 10 x 10 red square at the
+/// origin, followed by a horizontal line from 0 to 100 at y 50.
+struct Renderer {
+    id: String,
+}
+
+impl Renderer {
+    fn new(id: &str) -> Self {
+        common::create_cpu_renderer(id);
+        let square = SceneCommand::Fill {
+            shape: Shape::Rect {
+                x0: 0.0,
+                y0: 0.0,
+                x1: 10.0,
+                y1: 10.0,
+            },
+            brush: RED.into(),
+            fill_rule: FillRule::NonZero,
+            transform: identity_transform(),
+            brush_transform: None,
+        };
+        let line = SceneCommand::Stroke {
+            shape: Shape::Path {
+                elements: vec![
+                    PathElement::MoveTo { x: 0.0, y: 50.0 },
+                    PathElement::LineTo { x: 100.0, y: 50.0 },
+                ],
+            },
+            style: StrokeStyle {
+                width: 2.0,
+                join: LineJoin::Miter,
+                miter_limit: 4.0,
+                start_cap: LineCap::Butt,
+                end_cap: LineCap::Butt,
+                dash_pattern: vec![],
+                dash_offset: 0.0,
+            },
+            brush: BLUE.into(),
+            transform: identity_transform(),
+            brush_transform: None,
+        };
+        update_graph(
+            id.to_string(),
+            vec![GraphUpdate::Insert {
+                id: NODE,
+                parent: None,
+                commands: vec![square, line],
+                transform: identity_transform(),
+                z_index: 0,
+                visible: true,
+            }],
+        )
+        .block_on()
+        .unwrap();
+        Self { id: id.to_string() }
+    }
+
+    fn set(&self, tracks: Vec<Track>, looping: bool) {
+        set_timeline(self.id.clone(), Timeline { tracks, looping })
+            .block_on()
+            .unwrap();
+    }
+
+    fn seek(&self, position: f64) {
+        seek_timeline(self.id.clone(), position).block_on().unwrap();
+    }
+
+    /// Renders a frame at `seconds` and returns the RGBA pixel at `x`, `y`.
+    fn pixel_at(&self, seconds: f64, x: u32, y: u32) -> [u8; 4] {
+        let frame = render_graph_at(
+            self.id.clone(),
+            (seconds * 1e6) as u64,
+            100,
+            80,
+            RenderOptions::default(),
+        )
+        .block_on()
+        .unwrap()
+        .unwrap();
+        let i = ((y * frame.width + x) * 4) as usize;
+        frame.data[i..i + 4].try_into().unwrap()
+    }
+
+    /// The left edge of the square in a frame rendered at `seconds`, to the
+    /// pixel.
+    fn square_x_at(&self, seconds: f64) -> u32 {
+        self.pixel_at(seconds, 0, 0);
+        (0..100)
+            .find(|&x| hit_test(self.id.clone(), x as f64 + 0.5, 5.0).block_on() == Ok(vec![NODE]))
+            .expect("the square is on screen")
+    }
+}
+
+#[test]
+fn invalid_timelines_are_errors() {
+    let renderer = Renderer::new("animation-invalid");
+    let path = |elements: Vec<PathElement>| KeyframeValue::Path(elements);
+    let invalid_tracks = [
+        track(AnimatedProperty::Opacity, vec![]),
+        track(
+            AnimatedProperty::Opacity,
+            vec![
+                keyframe(1.0, KeyframeValue::Number(0.0), Easing::Linear),
+                keyframe(0.5, KeyframeValue::Number(1.0), Easing::Linear),
+            ],
+        ),
+        track(
+            AnimatedProperty::Color { command: 0 },
+            vec![keyframe(0.0, KeyframeValue::Number(1.0), Easing::Linear)],
+        ),
+        track(
+            AnimatedProperty::Path { command: 0 },
+            vec![
+                keyframe(
+                    0.0,
+                    path(vec![PathElement::MoveTo { x: 0.0, y: 0.0 }]),
+                    Easing::Linear,
+                ),
+                keyframe(1.0, path(vec![PathElement::Close]), Easing::Linear),
+            ],
+        ),
+        slide(Easing::CubicBezier {
+            x1: 1.5,
+            y1: 0.0,
+            x2: 1.0,
+            y2: 1.0,
+        }),
+        slide(Easing::Spring {
+            stiffness: 0.0,
+            damping: 1.0,
+            mass: 1.0,
+        }),
+    ];
+    for invalid in invalid_tracks {
+        let timeline = Timeline {
+            tracks: vec![invalid.clone()],
+            looping: false,
+        };
+        assert!(
+            matches!(
+                set_timeline(renderer.id.clone(), timeline).block_on(),
+                Err(RendererError::InvalidTimeline(_))
+            ),
+            "{invalid:?} was accepted"
+        );
+    }
+
+    assert_eq!(
+        play_timeline(renderer.id.clone()).block_on(),
+        Err(RendererError::TimelineNotSet)
+    );
+}
+
+#[test]
+fn playback_follows_frame_timestamps() {
+    let renderer = Renderer::new("animation-playback");
+    renderer.set(vec![slide(Easing::Linear)], false);
+
+    // A new timeline is paused at its start.
+    assert_eq!(renderer.square_x_at(5.0), 0);
+    renderer.seek(0.5);
+    assert_eq!(renderer.square_x_at(6.0), 25);
+
+    // Playing starts from the next frame's timestamp.
+    play_timeline(renderer.id.clone()).block_on().unwrap();
+    assert_eq!(renderer.square_x_at(10.0), 25);
+    assert_eq!(renderer.square_x_at(10.2), 35);
+    pause_timeline(renderer.id.clone()).block_on().unwrap();
+    assert_eq!(renderer.square_x_at(20.0), 35);
+
+    // Playing stops at the end of the timeline.
+    play_timeline(renderer.id.clone()).block_on().unwrap();
+    assert_eq!(renderer.square_x_at(30.0), 35);
+    assert_eq!(renderer.square_x_at(40.0), 50);
+
+    // Clearing the timeline returns the node to its own transform.
+    clear_timeline(renderer.id.clone()).block_on().unwrap();
+    assert_eq!(renderer.square_x_at(50.0), 0);
+    assert_eq!(
+        pause_timeline(renderer.id.clone()).block_on(),
+        Err(RendererError::TimelineNotSet)
+    );
+}
+
+#[test]
+fn playback_continues_across_clocks() {
+    let renderer = Renderer::new("animation-clocks");
+    renderer.set(vec![slide(Easing::Linear)], false);
+    play_timeline(renderer.id.clone()).block_on().unwrap();
+
+    // The renderer's clock starts playback, then timestamps far ahead of it
+    // take over from the same position.
+    render_graph(renderer.id.clone(), 100, 80, RenderOptions::default())
+        .block_on()
+        .unwrap();
+    assert_eq!(renderer.square_x_at(100.0), 0);
+    assert_eq!(renderer.square_x_at(100.2), 10);
+}
+
+#[test]
+fn looping_timelines_start_over() {
+    let renderer = Renderer::new("animation-looping");
+    renderer.set(vec![slide(Easing::Linear)], true);
+
+    renderer.seek(1.2);
+    assert_eq!(renderer.square_x_at(0.0), 10);
+    play_timeline(renderer.id.clone()).block_on().unwrap();
+    assert_eq!(renderer.square_x_at(1.0), 10);
+    assert_eq!(renderer.square_x_at(2.6), 40);
+}
+
+#[test]
+fn keyframes_are_eased() {
+    let renderer = Renderer::new("animation-easing");
+    let x_at = |easing: Easing, position: f64| {
+        renderer.set(vec![slide(easing)], false);
+        renderer.seek(position);
+        renderer.square_x_at(0.0)
+    };
+
+    assert_eq!(x_at(Easing::Hold, 0.9), 0);
+    let ease_in = Easing::CubicBezier {
+        x1: 0.42,
+        y1: 0.0,
+        x2: 1.0,
+        y2: 1.0,
+    };
+    assert!(x_at(ease_in, 0.5) < 20);
+    // Undamped, the spring swings to 1.7 times the distance by 0.75 seconds.
+    let spring = Easing::Spring {
+        stiffness: std::f64::consts::PI.powi(2),
+        damping: 0.0,
+        mass: 1.0,
+    };
+    assert_eq!(x_at(spring, 0.75), 85);
+}
+
+#[test]
+fn opacity_fades_overlapping_commands_as_one() {
+    let renderer = Renderer::new("animation-opacity");
+    let square = |x0: f64, color: Color| SceneCommand::Fill {
+        shape: Shape::Rect {
+            x0,
+            y0: 20.0,
+            x1: x0 + 10.0,
+            y1: 30.0,
+        },
+        brush: color.into(),
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    };
+    update_graph(
+        renderer.id.clone(),
+        vec![GraphUpdate::Insert {
+            id: 2,
+            parent: None,
+            commands: vec![square(20.0, RED), square(25.0, BLUE)],
+            transform: identity_transform(),
+            z_index: 0,
+            visible: true,
+        }],
+    )
+    .block_on()
+    .unwrap();
+    renderer.set(
+        vec![Track {
+            node: 2,
+            property: AnimatedProperty::Opacity,
+            keyframes: vec![keyframe(0.0, KeyframeValue::Number(0.5), Easing::Linear)],
+        }],
+        false,
+    );
+
+    // Where the squares overlap, the blue one hides the red one before both
+    // are faded.
+    let [r, g, b, _] = renderer.pixel_at(0.0, 27, 25);
+    assert!(r == 0 && g == 0 && b.abs_diff(128) <= 1, "{r} {g} {b}");
+    let [r, g, b, _] = renderer.pixel_at(0.0, 22, 25);
+    assert!(r.abs_diff(128) <= 1 && g == 0 && b == 0, "{r} {g} {b}");
+}
+
+#[test]
+fn command_properties_are_animated() {
+    let renderer = Renderer::new("animation-commands");
+    let number =
+        |time: f64, value: f64| keyframe(time, KeyframeValue::Number(value), Easing::Linear);
+    let wide_square = |width: f64| {
+        KeyframeValue::Path(vec![
+            PathElement::MoveTo { x: 0.0, y: 0.0 },
+            PathElement::LineTo { x: width, y: 0.0 },
+            PathElement::LineTo { x: width, y: 10.0 },
+            PathElement::LineTo { x: 0.0, y: 10.0 },
+            PathElement::Close,
+        ])
+    };
+    renderer.set(
+        vec![
+            track(
+                AnimatedProperty::Color { command: 0 },
+                vec![
+                    keyframe(0.0, KeyframeValue::Color(RED), Easing::Linear),
+                    keyframe(1.0, KeyframeValue::Color(BLUE), Easing::Linear),
+                ],
+            ),
+            track(
+                AnimatedProperty::Path { command: 0 },
+                vec![
+                    keyframe(0.0, wide_square(10.0), Easing::Linear),
+                    keyframe(1.0, wide_square(30.0), Easing::Linear),
+                ],
+            ),
+            track(
+                AnimatedProperty::StrokeWidth { command: 1 },
+                vec![number(0.0, 2.0), number(1.0, 22.0)],
+            ),
+            track(
+                AnimatedProperty::Opacity,
+                vec![number(0.0, 1.0), number(2.0, 0.5)],
+            ),
+        ],
+        false,
+    );
+
+    renderer.seek(0.5);
+    // Half way from red to blue, at 87.5% opacity over black.
+    let [r, g, b, _] = renderer.pixel_at(0.0, 15, 5);
+    assert!(
+        r.abs_diff(112) <= 1 && g == 0 && b.abs_diff(112) <= 1,
+        "{r} {g} {b}"
+    );
+    // The stroke is 12 wide.
+    let [_, _, b, _] = renderer.pixel_at(0.0, 50, 55);
+    assert!(b.abs_diff(223) <= 1, "{b}");
+    assert_eq!(renderer.pixel_at(0.0, 50, 57), [0, 0, 0, 255]);
+
+    // Without opacity, the colors are unchanged.
+    renderer.seek(0.0);
+    assert_eq!(renderer.pixel_at(0.0, 5, 5), [255, 0, 0, 255]);
+    assert_eq!(renderer.pixel_at(0.0, 15, 5), [0, 0, 0, 255]);
+}
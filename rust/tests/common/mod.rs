@@ -0,0 +1,25 @@
+//! Setup shared by the integration tests. Each test crate uses only some of
+//! it.
+#![allow(dead_code)]
+
+use rust_lib_flutter_vello::api::simple::create_renderer;
+use rust_lib_flutter_vello::api::simple::AntialiasingSupport;
+use rust_lib_flutter_vello::api::simple::RenderQueuePolicy;
+use rust_lib_flutter_vello::api::simple::RendererBackend;
+use rust_lib_flutter_vello::api::simple::RendererConfig;
+
+/// A renderer on the CPU backend, which every test machine has, that draws
+/// every request.
+pub fn cpu_config() -> RendererConfig {
+    RendererConfig {
+        backend: RendererBackend::Cpu,
+        queue_policy: RenderQueuePolicy::Fifo,
+        antialiasing_support: AntialiasingSupport::default(),
+    }
+}
+
+/// Creates a renderer with `cpu_config` under `id` and returns the id.
+pub fn create_cpu_renderer(id: &str) -> String {
+    create_renderer(id.to_string(), cpu_config()).unwrap();
+    id.to_string()
+}
@@ -0,0 +1,399 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::export::encode_png;
+use rust_lib_flutter_vello::api::export::export_svg;
+use rust_lib_flutter_vello::api::export::render_png;
+use rust_lib_flutter_vello::api::export::PngCompression;
+use rust_lib_flutter_vello::api::graph::render_graph;
+use rust_lib_flutter_vello::api::graph::stream_graph;
+use rust_lib_flutter_vello::api::graph::update_graph;
+use rust_lib_flutter_vello::api::graph::GraphUpdate;
+use rust_lib_flutter_vello::api::image::upload_rgba_image;
+use rust_lib_flutter_vello::api::scene::demo_scene;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::BlendMode;
+use rust_lib_flutter_vello::api::scene::Brush;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::ColorStop;
+use rust_lib_flutter_vello::api::scene::Extend;
+use rust_lib_flutter_vello::api::scene::ImageQuality;
+use rust_lib_flutter_vello::api::scene::LineCap;
+use rust_lib_flutter_vello::api::scene::LineJoin;
+use rust_lib_flutter_vello::api::scene::Mix;
+use rust_lib_flutter_vello::api::scene::PathElement;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::scene::StrokeStyle;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::set_scene;
+use rust_lib_flutter_vello::api::simple::AlphaMode;
+use rust_lib_flutter_vello::api::simple::ChannelOrder;
+use rust_lib_flutter_vello::api::simple::Frame;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::text::layout_unshaped_text;
+use rust_lib_flutter_vello::api::text::register_font;
+
+mod common;
+
+#[test]
+fn png_decodes_to_rendered_frame() {
+    let id = common::create_cpu_renderer("export");
+    let frame = render_scene(id.clone(), demo_scene(), 320, 240, RenderOptions::default())
+        .block_on()
+        .unwrap()
+        .unwrap();
+
+    for compression in [None, Some(PngCompression::Fast), Some(PngCompression::Best)] {
+        let png = render_png(
+            id.clone(),
+            demo_scene(),
+            320,
+            240,
+            RenderOptions::default(),
+            compression,
+        )
+        .block_on()
+        .unwrap()
+        .unwrap();
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (320, 240));
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        assert!(data == frame.data, "{compression:?} changed the pixels");
+    }
+}
+
+#[test]
+fn png_of_bgra_or_premultiplied_frame_is_straight_rgba() {
+    let id = common::create_cpu_renderer("export-pixel-format");
+    let translucent = Color {
+        r: 255,
+        g: 64,
+        b: 0,
+        a: 128,
+    };
+    let render = |alpha_mode, channel_order| {
+        let options = RenderOptions {
+            background: translucent,
+            alpha_mode,
+            channel_order,
+            ..Default::default()
+        };
+        render_scene(id.clone(), demo_scene(), 64, 48, options)
+            .block_on()
+            .unwrap()
+            .unwrap()
+    };
+    let decode = |png: Vec<u8>| {
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut data).unwrap();
+        data
+    };
+    let straight_rgba = render(AlphaMode::Straight, ChannelOrder::Rgba);
+
+    let bgra = render(AlphaMode::Straight, ChannelOrder::Bgra);
+    assert!(bgra.data != straight_rgba.data);
+    assert!(decode(encode_png(bgra, None).unwrap()) == straight_rgba.data);
+
+    // Premultiplying rounds, so unpremultiplying can't restore every bit.
+    let premultiplied = render(AlphaMode::Premultiplied, ChannelOrder::Bgra);
+    let decoded = decode(encode_png(premultiplied, None).unwrap());
+    assert!(decoded
+        .iter()
+        .zip(&straight_rgba.data)
+        .all(|(&decoded, &expected)| decoded.abs_diff(expected) <= 1));
+}
+
+const BACKGROUND: Color = Color {
+    r: 30,
+    g: 30,
+    b: 46,
+    a: 255,
+};
+
+/// Everything SVG exports keep as vectors: the demo's shapes, a dashed
+/// gradient stroke and a sweep gradient in a translucent clip layer, and
+/// text.
+fn vector_scene() -> Vec<SceneCommand> {
+    let stops = vec![
+        ColorStop {
+            offset: 0.0,
+            color: Color {
+                r: 243,
+                g: 139,
+                b: 168,
+                a: 255,
+            },
+        },
+        ColorStop {
+            offset: 1.0,
+            color: Color {
+                r: 137,
+                g: 180,
+                b: 250,
+                a: 128,
+            },
+        },
+    ];
+    let font = register_font(include_bytes!("fonts/DejaVuSansMono.ttf").to_vec()).unwrap();
+    let mut scene = demo_scene();
+    scene.extend([
+        SceneCommand::PushLayer {
+            clip: Shape::Circle {
+                cx: 200.0,
+                cy: 120.0,
+                radius: 90.0,
+            },
+            blend: BlendMode {
+                mix: Mix::Normal,
+                ..Default::default()
+            },
+            alpha: 0.75,
+            transform: identity_transform(),
+        },
+        SceneCommand::Stroke {
+            shape: Shape::Rect {
+                x0: 140.0,
+                y0: 60.0,
+                x1: 260.0,
+                y1: 180.0,
+            },
+            brush: Brush::LinearGradient {
+                x0: 140.0,
+                y0: 0.0,
+                x1: 260.0,
+                y1: 0.0,
+                stops: stops.clone(),
+                extend: Extend::Pad,
+            },
+            style: StrokeStyle {
+                width: 12.0,
+                join: LineJoin::Round,
+                miter_limit: 4.0,
+                start_cap: LineCap::Round,
+                end_cap: LineCap::Round,
+                dash_pattern: vec![30.0, 15.0],
+                dash_offset: 5.0,
+            },
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+        SceneCommand::Fill {
+            shape: Shape::Ellipse {
+                cx: 200.0,
+                cy: 120.0,
+                rx: 40.0,
+                ry: 30.0,
+                rotation: 0.0,
+            },
+            brush: Brush::SweepGradient {
+                cx: 200.0,
+                cy: 120.0,
+                start_angle: 0.0,
+                end_angle: std::f64::consts::TAU,
+                stops,
+                extend: Extend::Pad,
+            },
+            fill_rule: Default::default(),
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+        SceneCommand::PopLayer,
+        SceneCommand::GlyphRun {
+            font,
+            size: 28.0,
+            glyphs: layout_unshaped_text(font, "SVG".to_string(), 28.0)
+                .unwrap()
+                .glyphs,
+            brush: Color {
+                r: 166,
+                g: 227,
+                b: 161,
+                a: 255,
+            }
+            .into(),
+            transform: Transform {
+                dx: 10.0,
+                dy: 190.0,
+                ..identity_transform()
+            },
+        },
+    ]);
+    scene
+}
+
+fn options() -> RenderOptions {
+    RenderOptions {
+        background: BACKGROUND,
+        ..Default::default()
+    }
+}
+
+/// Draws `svg` with an independent SVG renderer.
+fn rasterize(svg: &str) -> resvg::tiny_skia::Pixmap {
+    let tree = resvg::usvg::Tree::from_str(svg, &Default::default()).unwrap();
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(320, 240).unwrap();
+    resvg::render(&tree, Default::default(), &mut pixmap.as_mut());
+    pixmap
+}
+
+/// Checks that `pixmap` has the pixels of `frame`, apart from how edges are
+/// antialiased.
+fn assert_draws_like(pixmap: &resvg::tiny_skia::Pixmap, frame: &Frame) {
+    let differing = frame
+        .data
+        .chunks(4)
+        .zip(pixmap.data().chunks(4))
+        .filter(|(e, a)| e.iter().zip(a.iter()).any(|(e, a)| e.abs_diff(*a) > 32))
+        .count();
+    assert!(
+        differing < frame.data.len() / 4 / 100,
+        "{differing} pixels differ"
+    );
+}
+
+#[test]
+fn svg_draws_like_the_scene() {
+    let id = common::create_cpu_renderer("export-svg");
+    set_scene(id.clone(), vector_scene(), 320, 240, options())
+        .block_on()
+        .unwrap();
+
+    let svg = export_svg(id.clone()).block_on().unwrap();
+    for expected in [
+        r#"width="320" height="240""#,
+        r#"stroke-linejoin="round""#,
+        r#"stroke-dasharray="30 15" stroke-dashoffset="5""#,
+        "<linearGradient",
+        "<clipPath",
+        r#"opacity="0.75""#,
+    ] {
+        assert!(svg.contains(expected), "{expected} is missing from {svg}");
+    }
+
+    let frame = render_scene(id, vector_scene(), 320, 240, options())
+        .block_on()
+        .unwrap()
+        .unwrap();
+    assert_draws_like(&rasterize(&svg), &frame);
+}
+
+#[test]
+fn svg_draws_like_the_scene_graph() {
+    let id = common::create_cpu_renderer("export-svg-graph");
+    // A gradient, so that the image can't be mistaken for a flat fill.
+    let pixels = (0..40 * 40)
+        .flat_map(|i| [(i % 40 * 6) as u8, (i / 40 * 6) as u8, 200, 255])
+        .collect();
+    let image = upload_rgba_image(id.clone(), pixels, 40, 40)
+        .block_on()
+        .unwrap();
+    let translate = |dx, dy| Transform {
+        dx,
+        dy,
+        ..identity_transform()
+    };
+    // A line from x 0 to 120 at y 80, with a butt cap at its start and a
+    // round one at its end.
+    let line = SceneCommand::Stroke {
+        shape: Shape::Path {
+            elements: vec![
+                PathElement::MoveTo { x: 0.0, y: 80.0 },
+                PathElement::LineTo { x: 120.0, y: 80.0 },
+            ],
+        },
+        brush: Color {
+            r: 250,
+            g: 179,
+            b: 135,
+            a: 255,
+        }
+        .into(),
+        style: StrokeStyle {
+            width: 16.0,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            start_cap: LineCap::Butt,
+            end_cap: LineCap::Round,
+            dash_pattern: vec![],
+            dash_offset: 0.0,
+        },
+        transform: identity_transform(),
+        brush_transform: None,
+    };
+    let updates = vec![
+        GraphUpdate::Insert {
+            id: 1,
+            parent: None,
+            commands: vec![],
+            transform: translate(100.0, 20.0),
+            z_index: 0,
+            visible: true,
+        },
+        GraphUpdate::Insert {
+            id: 2,
+            parent: Some(1),
+            commands: vec![
+                SceneCommand::Image {
+                    image,
+                    transform: identity_transform(),
+                    opacity: 1.0,
+                    quality: ImageQuality::Medium,
+                },
+                line,
+            ],
+            transform: translate(20.0, 20.0),
+            z_index: 0,
+            visible: true,
+        },
+    ];
+    update_graph(id.clone(), updates).block_on().unwrap();
+    stream_graph(id.clone(), 320, 240, options())
+        .block_on()
+        .unwrap();
+
+    let svg = export_svg(id.clone()).block_on().unwrap();
+    assert!(svg.contains(r#"href="data:image/png;base64,"#), "{svg}");
+    let pixmap = rasterize(&svg);
+    let frame = render_graph(id, 320, 240, options())
+        .block_on()
+        .unwrap()
+        .unwrap();
+    assert_draws_like(&pixmap, &frame);
+
+    // The line runs from x 120 to 240 in scene coordinates. Only its end is
+    // capped.
+    let painted = |x: u32| pixmap.pixel(x, 120).unwrap().red() > 200;
+    assert!(!painted(116));
+    assert!(painted(244));
+}
+
+#[test]
+fn scenes_without_svg_equivalents_are_errors() {
+    let id = common::create_cpu_renderer("export-svg-errors");
+    let export = || export_svg(id.clone()).block_on();
+    assert_eq!(export(), Err(RendererError::SceneNotSet));
+
+    let image_brush = SceneCommand::Fill {
+        shape: Shape::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 10.0,
+            y1: 10.0,
+        },
+        brush: Brush::Image { image: 0 },
+        fill_rule: Default::default(),
+        transform: identity_transform(),
+        brush_transform: None,
+    };
+    // Rendering fails too, for the missing image, but the scene is set.
+    let _ = set_scene(id.clone(), vec![image_brush], 10, 10, options()).block_on();
+    assert!(matches!(export(), Err(RendererError::Unsupported(_))));
+
+    let _ = set_scene(id.clone(), vec![SceneCommand::PopLayer], 10, 10, options()).block_on();
+    assert!(matches!(export(), Err(RendererError::InvalidLayers(_))));
+}
@@ -0,0 +1,733 @@
+//! Renders scenes on the CPU backend and compares them against the PNGs in
+//! `tests/goldens`.
+//!
+//! Run with `UPDATE_GOLDENS=1` to (re)write the goldens from the current
+//! output. On a mismatch the actual frame and a diff image are written to
+//! `target/tmp/golden-failures`.
+
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::image::upload_rgba_image;
+use rust_lib_flutter_vello::api::lottie::load_lottie;
+use rust_lib_flutter_vello::api::lottie::lottie_frame;
+use rust_lib_flutter_vello::api::scene::demo_scene;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::BlendMode;
+use rust_lib_flutter_vello::api::scene::Brush;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::ColorStop;
+use rust_lib_flutter_vello::api::scene::Compose;
+use rust_lib_flutter_vello::api::scene::Extend;
+use rust_lib_flutter_vello::api::scene::FillRule;
+use rust_lib_flutter_vello::api::scene::ImageQuality;
+use rust_lib_flutter_vello::api::scene::LineCap;
+use rust_lib_flutter_vello::api::scene::LineJoin;
+use rust_lib_flutter_vello::api::scene::Mix;
+use rust_lib_flutter_vello::api::scene::PathElement;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::scene::StrokeStyle;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::Antialiasing;
+use rust_lib_flutter_vello::api::simple::Frame;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::svg::import_svg;
+use rust_lib_flutter_vello::api::text::layout_unshaped_text;
+use rust_lib_flutter_vello::api::text::register_font;
+use std::f64::consts::FRAC_1_SQRT_2;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::path::PathBuf;
+use std::thread;
+
+mod common;
+
+const RENDERER_ID: &str = "golden";
+
+/// Largest per-channel difference that still counts as a matching pixel.
+/// Leaves room for rounding differences between rasterizers.
+const CHANNEL_TOLERANCE: u8 = 2;
+
+/// Fraction of pixels that may exceed `CHANNEL_TOLERANCE` before a frame
+/// counts as different, for anti-aliased edges that land slightly apart.
+const PIXEL_TOLERANCE: f64 = 0.001;
+
+fn render(scene: Vec<SceneCommand>, width: u32, height: u32) -> Frame {
+    render_with_options(scene, width, height, RenderOptions::default())
+}
+
+fn render_with_options(
+    scene: Vec<SceneCommand>,
+    width: u32,
+    height: u32,
+    options: RenderOptions,
+) -> Frame {
+    create_golden_renderer();
+    render_scene(RENDERER_ID.to_string(), scene, width, height, options)
+        .block_on()
+        .expect("rendering succeeds")
+        .expect("Fifo renderers don't skip frames")
+}
+
+fn create_golden_renderer() {
+    // Every test shares one renderer; creating it again is a no-op.
+    common::create_cpu_renderer(RENDERER_ID);
+}
+
+fn assert_golden(name: &str, frame: &Frame) {
+    let golden_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/goldens")
+        .join(format!("{name}.png"));
+    if std::env::var_os("UPDATE_GOLDENS").is_some() {
+        write_png(&golden_path, frame.width, frame.height, &frame.data);
+        return;
+    }
+
+    let (width, height, golden) = read_png(&golden_path).unwrap_or_else(|e| {
+        panic!(
+            "Couldn't read {}: {e}. Run with UPDATE_GOLDENS=1 to create it.",
+            golden_path.display()
+        )
+    });
+    assert_eq!(
+        (frame.width, frame.height),
+        (width, height),
+        "{name}: frame size differs from the golden"
+    );
+
+    let mut diff = Vec::with_capacity(golden.len());
+    let mut differing_pixels = 0;
+    for (actual, expected) in frame.data.chunks(4).zip(golden.chunks(4)) {
+        let matches = actual
+            .iter()
+            .zip(expected)
+            .all(|(a, e)| a.abs_diff(*e) <= CHANNEL_TOLERANCE);
+        if matches {
+            // Faded copy of the golden, so the differences stand out.
+            let luma = (expected[0] as u32 + expected[1] as u32 + expected[2] as u32) / 3;
+            let faded = (luma / 4) as u8;
+            diff.extend_from_slice(&[faded, faded, faded, 255]);
+        } else {
+            differing_pixels += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        }
+    }
+
+    let allowed = (PIXEL_TOLERANCE * (width * height) as f64) as usize;
+    if differing_pixels > allowed {
+        let failures = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden-failures");
+        std::fs::create_dir_all(&failures).unwrap();
+        let actual_path = failures.join(format!("{name}.actual.png"));
+        let diff_path = failures.join(format!("{name}.diff.png"));
+        write_png(&actual_path, width, height, &frame.data);
+        write_png(&diff_path, width, height, &diff);
+        panic!(
+            "{name}: {differing_pixels} pixels differ from the golden (at most {allowed} allowed). \
+             See {} and {}",
+            actual_path.display(),
+            diff_path.display()
+        );
+    }
+}
+
+fn read_png(path: &PathBuf) -> Result<(u32, u32, Vec<u8>), png::DecodingError> {
+    let mut decoder = png::Decoder::new(File::open(path)?);
+    decoder.set_transformations(png::Transformations::EXPAND);
+    let mut reader = decoder.read_info()?;
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data)?;
+    assert_eq!(
+        (info.color_type, info.bit_depth),
+        (png::ColorType::Rgba, png::BitDepth::Eight),
+        "goldens are 8 bit RGBA"
+    );
+    data.truncate(info.buffer_size());
+    Ok((info.width, info.height, data))
+}
+
+fn write_png(path: &PathBuf, width: u32, height: u32, data: &[u8]) {
+    let file = BufWriter::new(File::create(path).unwrap());
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(data).unwrap();
+}
+
+fn color(r: u8, g: u8, b: u8) -> Color {
+    Color { r, g, b, a: 255 }
+}
+
+fn stroke(width: f64) -> StrokeStyle {
+    StrokeStyle {
+        width,
+        join: LineJoin::Miter,
+        miter_limit: 4.0,
+        start_cap: LineCap::Butt,
+        end_cap: LineCap::Butt,
+        dash_pattern: vec![],
+        dash_offset: 0.0,
+    }
+}
+
+/// The same self-intersecting star filled with both fill rules, side by side.
+fn fill_rules_scene() -> Vec<SceneCommand> {
+    let star = |dx: f64| Shape::Path {
+        elements: vec![
+            PathElement::MoveTo {
+                x: 50.0 + dx,
+                y: 5.0,
+            },
+            PathElement::LineTo {
+                x: 78.0 + dx,
+                y: 92.0,
+            },
+            PathElement::LineTo {
+                x: 5.0 + dx,
+                y: 38.0,
+            },
+            PathElement::LineTo {
+                x: 95.0 + dx,
+                y: 38.0,
+            },
+            PathElement::LineTo {
+                x: 22.0 + dx,
+                y: 92.0,
+            },
+            PathElement::Close,
+        ],
+    };
+    vec![
+        SceneCommand::Fill {
+            shape: star(0.0),
+            brush: color(137, 180, 250).into(),
+            fill_rule: FillRule::NonZero,
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+        SceneCommand::Fill {
+            shape: star(100.0),
+            brush: color(137, 180, 250).into(),
+            fill_rule: FillRule::EvenOdd,
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+    ]
+}
+
+/// Dashes, caps, joins and a rotated, translucent shape.
+fn strokes_scene() -> Vec<SceneCommand> {
+    let zigzag = |y: f64| Shape::Path {
+        elements: vec![
+            PathElement::MoveTo { x: 20.0, y },
+            PathElement::LineTo {
+                x: 60.0,
+                y: y - 25.0,
+            },
+            PathElement::LineTo { x: 100.0, y },
+        ],
+    };
+    vec![
+        SceneCommand::Stroke {
+            shape: Shape::Line {
+                x0: 20.0,
+                y0: 20.0,
+                x1: 180.0,
+                y1: 20.0,
+            },
+            brush: color(250, 179, 135).into(),
+            style: StrokeStyle {
+                dash_pattern: vec![12.0, 6.0],
+                dash_offset: 3.0,
+                ..stroke(4.0)
+            },
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+        SceneCommand::Stroke {
+            shape: zigzag(80.0),
+            brush: color(123, 201, 111).into(),
+            style: StrokeStyle {
+                start_cap: LineCap::Round,
+                end_cap: LineCap::Square,
+                ..stroke(10.0)
+            },
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+        SceneCommand::Stroke {
+            shape: zigzag(130.0),
+            brush: color(203, 166, 247).into(),
+            style: StrokeStyle {
+                join: LineJoin::Bevel,
+                ..stroke(10.0)
+            },
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+        SceneCommand::Fill {
+            shape: Shape::Rect {
+                x0: -30.0,
+                y0: -30.0,
+                x1: 30.0,
+                y1: 30.0,
+            },
+            brush: Color {
+                a: 160,
+                ..color(243, 139, 168)
+            }
+            .into(),
+            fill_rule: FillRule::NonZero,
+            transform: Transform {
+                xx: FRAC_1_SQRT_2,
+                yx: FRAC_1_SQRT_2,
+                xy: -FRAC_1_SQRT_2,
+                yy: FRAC_1_SQRT_2,
+                dx: 150.0,
+                dy: 140.0,
+            },
+            brush_transform: None,
+        },
+    ]
+}
+
+/// Linear and sweep gradients with each extend mode and a brush transform.
+/// Radial gradients are left out, the CPU backend can't draw them.
+fn gradients_scene() -> Vec<SceneCommand> {
+    let stops = vec![
+        ColorStop {
+            offset: 0.0,
+            color: color(243, 139, 168),
+        },
+        ColorStop {
+            offset: 0.5,
+            color: color(249, 226, 175),
+        },
+        ColorStop {
+            offset: 1.0,
+            color: color(137, 180, 250),
+        },
+    ];
+    let square = |x0: f64, y0: f64| Shape::Rect {
+        x0,
+        y0,
+        x1: x0 + 80.0,
+        y1: y0 + 80.0,
+    };
+    let fill = |shape, brush, brush_transform| SceneCommand::Fill {
+        shape,
+        brush,
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform,
+    };
+    vec![
+        fill(
+            square(10.0, 10.0),
+            Brush::LinearGradient {
+                x0: 30.0,
+                y0: 0.0,
+                x1: 60.0,
+                y1: 0.0,
+                stops: stops.clone(),
+                extend: Extend::Reflect,
+            },
+            None,
+        ),
+        fill(
+            square(110.0, 10.0),
+            Brush::LinearGradient {
+                x0: 0.0,
+                y0: 0.0,
+                x1: 20.0,
+                y1: 0.0,
+                stops: stops.clone(),
+                extend: Extend::Repeat,
+            },
+            // Rotated by 45 degrees.
+            Some(Transform {
+                xx: FRAC_1_SQRT_2,
+                yx: FRAC_1_SQRT_2,
+                xy: -FRAC_1_SQRT_2,
+                yy: FRAC_1_SQRT_2,
+                dx: 0.0,
+                dy: 0.0,
+            }),
+        ),
+        fill(
+            Shape::Circle {
+                cx: 50.0,
+                cy: 150.0,
+                radius: 40.0,
+            },
+            Brush::SweepGradient {
+                cx: 50.0,
+                cy: 150.0,
+                start_angle: 0.0,
+                end_angle: std::f64::consts::PI,
+                stops: stops.clone(),
+                extend: Extend::Pad,
+            },
+            None,
+        ),
+        SceneCommand::Stroke {
+            shape: Shape::Circle {
+                cx: 150.0,
+                cy: 150.0,
+                radius: 30.0,
+            },
+            brush: Brush::SweepGradient {
+                cx: 150.0,
+                cy: 150.0,
+                start_angle: 0.0,
+                end_angle: std::f64::consts::TAU,
+                stops,
+                extend: Extend::Pad,
+            },
+            style: stroke(16.0),
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+    ]
+}
+
+/// A 4x4 checkerboard of two colors, with a translucent corner.
+fn upload_checkerboard() -> u64 {
+    let data = (0..16)
+        .flat_map(|i| match (i % 4 + i / 4) % 2 {
+            _ if i == 15 => [166, 227, 161, 128],
+            0 => [249, 226, 175, 255],
+            _ => [116, 199, 236, 255],
+        })
+        .collect();
+    create_golden_renderer();
+    upload_rgba_image(RENDERER_ID.to_string(), data, 4, 4)
+        .block_on()
+        .expect("upload succeeds")
+}
+
+/// An image scaled up, the same image half transparent, and a stroke painted
+/// with it.
+fn images_scene(image: u64) -> Vec<SceneCommand> {
+    let scale = |factor: f64, dx: f64, dy: f64| Transform {
+        xx: factor,
+        yx: 0.0,
+        xy: 0.0,
+        yy: factor,
+        dx,
+        dy,
+    };
+    vec![
+        SceneCommand::Image {
+            image,
+            transform: scale(20.0, 10.0, 10.0),
+            opacity: 1.0,
+            quality: ImageQuality::High,
+        },
+        SceneCommand::Image {
+            image,
+            transform: scale(20.0, 110.0, 10.0),
+            opacity: 0.5,
+            quality: ImageQuality::High,
+        },
+        SceneCommand::Stroke {
+            shape: Shape::Circle {
+                cx: 100.0,
+                cy: 150.0,
+                radius: 30.0,
+            },
+            brush: Brush::Image { image },
+            style: stroke(20.0),
+            transform: identity_transform(),
+            brush_transform: Some(scale(20.0, 60.0, 110.0)),
+        },
+    ]
+}
+
+/// Two lines of text in a solid color and one with a gradient, rotated.
+fn text_scene() -> Vec<SceneCommand> {
+    let font = register_font(include_bytes!("fonts/DejaVuSansMono.ttf").to_vec())
+        .expect("the test font is valid");
+    let layout = |text: &str, size| {
+        layout_unshaped_text(font, text.to_string(), size)
+            .expect("the font was just registered")
+            .glyphs
+    };
+    vec![
+        SceneCommand::GlyphRun {
+            font,
+            size: 24.0,
+            glyphs: layout("Hello,\nvello!", 24.0),
+            brush: color(205, 214, 244).into(),
+            transform: Transform {
+                dx: 10.0,
+                dy: 10.0,
+                ..identity_transform()
+            },
+        },
+        SceneCommand::GlyphRun {
+            font,
+            size: 32.0,
+            glyphs: layout("Text", 32.0),
+            brush: Brush::LinearGradient {
+                x0: 0.0,
+                y0: 0.0,
+                x1: 80.0,
+                y1: 0.0,
+                stops: vec![
+                    ColorStop {
+                        offset: 0.0,
+                        color: color(243, 139, 168),
+                    },
+                    ColorStop {
+                        offset: 1.0,
+                        color: color(137, 180, 250),
+                    },
+                ],
+                extend: Extend::Pad,
+            },
+            transform: Transform {
+                xx: FRAC_1_SQRT_2,
+                yx: -FRAC_1_SQRT_2,
+                xy: FRAC_1_SQRT_2,
+                yy: FRAC_1_SQRT_2,
+                dx: 100.0,
+                dy: 160.0,
+            },
+        },
+    ]
+}
+
+fn layers_scene() -> Vec<SceneCommand> {
+    let circle = |cx, cy| Shape::Circle {
+        cx,
+        cy,
+        radius: 30.0,
+    };
+    let fill = |shape, color| SceneCommand::Fill {
+        shape,
+        brush: Brush::Solid { color },
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    };
+    let push = |clip, mix, compose, alpha| SceneCommand::PushLayer {
+        clip,
+        blend: BlendMode { mix, compose },
+        alpha,
+        transform: identity_transform(),
+    };
+    let everything = Shape::Rect {
+        x0: 0.0,
+        y0: 0.0,
+        x1: 200.0,
+        y1: 200.0,
+    };
+    let red = color(243, 139, 168);
+    let blue = color(137, 180, 250);
+    vec![
+        // Two squares clipped to a circle.
+        push(circle(50.0, 50.0), Mix::Clip, Compose::SrcOver, 1.0),
+        fill(
+            Shape::Rect {
+                x0: 0.0,
+                y0: 0.0,
+                x1: 100.0,
+                y1: 50.0,
+            },
+            red,
+        ),
+        fill(
+            Shape::Rect {
+                x0: 0.0,
+                y0: 50.0,
+                x1: 100.0,
+                y1: 100.0,
+            },
+            blue,
+        ),
+        SceneCommand::PopLayer,
+        // Group opacity: the overlap isn't darker than the rest.
+        push(everything.clone(), Mix::Normal, Compose::SrcOver, 0.5),
+        fill(circle(130.0, 50.0), red),
+        fill(circle(160.0, 50.0), red),
+        SceneCommand::PopLayer,
+        // Multiply over a backdrop.
+        fill(circle(40.0, 150.0), color(249, 226, 175)),
+        push(everything.clone(), Mix::Multiply, Compose::SrcOver, 1.0),
+        fill(circle(70.0, 150.0), blue),
+        SceneCommand::PopLayer,
+        // Xor inside an isolated group leaves the overlap empty.
+        push(everything.clone(), Mix::Normal, Compose::SrcOver, 1.0),
+        fill(circle(130.0, 150.0), red),
+        push(everything, Mix::Normal, Compose::Xor, 1.0),
+        fill(circle(160.0, 150.0), blue),
+        SceneCommand::PopLayer,
+        SceneCommand::PopLayer,
+    ]
+}
+
+/// An icon with a gradient, a stroke, a translucent group and a clip path,
+/// drawn at two sizes.
+fn svg_scene() -> Vec<SceneCommand> {
+    let icon = import_svg(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="48" height="48"
+                 viewBox="0 0 24 24">
+              <defs>
+                <linearGradient id="sky" x1="0" y1="0" x2="0" y2="1">
+                  <stop offset="0" stop-color="#89b4fa"/>
+                  <stop offset="1" stop-color="#cba6f7"/>
+                </linearGradient>
+                <clipPath id="half"><rect width="24" height="12"/></clipPath>
+              </defs>
+              <rect x="2" y="2" width="20" height="20" rx="4" fill="url(#sky)"
+                    stroke="#f5e0dc" stroke-width="1.5"/>
+              <g opacity="0.5">
+                <circle cx="9" cy="12" r="5" fill="#f38ba8"/>
+                <circle cx="15" cy="12" r="5" fill="#f38ba8"/>
+              </g>
+              <g clip-path="url(#half)">
+                <path d="M4 20 L12 6 L20 20 Z" fill="#a6e3a1"/>
+              </g>
+            </svg>"##
+            .to_string(),
+    )
+    .expect("the icon is valid");
+    assert!(icon.warnings.is_empty(), "{:?}", icon.warnings);
+
+    let place = |scale: f64, dx: f64, dy: f64| Transform {
+        xx: scale,
+        yx: 0.0,
+        xy: 0.0,
+        yy: scale,
+        dx,
+        dy,
+    };
+    let mut scene = vec![SceneCommand::Fill {
+        shape: Shape::Rect {
+            x0: 100.0,
+            y0: 100.0,
+            x1: 190.0,
+            y1: 190.0,
+        },
+        brush: color(69, 71, 90).into(),
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    }];
+    for placement in [place(3.0, 10.0, 10.0), place(3.5, 103.0, 103.0)] {
+        scene.extend(icon.commands.iter().cloned().map(|mut command| {
+            match &mut command {
+                SceneCommand::Fill { transform, .. }
+                | SceneCommand::Stroke { transform, .. }
+                | SceneCommand::PushLayer { transform, .. } => {
+                    *transform = concat(placement, *transform);
+                }
+                _ => {}
+            }
+            command
+        }));
+    }
+    scene
+}
+
+/// `a` applied after `b`.
+/// Halfway through `tests/lottie/shapes.json`: a rotating gradient ellipse
+/// with a mask cut out of it, a star being traced with trim paths and a
+/// precomposition moving along a parent null, on a solid layer.
+fn lottie_scene() -> Vec<SceneCommand> {
+    let info = load_lottie(include_str!("lottie/shapes.json").to_string())
+        .expect("the animation is valid");
+    assert!(info.warnings.is_empty(), "{:?}", info.warnings);
+    lottie_frame(info.animation, 1.0).expect("the animation is loaded")
+}
+
+fn concat(a: Transform, b: Transform) -> Transform {
+    Transform {
+        xx: a.xx * b.xx + a.xy * b.yx,
+        yx: a.yx * b.xx + a.yy * b.yx,
+        xy: a.xx * b.xy + a.xy * b.yy,
+        yy: a.yx * b.xy + a.yy * b.yy,
+        dx: a.xx * b.dx + a.xy * b.dy + a.dx,
+        dy: a.yx * b.dx + a.yy * b.dy + a.dy,
+    }
+}
+
+#[test]
+fn demo_scene_matches_golden() {
+    assert_golden("demo_scene", &render(demo_scene(), 640, 600));
+}
+
+#[test]
+fn fill_rules_match_golden() {
+    assert_golden("fill_rules", &render(fill_rules_scene(), 200, 100));
+}
+
+#[test]
+fn strokes_match_golden() {
+    assert_golden("strokes", &render(strokes_scene(), 200, 200));
+}
+
+#[test]
+fn gradients_match_golden() {
+    assert_golden("gradients", &render(gradients_scene(), 200, 200));
+}
+
+#[test]
+fn images_match_golden() {
+    let image = upload_checkerboard();
+    assert_golden("images", &render(images_scene(image), 200, 200));
+}
+
+#[test]
+fn text_matches_golden() {
+    assert_golden("text", &render(text_scene(), 200, 200));
+}
+
+#[test]
+fn layers_match_golden() {
+    assert_golden("layers", &render(layers_scene(), 200, 200));
+}
+
+#[test]
+fn svg_matches_golden() {
+    assert_golden("svg", &render(svg_scene(), 200, 200));
+}
+
+#[test]
+fn lottie_matches_golden() {
+    assert_golden("lottie", &render(lottie_scene(), 200, 200));
+}
+
+#[test]
+fn area_antialiasing_matches_golden() {
+    let options = RenderOptions {
+        antialiasing: Antialiasing::Area,
+        ..Default::default()
+    };
+    let frame = render_with_options(strokes_scene(), 200, 200, options);
+    assert_golden("strokes_area", &frame);
+}
+
+/// Frames queued back to back are read back while the next one renders, and
+/// the size changes between them. None of that may mix up their pixels.
+#[test]
+fn pipelined_frames_match_goldens() {
+    let frames = thread::scope(|scope| {
+        let handles = [
+            scope.spawn(|| render(fill_rules_scene(), 200, 100)),
+            scope.spawn(|| render(strokes_scene(), 200, 200)),
+            scope.spawn(|| render(fill_rules_scene(), 200, 100)),
+            scope.spawn(|| render(strokes_scene(), 200, 200)),
+        ];
+        handles.map(|handle| handle.join().unwrap())
+    });
+    for frame in &frames {
+        let name = match frame.height {
+            100 => "fill_rules",
+            _ => "strokes",
+        };
+        assert_golden(name, frame);
+    }
+}
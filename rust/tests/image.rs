@@ -0,0 +1,138 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::image::release_image;
+use rust_lib_flutter_vello::api::image::upload_image;
+use rust_lib_flutter_vello::api::image::upload_rgba_image;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::ImageQuality;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::Frame;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+
+mod common;
+
+const SIZE: u32 = 8;
+
+/// A 4x4 image whose pixels all differ.
+fn pattern() -> Vec<u8> {
+    (0..16u8)
+        .flat_map(|i| [i * 16, 255 - i * 16, (i % 4) * 80, 255])
+        .collect()
+}
+
+/// Draws `image` unscaled at (2, 3) on a transparent background.
+fn draw(id: &str, image: u64) -> Result<Frame, RendererError> {
+    let scene = vec![SceneCommand::Image {
+        image,
+        transform: Transform {
+            xx: 1.0,
+            yx: 0.0,
+            xy: 0.0,
+            yy: 1.0,
+            dx: 2.0,
+            dy: 3.0,
+        },
+        opacity: 1.0,
+        quality: ImageQuality::Medium,
+    }];
+    let options = RenderOptions {
+        background: Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0,
+        },
+        ..Default::default()
+    };
+    render_scene(id.to_string(), scene, SIZE, SIZE, options)
+        .block_on()
+        .map(|frame| frame.expect("Fifo renderers don't skip frames"))
+}
+
+/// The 4x4 block at (2, 3) in `frame`.
+fn drawn_pixels(frame: &Frame) -> Vec<u8> {
+    let row = 4 * SIZE as usize;
+    (3..7)
+        .flat_map(|y| frame.data[y * row + 8..y * row + 24].to_vec())
+        .collect()
+}
+
+#[test]
+fn rgba_images_draw_pixel_for_pixel() {
+    let id = common::create_cpu_renderer("image-rgba");
+    let image = upload_rgba_image(id.clone(), pattern(), 4, 4)
+        .block_on()
+        .unwrap();
+
+    let frame = draw(&id, image).unwrap();
+    assert_eq!(drawn_pixels(&frame), pattern());
+    // Nothing is drawn outside the image.
+    assert_eq!(&frame.data[..4], &[0, 0, 0, 0]);
+}
+
+#[test]
+fn encoded_images_are_decoded() {
+    let id = common::create_cpu_renderer("image-encoded");
+
+    // RGB without alpha, which is filled in as opaque.
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, 4, 4);
+    encoder.set_color(png::ColorType::Rgb);
+    let rgb: Vec<u8> = pattern()
+        .chunks(4)
+        .flat_map(|pixel| pixel[..3].to_vec())
+        .collect();
+    encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(&rgb)
+        .unwrap();
+    let image = upload_image(id.clone(), png).block_on().unwrap();
+    assert_eq!(drawn_pixels(&draw(&id, image).unwrap()), pattern());
+
+    // JPEG is lossy, so only check that a flat color survives.
+    let mut jpeg = Vec::new();
+    let color = [200, 120, 40];
+    let flat: Vec<u8> = color.repeat(16);
+    jpeg_encoder::Encoder::new(&mut jpeg, 100)
+        .encode(&flat, 4, 4, jpeg_encoder::ColorType::Rgb)
+        .unwrap();
+    let image = upload_image(id.clone(), jpeg).block_on().unwrap();
+    let pixels = drawn_pixels(&draw(&id, image).unwrap());
+    for pixel in pixels.chunks(4) {
+        for (actual, expected) in pixel.iter().zip(color.iter().chain([&255])) {
+            assert!(actual.abs_diff(*expected) <= 2, "{pixel:?} isn't {color:?}");
+        }
+    }
+}
+
+#[test]
+fn invalid_and_released_images_are_errors() {
+    let id = common::create_cpu_renderer("image-errors");
+    assert!(matches!(
+        upload_image(id.clone(), b"GIF89a".to_vec()).block_on(),
+        Err(RendererError::Decoding(_))
+    ));
+    assert!(matches!(
+        upload_rgba_image(id.clone(), vec![0; 15], 2, 2).block_on(),
+        Err(RendererError::Decoding(_))
+    ));
+
+    let image = upload_rgba_image(id.clone(), pattern(), 4, 4)
+        .block_on()
+        .unwrap();
+    release_image(id.clone(), image).block_on().unwrap();
+    assert_eq!(
+        draw(&id, image).unwrap_err(),
+        RendererError::ImageNotFound(image)
+    );
+    assert_eq!(
+        release_image(id.clone(), image).block_on(),
+        Err(RendererError::ImageNotFound(image))
+    );
+    // Handles aren't reused.
+    let next = upload_rgba_image(id, pattern(), 4, 4).block_on().unwrap();
+    assert_ne!(next, image);
+}
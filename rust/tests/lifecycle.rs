@@ -0,0 +1,46 @@
+//! Kept apart from the other tests, which start render threads of their own
+//! that would throw off the thread count below.
+
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::scene::demo_scene;
+use rust_lib_flutter_vello::api::simple::destroy_renderer;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use std::fs;
+
+mod common;
+
+/// Number of live render threads in this process. Thread names are cut to
+/// 15 bytes, which leaves just the common prefix.
+fn render_threads() -> usize {
+    fs::read_dir("/proc/self/task")
+        .unwrap()
+        .filter_map(|task| fs::read_to_string(task.unwrap().path().join("comm")).ok())
+        .filter(|name| name.trim_end() == "vello-renderer-")
+        .count()
+}
+
+#[test]
+fn destroyed_renderers_are_gone() {
+    let id = common::create_cpu_renderer("destroyed");
+    let render = || render_scene(id.clone(), demo_scene(), 64, 64, RenderOptions::default());
+    assert!(matches!(render().block_on(), Ok(Some(_))));
+    if cfg!(target_os = "linux") {
+        assert_eq!(render_threads(), 1);
+    }
+
+    destroy_renderer(id.clone()).unwrap();
+    // The render thread has been joined by the time `destroy_renderer`
+    // returns.
+    if cfg!(target_os = "linux") {
+        assert_eq!(render_threads(), 0);
+    }
+    let not_found = Err(RendererError::RendererNotFound(id.clone()));
+    assert_eq!(render().block_on().map(|_| ()), not_found);
+    assert_eq!(destroy_renderer(id.clone()), not_found);
+
+    // The id can be used again.
+    common::create_cpu_renderer(&id);
+    assert!(matches!(render().block_on(), Ok(Some(_))));
+}
@@ -0,0 +1,273 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::lottie::load_lottie;
+use rust_lib_flutter_vello::api::lottie::lottie_frame;
+use rust_lib_flutter_vello::api::lottie::lottie_info;
+use rust_lib_flutter_vello::api::lottie::release_lottie;
+use rust_lib_flutter_vello::api::lottie::render_lottie;
+use rust_lib_flutter_vello::api::scene::Compose;
+use rust_lib_flutter_vello::api::scene::PathElement;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+
+mod common;
+
+/// A 100 x 100, 2 second animation with the given layers.
+fn animation(layers: &str) -> String {
+    format!(
+        r#"{{"v": "5.7.4", "fr": 30, "ip": 0, "op": 60, "w": 100, "h": 100, "layers": [{layers}]}}"#
+    )
+}
+
+/// A shape layer with the given shapes and transform properties.
+fn shape_layer(shapes: &str, transform: &str) -> String {
+    format!(
+        r#"{{"ty": 4, "ind": 1, "ip": 0, "op": 60, "st": 0, "ks": {{{transform}}}, "shapes": [{shapes}]}}"#
+    )
+}
+
+const SQUARE: &str = r#"
+    {"ty": "rc", "p": {"a": 0, "k": [0, 0]}, "s": {"a": 0, "k": [10, 10]}},
+    {"ty": "fl", "c": {"a": 0, "k": [1, 0, 0, 1]}, "o": {"a": 0, "k": 100}}"#;
+
+fn load(json: &str) -> u64 {
+    let info = load_lottie(json.to_string()).expect("the animation is valid");
+    assert!(info.warnings.is_empty(), "{:?}", info.warnings);
+    info.animation
+}
+
+#[test]
+fn info_is_read_from_the_composition() {
+    let info = load_lottie(animation("")).expect("the animation is valid");
+
+    assert_eq!(info.width, 100.0);
+    assert_eq!(info.height, 100.0);
+    assert_eq!(info.frame_rate, 30.0);
+    assert_eq!(info.duration, 2.0);
+    assert_eq!(lottie_info(info.animation), Ok(info.clone()));
+    release_lottie(info.animation).unwrap();
+    assert_eq!(
+        lottie_frame(info.animation, 0.0),
+        Err(RendererError::LottieNotFound(info.animation))
+    );
+}
+
+#[test]
+fn keyframes_are_eased() {
+    let layer = |easing: &str| {
+        shape_layer(
+            SQUARE,
+            &format!(
+                r#""p": {{"a": 1, "k": [
+                    {{"t": 0, "s": [0, 0] {easing}}},
+                    {{"t": 60, "s": [100, 0]}}
+                ]}}"#
+            ),
+        )
+    };
+    let x_at = |animation: u64, time: f64| {
+        let commands = lottie_frame(animation, time).unwrap();
+        let [SceneCommand::Fill { transform, .. }] = &commands[..] else {
+            panic!("unexpected commands: {commands:?}");
+        };
+        transform.dx
+    };
+
+    let linear = load(&animation(&layer("")));
+    assert!((x_at(linear, 0.5) - 25.0).abs() < 1e-9);
+    assert!((x_at(linear, 1.0) - 50.0).abs() < 1e-9);
+    // Times outside of the animation show its first or last frame.
+    assert_eq!(x_at(linear, -1.0), 0.0);
+    assert!((x_at(linear, 5.0) - 100.0 * 59.0 / 60.0).abs() < 1e-9);
+
+    let ease_in = load(&animation(&layer(
+        r#", "o": {"x": [0.42], "y": [0]}, "i": {"x": [1], "y": [1]}"#,
+    )));
+    assert!(x_at(ease_in, 1.0) < 40.0);
+    let hold = load(&animation(&layer(r#", "h": 1"#)));
+    assert_eq!(x_at(hold, 1.9), 0.0);
+}
+
+#[test]
+fn empty_keyframe_lists_are_the_default_value() {
+    let animation = load(&animation(&shape_layer(
+        SQUARE,
+        r#""p": {"a": 1, "k": []}"#,
+    )));
+
+    let commands = lottie_frame(animation, 0.5).unwrap();
+    let [SceneCommand::Fill { transform, .. }] = &commands[..] else {
+        panic!("unexpected commands: {commands:?}");
+    };
+    assert_eq!((transform.dx, transform.dy), (0.0, 0.0));
+}
+
+#[test]
+fn trim_paths_shorten_strokes() {
+    let shapes = |end: f64| {
+        format!(
+            r#"
+            {{"ty": "sh", "ks": {{"a": 0, "k": {{"c": false, "v": [[0, 0], [100, 0]],
+                "i": [[0, 0], [0, 0]], "o": [[0, 0], [0, 0]]}}}}}},
+            {{"ty": "tm", "s": {{"a": 0, "k": 25}}, "e": {{"a": 0, "k": {end}}},
+              "o": {{"a": 0, "k": 0}}, "m": 1}},
+            {{"ty": "st", "c": {{"a": 0, "k": [0, 0, 0]}}, "o": {{"a": 0, "k": 100}},
+              "w": {{"a": 0, "k": 2}}, "lc": 1, "lj": 1}}"#
+        )
+    };
+
+    let trimmed = load(&animation(&shape_layer(&shapes(75.0), "")));
+    let commands = lottie_frame(trimmed, 0.0).unwrap();
+    let [SceneCommand::Stroke {
+        shape: Shape::Path { elements },
+        ..
+    }] = &commands[..]
+    else {
+        panic!("unexpected commands: {commands:?}");
+    };
+    assert_eq!(
+        elements,
+        &[
+            PathElement::MoveTo { x: 25.0, y: 0.0 },
+            PathElement::LineTo { x: 75.0, y: 0.0 },
+        ]
+    );
+
+    // Trimmed down to nothing, there's nothing to stroke.
+    let empty = load(&animation(&shape_layer(&shapes(25.0), "")));
+    assert_eq!(lottie_frame(empty, 0.0), Ok(vec![]));
+}
+
+#[test]
+fn masks_and_opacity_become_layers() {
+    let mask = |mode: &str| {
+        format!(
+            r#"{{"mode": "{mode}", "o": {{"a": 0, "k": 100}}, "pt": {{"a": 0, "k": {{"c": true,
+                "v": [[0, 0], [5, 0], [5, 5]], "i": [[0, 0], [0, 0], [0, 0]],
+                "o": [[0, 0], [0, 0], [0, 0]]}}}}}}"#
+        )
+    };
+    let layer = |masks: &[String], opacity: f64| {
+        let layer = shape_layer(SQUARE, &format!(r#""o": {{"a": 0, "k": {opacity}}}"#));
+        format!(
+            r#"{}, "masksProperties": [{}]}}"#,
+            layer.strip_suffix('}').unwrap(),
+            masks.join(",")
+        )
+    };
+    let kinds = |layer: String| -> Vec<String> {
+        lottie_frame(load(&animation(&layer)), 0.0)
+            .unwrap()
+            .iter()
+            .map(|command| match command {
+                SceneCommand::PushLayer { blend, .. } => format!("push {:?}", blend.compose),
+                SceneCommand::PopLayer => "pop".to_string(),
+                _ => "draw".to_string(),
+            })
+            .collect()
+    };
+
+    assert_eq!(kinds(layer(&[], 100.0)), ["draw"]);
+    assert_eq!(kinds(layer(&[], 50.0)), ["push SrcOver", "draw", "pop"]);
+    // A single added mask is a clip.
+    assert_eq!(
+        kinds(layer(&[mask("a")], 100.0)),
+        ["push SrcOver", "draw", "pop"]
+    );
+    let subtract = format!("push {:?}", Compose::DestOut);
+    let keep = format!("push {:?}", Compose::DestIn);
+    assert_eq!(
+        kinds(layer(&[mask("a"), mask("s")], 100.0)),
+        [
+            "push SrcOver",
+            "draw",
+            &keep,
+            "draw",
+            &subtract,
+            "draw",
+            "pop",
+            "pop",
+            "pop",
+        ]
+    );
+}
+
+#[test]
+fn unsupported_features_are_warnings() {
+    let text_layer = r#"{"ty": 5, "ind": 2, "ip": 0, "op": 60, "ks": {}}"#;
+    let shapes = format!(r#"{SQUARE}, {{"ty": "rp"}}, {{"ty": "mm"}}"#);
+    let info = load_lottie(animation(&format!(
+        "{}, {text_layer}",
+        shape_layer(&shapes, r#""r": {"a": 0, "k": 0, "x": "time * 10"}"#)
+    )))
+    .expect("the animation is valid");
+
+    for feature in ["expressions", "text layers", "repeaters", "merge paths"] {
+        assert!(
+            info.warnings.iter().any(|w| w.starts_with(feature)),
+            "no warning about {feature} in {:?}",
+            info.warnings
+        );
+    }
+    // The rest of the layer is still drawn.
+    assert_eq!(lottie_frame(info.animation, 0.0).unwrap().len(), 1);
+}
+
+#[test]
+fn invalid_animations_are_errors() {
+    for json in [
+        "not json".to_string(),
+        r#"{"fr": 30}"#.to_string(),
+        animation("").replace(r#""fr": 30"#, r#""fr": 0"#),
+        animation(r#"{"ty": 4, "ip": 0}"#),
+    ] {
+        assert!(
+            matches!(
+                load_lottie(json.clone()),
+                Err(RendererError::InvalidLottie(_))
+            ),
+            "{json} was loaded"
+        );
+    }
+
+    let recursive = r#"{"fr": 30, "ip": 0, "op": 60, "w": 100, "h": 100,
+        "assets": [{"id": "loop", "layers": [
+            {"ty": 0, "refId": "loop", "ip": 0, "op": 60, "ks": {}}
+        ]}],
+        "layers": [{"ty": 0, "refId": "loop", "ip": 0, "op": 60, "ks": {}}]}"#;
+    assert!(matches!(
+        load_lottie(recursive.to_string()),
+        Err(RendererError::InvalidLottie(_))
+    ));
+}
+
+#[test]
+fn frames_render_on_the_render_thread() {
+    common::create_cpu_renderer("lottie");
+    // A square covering the center of the composition.
+    let animation = load(&animation(&shape_layer(
+        SQUARE,
+        r#""p": {"a": 0, "k": [50, 50]}, "s": {"a": 0, "k": [500, 500]}"#,
+    )));
+
+    // Rendered twice as wide as the composition, so it's centered.
+    let frame = render_lottie(
+        "lottie".to_string(),
+        animation,
+        0.0,
+        40,
+        20,
+        RenderOptions::default(),
+    )
+    .block_on()
+    .expect("rendering succeeds")
+    .expect("Fifo renderers don't skip frames");
+
+    let pixel = |x: u32, y: u32| {
+        let i = ((y * frame.width + x) * 4) as usize;
+        frame.data[i..i + 4].to_vec()
+    };
+    assert_eq!(pixel(20, 10), [255, 0, 0, 255]);
+    assert_eq!(pixel(2, 10), [0, 0, 0, 255]);
+}
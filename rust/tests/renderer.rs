@@ -0,0 +1,447 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::scene::demo_scene;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::BlendMode;
+use rust_lib_flutter_vello::api::scene::Brush;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::ColorStop;
+use rust_lib_flutter_vello::api::scene::Extend;
+use rust_lib_flutter_vello::api::scene::FillRule;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::simple::create_renderer;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::AlphaMode;
+use rust_lib_flutter_vello::api::simple::Antialiasing;
+use rust_lib_flutter_vello::api::simple::AntialiasingSupport;
+use rust_lib_flutter_vello::api::simple::ChannelOrder;
+use rust_lib_flutter_vello::api::simple::Frame;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::simple::RenderQueuePolicy;
+use rust_lib_flutter_vello::api::simple::RendererBackend;
+use rust_lib_flutter_vello::api::simple::RendererConfig;
+use std::future::Future;
+use std::task::Context;
+use std::task::Waker;
+use std::thread;
+use std::time::Duration;
+
+mod common;
+
+#[test]
+fn unsupported_antialiasing_is_an_error() {
+    let id = "area-only".to_string();
+    let config = RendererConfig {
+        antialiasing_support: AntialiasingSupport {
+            area: true,
+            msaa8: false,
+            msaa16: false,
+        },
+        ..common::cpu_config()
+    };
+    create_renderer(id.clone(), config).unwrap();
+    let render = |antialiasing| {
+        let options = RenderOptions {
+            antialiasing,
+            ..Default::default()
+        };
+        render_scene(id.clone(), demo_scene(), 64, 64, options).block_on()
+    };
+
+    assert!(matches!(render(Antialiasing::Area), Ok(Some(_))));
+    assert_eq!(
+        render(Antialiasing::Msaa16).unwrap_err(),
+        RendererError::UnsupportedAntialiasing(Antialiasing::Msaa16)
+    );
+    // The failed frame doesn't take the renderer down with it.
+    assert!(matches!(render(Antialiasing::Area), Ok(Some(_))));
+}
+
+/// A circle filled with red fading to black from its center to its rim.
+fn radial_gradient_scene() -> Vec<SceneCommand> {
+    let stop = |offset, r| ColorStop {
+        offset,
+        color: Color {
+            r,
+            g: 0,
+            b: 0,
+            a: 255,
+        },
+    };
+    vec![SceneCommand::Fill {
+        shape: Shape::Circle {
+            cx: 32.0,
+            cy: 32.0,
+            radius: 32.0,
+        },
+        brush: Brush::RadialGradient {
+            x0: 32.0,
+            y0: 32.0,
+            r0: 0.0,
+            x1: 32.0,
+            y1: 32.0,
+            r1: 32.0,
+            stops: vec![stop(0.0, 255), stop(1.0, 0)],
+            extend: Extend::Pad,
+        },
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    }]
+}
+
+#[test]
+fn radial_gradients_are_an_error_on_the_cpu_backend() {
+    let id = common::create_cpu_renderer("radial");
+    let result = render_scene(
+        id,
+        radial_gradient_scene(),
+        64,
+        64,
+        RenderOptions::default(),
+    )
+    .block_on();
+    assert!(matches!(result, Err(RendererError::Unsupported(_))));
+}
+
+#[test]
+fn radial_gradients_are_drawn_on_the_gpu_backend() {
+    let id = "radial-gpu".to_string();
+    let config = RendererConfig {
+        backend: RendererBackend::Gpu,
+        ..common::cpu_config()
+    };
+    match create_renderer(id.clone(), config) {
+        Ok(()) => {}
+        Err(RendererError::AdapterNotFound) => {
+            eprintln!("Skipped, no GPU adapter");
+            return;
+        }
+        Err(e) => panic!("{e}"),
+    }
+    let frame = render_scene(
+        id,
+        radial_gradient_scene(),
+        64,
+        64,
+        RenderOptions::default(),
+    )
+    .block_on()
+    .unwrap()
+    .unwrap();
+    let red = |x: usize, y: usize| frame.data[(y * 64 + x) * 4];
+
+    assert!(red(32, 32) > 240);
+    assert!(red(48, 32) > 100 && red(48, 32) < 160);
+    assert!(red(62, 32) < 40);
+}
+
+#[test]
+fn unbalanced_and_too_deep_layers_are_errors() {
+    let id = common::create_cpu_renderer("layers");
+    let push = || SceneCommand::PushLayer {
+        clip: Shape::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 8.0,
+            y1: 8.0,
+        },
+        blend: BlendMode::default(),
+        alpha: 1.0,
+        transform: identity_transform(),
+    };
+    let render = |scene| render_scene(id.clone(), scene, 8, 8, RenderOptions::default()).block_on();
+
+    assert!(matches!(
+        render(vec![SceneCommand::PopLayer]),
+        Err(RendererError::InvalidLayers(_))
+    ));
+    assert!(matches!(
+        render(vec![push(), push(), SceneCommand::PopLayer]),
+        Err(RendererError::InvalidLayers(_))
+    ));
+
+    let mut deepest = vec![push(); 4];
+    deepest.extend(vec![SceneCommand::PopLayer; 4]);
+    assert!(matches!(render(deepest.clone()), Ok(Some(_))));
+    // A sweep gradient needs two more layers of its own.
+    deepest.insert(
+        2,
+        SceneCommand::Fill {
+            shape: Shape::Circle {
+                cx: 4.0,
+                cy: 4.0,
+                radius: 4.0,
+            },
+            brush: Brush::SweepGradient {
+                cx: 4.0,
+                cy: 4.0,
+                start_angle: 0.0,
+                end_angle: 1.0,
+                stops: Vec::new(),
+                extend: Extend::Pad,
+            },
+            fill_rule: FillRule::NonZero,
+            transform: identity_transform(),
+            brush_transform: None,
+        },
+    );
+    assert!(matches!(render(deepest.clone()), Ok(Some(_))));
+    deepest.insert(2, push());
+    deepest.push(SceneCommand::PopLayer);
+    assert!(matches!(
+        render(deepest),
+        Err(RendererError::InvalidLayers(_))
+    ));
+}
+
+fn translucent_square() -> Vec<SceneCommand> {
+    vec![SceneCommand::Fill {
+        shape: Shape::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 8.0,
+            y1: 8.0,
+        },
+        brush: Color {
+            r: 255,
+            g: 64,
+            b: 0,
+            a: 128,
+        }
+        .into(),
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    }]
+}
+
+#[test]
+fn transparent_background_honours_alpha_mode() {
+    let id = common::create_cpu_renderer("alpha");
+    let render = |alpha_mode| {
+        let options = RenderOptions {
+            background: Color {
+                r: 0,
+                g: 0,
+                b: 0,
+                a: 0,
+            },
+            alpha_mode,
+            ..Default::default()
+        };
+        render_scene(id.clone(), translucent_square(), 16, 16, options)
+            .block_on()
+            .unwrap()
+            .unwrap()
+    };
+    let pixel = |frame: &Frame, x: usize, y: usize| {
+        let offset = (y * frame.width as usize + x) * 4;
+        <[u8; 4]>::try_from(&frame.data[offset..offset + 4]).unwrap()
+    };
+
+    // (4, 4) is inside the square, (12, 12) in the uncovered corner.
+    let straight = render(AlphaMode::Straight);
+    assert_eq!(pixel(&straight, 4, 4), [255, 64, 0, 128]);
+    assert_eq!(pixel(&straight, 12, 12), [0, 0, 0, 0]);
+
+    let premultiplied = render(AlphaMode::Premultiplied);
+    assert_eq!(pixel(&premultiplied, 4, 4), [128, 32, 0, 128]);
+    assert_eq!(pixel(&premultiplied, 12, 12), [0, 0, 0, 0]);
+}
+
+#[test]
+fn channel_order_and_alpha_mode_are_converted() {
+    let id = common::create_cpu_renderer("pixel-format");
+    // Odd sizes, so rows are padded and the last workgroups are partial.
+    let render = |alpha_mode, channel_order| {
+        let options = RenderOptions {
+            background: Color {
+                r: 0,
+                g: 0,
+                b: 255,
+                a: 64,
+            },
+            alpha_mode,
+            channel_order,
+            ..Default::default()
+        };
+        render_scene(id.clone(), translucent_square(), 13, 11, options)
+            .block_on()
+            .unwrap()
+            .unwrap()
+    };
+    let straight_rgba = render(AlphaMode::Straight, ChannelOrder::Rgba);
+
+    for (alpha_mode, channel_order) in [
+        (AlphaMode::Straight, ChannelOrder::Bgra),
+        (AlphaMode::Premultiplied, ChannelOrder::Rgba),
+        (AlphaMode::Premultiplied, ChannelOrder::Bgra),
+    ] {
+        let frame = render(alpha_mode, channel_order);
+        assert_eq!(frame.data.len(), 13 * 11 * 4);
+        for (actual, expected) in frame.data.chunks(4).zip(straight_rgba.data.chunks(4)) {
+            let mut expected = <[u8; 4]>::try_from(expected).unwrap();
+            if alpha_mode == AlphaMode::Premultiplied {
+                let alpha = expected[3] as u32;
+                for channel in &mut expected[..3] {
+                    *channel = ((*channel as u32 * alpha + 127) / 255) as u8;
+                }
+            }
+            if channel_order == ChannelOrder::Bgra {
+                expected.swap(0, 2);
+            }
+            assert_eq!(actual, expected, "{alpha_mode:?} {channel_order:?}");
+        }
+    }
+}
+
+#[test]
+fn empty_scene_renders_background() {
+    let id = common::create_cpu_renderer("empty");
+    let frame = render_scene(id, vec![], 4, 4, RenderOptions::default())
+        .block_on()
+        .unwrap()
+        .unwrap();
+    assert!(frame.data.chunks(4).all(|pixel| pixel == [0, 0, 0, 255]));
+}
+
+#[test]
+fn oversized_frames_are_errors() {
+    let id = common::create_cpu_renderer("oversized");
+    let render = |width, height| {
+        render_scene(
+            id.clone(),
+            demo_scene(),
+            width,
+            height,
+            RenderOptions::default(),
+        )
+        .block_on()
+    };
+
+    assert!(matches!(
+        render(9000, 10),
+        Err(RendererError::Unsupported(_))
+    ));
+    // The failed frame doesn't take the renderer down with it.
+    assert!(matches!(render(64, 64), Ok(Some(_))));
+}
+
+#[test]
+fn racing_creations_keep_one_renderer() {
+    let id = "racing".to_string();
+    let config = common::cpu_config();
+    let creations: Vec<_> = (0..2)
+        .map(|_| {
+            let id = id.clone();
+            thread::spawn(move || create_renderer(id, config))
+        })
+        .collect();
+    for creation in creations {
+        assert_eq!(creation.join().unwrap(), Ok(()));
+    }
+    assert!(matches!(
+        render_scene(id, vec![], 4, 4, RenderOptions::default()).block_on(),
+        Ok(Some(_))
+    ));
+}
+
+#[test]
+fn concurrent_requests_get_their_own_frames() {
+    let id = common::create_cpu_renderer("concurrent");
+
+    let requests: Vec<_> = (1..=8)
+        .map(|size| {
+            let id = id.clone();
+            thread::spawn(move || {
+                // A background per request tells the frames apart by color
+                // as well as by size.
+                let options = RenderOptions {
+                    background: Color {
+                        r: size as u8,
+                        g: 0,
+                        b: 0,
+                        a: 255,
+                    },
+                    ..Default::default()
+                };
+                let frames: Vec<Frame> = (0..4)
+                    .map(|_| {
+                        render_scene(id.clone(), vec![], size * 8, size, options)
+                            .block_on()
+                            .unwrap()
+                            .unwrap()
+                    })
+                    .collect();
+                (size, frames)
+            })
+        })
+        .collect();
+    for request in requests {
+        let (size, frames) = request.join().unwrap();
+        for frame in frames {
+            assert_eq!((frame.width, frame.height), (size * 8, size));
+            assert!(frame
+                .data
+                .chunks(4)
+                .all(|pixel| pixel == [size as u8, 0, 0, 255]));
+        }
+    }
+}
+
+#[test]
+fn latest_wins_skips_superseded_frames() {
+    let id = "latest-wins".to_string();
+    let config = RendererConfig {
+        queue_policy: RenderQueuePolicy::LatestWins,
+        ..common::cpu_config()
+    };
+    create_renderer(id.clone(), config).unwrap();
+
+    // Keep the render thread busy so that the burst below queues up behind
+    // this frame and is picked up as one batch.
+    let busy = {
+        let id = id.clone();
+        thread::spawn(move || {
+            render_scene(id, demo_scene(), 1024, 1024, RenderOptions::default()).block_on()
+        })
+    };
+    thread::sleep(Duration::from_millis(50));
+
+    // Polling a request once sends it to the render thread.
+    let mut burst: Vec<_> = (0..8)
+        .map(|_| {
+            Box::pin(render_scene(
+                id.clone(),
+                vec![],
+                4,
+                4,
+                RenderOptions::default(),
+            ))
+        })
+        .collect();
+    let mut context = Context::from_waker(Waker::noop());
+    for request in &mut burst {
+        assert!(request.as_mut().poll(&mut context).is_pending());
+    }
+    let results: Vec<_> = burst
+        .into_iter()
+        .map(|request| request.block_on().unwrap())
+        .collect();
+    assert!(matches!(busy.join().unwrap(), Ok(Some(_))));
+
+    assert!(results.last().unwrap().is_some());
+    assert!(results.iter().any(Option::is_none));
+    let mut skipped = 0;
+    for result in results {
+        match result {
+            Some(frame) => {
+                assert_eq!(frame.skipped_frames, skipped);
+                skipped = 0;
+            }
+            None => skipped += 1,
+        }
+    }
+}
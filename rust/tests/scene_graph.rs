@@ -0,0 +1,443 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::graph::hit_test;
+use rust_lib_flutter_vello::api::graph::render_graph;
+use rust_lib_flutter_vello::api::graph::update_graph;
+use rust_lib_flutter_vello::api::graph::GraphUpdate;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::BlendMode;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::FillRule;
+use rust_lib_flutter_vello::api::scene::LineCap;
+use rust_lib_flutter_vello::api::scene::LineJoin;
+use rust_lib_flutter_vello::api::scene::PathElement;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::scene::StrokeStyle;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::Frame;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+
+mod common;
+
+const GROUP: u64 = 1;
+const RECT: u64 = 2;
+const CIRCLE: u64 = 3;
+
+fn translate(dx: f64, dy: f64) -> Transform {
+    Transform {
+        dx,
+        dy,
+        ..identity_transform()
+    }
+}
+
+fn rect(transform: Transform) -> SceneCommand {
+    SceneCommand::Fill {
+        shape: Shape::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 40.0,
+            y1: 30.0,
+        },
+        brush: Color {
+            r: 137,
+            g: 180,
+            b: 250,
+            a: 255,
+        }
+        .into(),
+        fill_rule: FillRule::NonZero,
+        transform,
+        brush_transform: None,
+    }
+}
+
+fn circle(transform: Transform) -> SceneCommand {
+    SceneCommand::Fill {
+        shape: Shape::Circle {
+            cx: 30.0,
+            cy: 30.0,
+            radius: 20.0,
+        },
+        brush: Color {
+            r: 243,
+            g: 139,
+            b: 168,
+            a: 255,
+        }
+        .into(),
+        fill_rule: FillRule::NonZero,
+        transform,
+        brush_transform: None,
+    }
+}
+
+struct Renderer {
+    id: String,
+}
+
+impl Renderer {
+    fn new(id: &str) -> Self {
+        common::create_cpu_renderer(id);
+        Self { id: id.to_string() }
+    }
+
+    fn update(&self, updates: Vec<GraphUpdate>) -> Result<(), RendererError> {
+        update_graph(self.id.clone(), updates).block_on()
+    }
+
+    fn render_graph(&self) -> Frame {
+        render_graph(self.id.clone(), 100, 80, RenderOptions::default())
+            .block_on()
+            .unwrap()
+            .unwrap()
+    }
+
+    fn hit_test(&self, x: f64, y: f64) -> Vec<u64> {
+        hit_test(self.id.clone(), x, y).block_on().unwrap()
+    }
+
+    fn render_scene(&self, scene: Vec<SceneCommand>) -> Frame {
+        render_scene(self.id.clone(), scene, 100, 80, RenderOptions::default())
+            .block_on()
+            .unwrap()
+            .unwrap()
+    }
+
+    /// Checks that the graph draws the same as the flat `expected` scene.
+    fn assert_graph_draws(&self, expected: Vec<SceneCommand>) {
+        let actual = self.render_graph();
+        let expected = self.render_scene(expected);
+        let differing = actual
+            .data
+            .iter()
+            .zip(&expected.data)
+            .filter(|(a, e)| a.abs_diff(**e) > 2)
+            .count();
+        assert_eq!(differing, 0, "graph and flat scene differ");
+    }
+}
+
+#[test]
+fn graph_draws_like_the_equivalent_flat_scene() {
+    let renderer = Renderer::new("graph");
+    let insert = |id, parent, commands, transform, z_index| GraphUpdate::Insert {
+        id,
+        parent,
+        commands,
+        transform,
+        z_index,
+        visible: true,
+    };
+    renderer
+        .update(vec![
+            insert(GROUP, None, vec![], translate(20.0, 10.0), 0),
+            insert(
+                RECT,
+                Some(GROUP),
+                vec![rect(identity_transform())],
+                translate(5.0, 5.0),
+                1,
+            ),
+            insert(
+                CIRCLE,
+                Some(GROUP),
+                vec![circle(identity_transform())],
+                identity_transform(),
+                0,
+            ),
+        ])
+        .unwrap();
+    // The circle has the lower z-index, so it is drawn first.
+    renderer.assert_graph_draws(vec![
+        circle(translate(20.0, 10.0)),
+        rect(translate(25.0, 15.0)),
+    ]);
+
+    renderer
+        .update(vec![GraphUpdate::SetTransform {
+            id: GROUP,
+            transform: translate(40.0, 20.0),
+        }])
+        .unwrap();
+    renderer.assert_graph_draws(vec![
+        circle(translate(40.0, 20.0)),
+        rect(translate(45.0, 25.0)),
+    ]);
+
+    renderer
+        .update(vec![GraphUpdate::SetZIndex {
+            id: RECT,
+            z_index: -1,
+        }])
+        .unwrap();
+    renderer.assert_graph_draws(vec![
+        rect(translate(45.0, 25.0)),
+        circle(translate(40.0, 20.0)),
+    ]);
+
+    renderer
+        .update(vec![GraphUpdate::SetVisible {
+            id: RECT,
+            visible: false,
+        }])
+        .unwrap();
+    renderer.assert_graph_draws(vec![circle(translate(40.0, 20.0))]);
+
+    renderer
+        .update(vec![
+            GraphUpdate::SetVisible {
+                id: RECT,
+                visible: true,
+            },
+            GraphUpdate::Reparent {
+                id: CIRCLE,
+                parent: None,
+            },
+        ])
+        .unwrap();
+    renderer.assert_graph_draws(vec![
+        rect(translate(45.0, 25.0)),
+        circle(identity_transform()),
+    ]);
+
+    renderer
+        .update(vec![GraphUpdate::SetCommands {
+            id: CIRCLE,
+            commands: vec![rect(identity_transform())],
+        }])
+        .unwrap();
+    renderer.assert_graph_draws(vec![
+        rect(translate(45.0, 25.0)),
+        rect(identity_transform()),
+    ]);
+
+    renderer
+        .update(vec![GraphUpdate::Remove { id: GROUP }])
+        .unwrap();
+    renderer.assert_graph_draws(vec![rect(identity_transform())]);
+    // The group's children went with it.
+    assert_eq!(
+        renderer.update(vec![GraphUpdate::Remove { id: RECT }]),
+        Err(RendererError::NodeNotFound(RECT))
+    );
+
+    renderer.update(vec![GraphUpdate::Clear]).unwrap();
+    renderer.assert_graph_draws(vec![]);
+}
+
+#[test]
+fn invalid_updates_are_errors() {
+    let renderer = Renderer::new("graph-errors");
+    let insert = |id, parent| GraphUpdate::Insert {
+        id,
+        parent,
+        commands: vec![],
+        transform: identity_transform(),
+        z_index: 0,
+        visible: true,
+    };
+    renderer
+        .update(vec![insert(GROUP, None), insert(RECT, Some(GROUP))])
+        .unwrap();
+
+    assert_eq!(
+        renderer.update(vec![insert(RECT, None)]),
+        Err(RendererError::NodeExists(RECT))
+    );
+    assert_eq!(
+        renderer.update(vec![insert(CIRCLE, Some(42))]),
+        Err(RendererError::NodeNotFound(42))
+    );
+    assert_eq!(
+        renderer.update(vec![GraphUpdate::Reparent {
+            id: GROUP,
+            parent: Some(RECT),
+        }]),
+        Err(RendererError::NodeCycle(GROUP))
+    );
+    assert!(matches!(
+        renderer.update(vec![GraphUpdate::SetCommands {
+            id: RECT,
+            commands: vec![SceneCommand::PopLayer],
+        }]),
+        Err(RendererError::InvalidLayers(_))
+    ));
+    // A failed insert leaves nothing behind.
+    renderer.update(vec![insert(CIRCLE, None)]).unwrap();
+}
+
+#[test]
+fn hit_test_finds_nodes_topmost_first() {
+    let renderer = Renderer::new("graph-hit-test");
+    let insert = |id, parent, commands, transform, z_index| GraphUpdate::Insert {
+        id,
+        parent,
+        commands,
+        transform,
+        z_index,
+        visible: true,
+    };
+    renderer
+        .update(vec![
+            insert(GROUP, None, vec![], translate(20.0, 10.0), 0),
+            insert(
+                RECT,
+                Some(GROUP),
+                vec![rect(identity_transform())],
+                translate(5.0, 5.0),
+                1,
+            ),
+            insert(
+                CIRCLE,
+                Some(GROUP),
+                vec![circle(identity_transform())],
+                identity_transform(),
+                0,
+            ),
+        ])
+        .unwrap();
+    // The rect covers (25, 15) to (65, 45), the circle is centered on (50, 40).
+    assert_eq!(renderer.hit_test(50.0, 40.0), vec![RECT, CIRCLE]);
+    assert_eq!(renderer.hit_test(30.0, 20.0), vec![RECT]);
+    assert_eq!(renderer.hit_test(50.0, 55.0), vec![CIRCLE]);
+    // The group has no commands of its own.
+    assert_eq!(renderer.hit_test(10.0, 5.0), Vec::<u64>::new());
+
+    renderer
+        .update(vec![GraphUpdate::SetZIndex {
+            id: RECT,
+            z_index: -1,
+        }])
+        .unwrap();
+    assert_eq!(renderer.hit_test(50.0, 40.0), vec![CIRCLE, RECT]);
+
+    renderer
+        .update(vec![GraphUpdate::SetVisible {
+            id: GROUP,
+            visible: false,
+        }])
+        .unwrap();
+    assert_eq!(renderer.hit_test(50.0, 40.0), Vec::<u64>::new());
+}
+
+#[test]
+fn hit_test_respects_fill_rules_and_stroke_widths() {
+    let renderer = Renderer::new("graph-hit-test-shapes");
+    // Two nested squares, wound the same way, so even-odd leaves a hole.
+    let square = |min: f64, max: f64| {
+        vec![
+            PathElement::MoveTo { x: min, y: min },
+            PathElement::LineTo { x: max, y: min },
+            PathElement::LineTo { x: max, y: max },
+            PathElement::LineTo { x: min, y: max },
+        ]
+    };
+    let ring = SceneCommand::Fill {
+        shape: Shape::Path {
+            elements: [square(0.0, 60.0), square(20.0, 40.0)].concat(),
+        },
+        brush: Color {
+            r: 137,
+            g: 180,
+            b: 250,
+            a: 255,
+        }
+        .into(),
+        fill_rule: FillRule::EvenOdd,
+        transform: identity_transform(),
+        brush_transform: None,
+    };
+    let line = SceneCommand::Stroke {
+        shape: Shape::Line {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 60.0,
+            y1: 0.0,
+        },
+        brush: Color {
+            r: 243,
+            g: 139,
+            b: 168,
+            a: 255,
+        }
+        .into(),
+        style: StrokeStyle {
+            width: 10.0,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            start_cap: LineCap::Butt,
+            end_cap: LineCap::Butt,
+            dash_pattern: vec![],
+            dash_offset: 0.0,
+        },
+        transform: translate(0.0, 80.0),
+        brush_transform: None,
+    };
+    renderer
+        .update(vec![
+            GraphUpdate::Insert {
+                id: RECT,
+                parent: None,
+                commands: vec![ring],
+                transform: identity_transform(),
+                z_index: 0,
+                visible: true,
+            },
+            GraphUpdate::Insert {
+                id: CIRCLE,
+                parent: None,
+                commands: vec![line],
+                transform: identity_transform(),
+                z_index: 0,
+                visible: true,
+            },
+        ])
+        .unwrap();
+
+    assert_eq!(renderer.hit_test(10.0, 30.0), vec![RECT]);
+    assert_eq!(renderer.hit_test(30.0, 30.0), Vec::<u64>::new());
+    // The stroke reaches half its width to either side of the line.
+    assert_eq!(renderer.hit_test(30.0, 84.0), vec![CIRCLE]);
+    assert_eq!(renderer.hit_test(30.0, 76.0), vec![CIRCLE]);
+    assert_eq!(renderer.hit_test(30.0, 86.0), Vec::<u64>::new());
+    assert_eq!(renderer.hit_test(65.0, 80.0), Vec::<u64>::new());
+}
+
+#[test]
+fn hit_test_respects_layer_clips() {
+    let renderer = Renderer::new("graph-hit-test-clips");
+    // The rect clipped to its left half, then the circle unclipped.
+    let clip = SceneCommand::PushLayer {
+        clip: Shape::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 20.0,
+            y1: 30.0,
+        },
+        blend: BlendMode::default(),
+        alpha: 1.0,
+        transform: identity_transform(),
+    };
+    renderer
+        .update(vec![GraphUpdate::Insert {
+            id: RECT,
+            parent: None,
+            commands: vec![
+                clip,
+                rect(identity_transform()),
+                SceneCommand::PopLayer,
+                circle(translate(100.0, 0.0)),
+            ],
+            transform: translate(10.0, 10.0),
+            z_index: 0,
+            visible: true,
+        }])
+        .unwrap();
+
+    assert_eq!(renderer.hit_test(25.0, 20.0), vec![RECT]);
+    assert_eq!(renderer.hit_test(35.0, 20.0), Vec::<u64>::new());
+    assert_eq!(renderer.hit_test(140.0, 40.0), vec![RECT]);
+}
@@ -0,0 +1,219 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::animation::play_timeline;
+use rust_lib_flutter_vello::api::animation::set_timeline;
+use rust_lib_flutter_vello::api::animation::AnimatedProperty;
+use rust_lib_flutter_vello::api::animation::Easing;
+use rust_lib_flutter_vello::api::animation::Keyframe;
+use rust_lib_flutter_vello::api::animation::KeyframeValue;
+use rust_lib_flutter_vello::api::animation::Timeline;
+use rust_lib_flutter_vello::api::animation::Track;
+use rust_lib_flutter_vello::api::graph::stream_graph;
+use rust_lib_flutter_vello::api::graph::update_graph;
+use rust_lib_flutter_vello::api::graph::GraphUpdate;
+use rust_lib_flutter_vello::api::scene::demo_scene;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::FillRule;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Shape;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::create_renderer;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::set_scene;
+use rust_lib_flutter_vello::api::simple::subscribe_frames_to;
+use rust_lib_flutter_vello::api::simple::Frame;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::simple::RenderQueuePolicy;
+use rust_lib_flutter_vello::api::simple::RendererConfig;
+use std::future::Future;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::task::Context;
+use std::task::Waker;
+use std::thread;
+use std::time::Duration;
+
+mod common;
+
+const RED: Color = Color {
+    r: 255,
+    g: 0,
+    b: 0,
+    a: 255,
+};
+
+/// Creates a renderer and subscribes to its frames.
+fn subscribed_renderer(id: &str) -> Receiver<Frame> {
+    common::create_cpu_renderer(id);
+    subscribe(id)
+}
+
+fn subscribe(id: &str) -> Receiver<Frame> {
+    let (frames_tx, frames_rx) = mpsc::channel();
+    subscribe_frames_to(id.to_string(), frames_tx)
+        .block_on()
+        .unwrap();
+    frames_rx
+}
+
+/// Receives frames until none arrives for a while.
+fn received_frames(frames: &Receiver<Frame>) -> Vec<Frame> {
+    frames
+        .recv_timeout(Duration::from_secs(10))
+        .into_iter()
+        .chain(std::iter::from_fn(|| {
+            frames.recv_timeout(Duration::from_millis(300)).ok()
+        }))
+        .collect()
+}
+
+fn assert_in_order(frames: &[Frame]) {
+    for pair in frames.windows(2) {
+        assert!(pair[0].frame_number < pair[1].frame_number);
+        assert!(pair[0].timestamp_micros < pair[1].timestamp_micros);
+    }
+}
+
+fn pixel(frame: &Frame, x: u32, y: u32) -> &[u8] {
+    let offset = ((y * frame.width + x) * 4) as usize;
+    &frame.data[offset..offset + 4]
+}
+
+#[test]
+fn scenes_are_streamed() {
+    let frames = subscribed_renderer("stream-scene");
+    for value in [10, 20, 30] {
+        set_scene("stream-scene".to_string(), vec![], 8, 8, gray(value))
+            .block_on()
+            .unwrap();
+    }
+
+    let frames = received_frames(&frames);
+    assert_eq!(frames.len(), 3);
+    assert_in_order(&frames);
+    for (frame, value) in frames.iter().zip([10, 20, 30]) {
+        assert_eq!(pixel(frame, 0, 0), [value, value, value, 255]);
+    }
+}
+
+fn gray(value: u8) -> RenderOptions {
+    RenderOptions {
+        background: Color {
+            r: value,
+            g: value,
+            b: value,
+            a: 255,
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn latest_wins_streams_scenes_set_before_renders() {
+    let id = "stream-latest-wins".to_string();
+    let config = RendererConfig {
+        queue_policy: RenderQueuePolicy::LatestWins,
+        ..common::cpu_config()
+    };
+    create_renderer(id.clone(), config).unwrap();
+    let frames = subscribe(&id);
+
+    // Keep the render thread busy so that the requests below queue up
+    // behind this frame and are picked up as one batch.
+    let busy = {
+        let id = id.clone();
+        thread::spawn(move || {
+            render_scene(id, demo_scene(), 1024, 1024, RenderOptions::default()).block_on()
+        })
+    };
+    thread::sleep(Duration::from_millis(50));
+
+    // Polling a request once sends it to the render thread.
+    let mut context = Context::from_waker(Waker::noop());
+    let mut scene = Box::pin(set_scene(id.clone(), vec![], 8, 8, gray(10)));
+    assert!(scene.as_mut().poll(&mut context).is_pending());
+    let mut render = Box::pin(render_scene(id, vec![], 8, 8, gray(20)));
+    assert!(render.as_mut().poll(&mut context).is_pending());
+    scene.block_on().unwrap();
+    let render = render.block_on().unwrap().unwrap();
+    assert!(matches!(busy.join().unwrap(), Ok(Some(_))));
+
+    // The render is of another kind, so it doesn't hold back the new scene.
+    assert_eq!(pixel(&render, 0, 0), [20, 20, 20, 255]);
+    let frames = received_frames(&frames);
+    assert_eq!(frames.len(), 1);
+    assert_eq!(pixel(&frames[0], 0, 0), [10, 10, 10, 255]);
+}
+
+#[test]
+fn graph_changes_and_timeline_ticks_are_streamed() {
+    let id = "stream-graph".to_string();
+    let frames = subscribed_renderer(&id);
+    stream_graph(id.clone(), 64, 16, RenderOptions::default())
+        .block_on()
+        .unwrap();
+    let square = SceneCommand::Fill {
+        shape: Shape::Rect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 8.0,
+            y1: 8.0,
+        },
+        brush: RED.into(),
+        fill_rule: FillRule::NonZero,
+        transform: identity_transform(),
+        brush_transform: None,
+    };
+    let insert = GraphUpdate::Insert {
+        id: 1,
+        parent: None,
+        commands: vec![square],
+        transform: identity_transform(),
+        z_index: 0,
+        visible: true,
+    };
+    update_graph(id.clone(), vec![insert]).block_on().unwrap();
+
+    // Streaming starts with the empty graph, then the update adds the square.
+    let changes = received_frames(&frames);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(pixel(&changes[0], 4, 4), [0, 0, 0, 255]);
+    assert_eq!(pixel(&changes[1], 4, 4), [255, 0, 0, 255]);
+
+    // Slides the square 40 pixels to the right in a fifth of a second.
+    let translate = |dx| {
+        KeyframeValue::Transform(Transform {
+            dx,
+            ..identity_transform()
+        })
+    };
+    let timeline = Timeline {
+        tracks: vec![Track {
+            node: 1,
+            property: AnimatedProperty::Transform,
+            keyframes: vec![
+                Keyframe {
+                    time: 0.0,
+                    value: translate(0.0),
+                    easing: Easing::Linear,
+                },
+                Keyframe {
+                    time: 0.2,
+                    value: translate(40.0),
+                    easing: Easing::Linear,
+                },
+            ],
+        }],
+        looping: false,
+    };
+    set_timeline(id.clone(), timeline).block_on().unwrap();
+    play_timeline(id.clone()).block_on().unwrap();
+
+    // Ticks stop once the timeline reaches its end.
+    let ticks = received_frames(&frames);
+    assert!(ticks.len() > 3, "{} frames", ticks.len());
+    assert_in_order(&[changes, ticks.clone()].concat());
+    let last = ticks.last().unwrap();
+    assert_eq!(pixel(last, 4, 4), [0, 0, 0, 255]);
+    assert_eq!(pixel(last, 44, 4), [255, 0, 0, 255]);
+}
@@ -0,0 +1,249 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::scene::Brush;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::Extend;
+use rust_lib_flutter_vello::api::scene::Mix;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::scene::Transform;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::svg::import_svg;
+use rust_lib_flutter_vello::api::svg::ViewBox;
+
+mod common;
+
+fn import(svg: &str) -> rust_lib_flutter_vello::api::svg::SvgScene {
+    import_svg(svg.to_string()).expect("the document is valid")
+}
+
+#[test]
+fn shapes_and_paints_are_converted() {
+    let scene = import(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="100"
+                 viewBox="10 20 100 50">
+              <defs>
+                <linearGradient id="fade" x1="0" y1="0" x2="10" y2="0"
+                                gradientUnits="userSpaceOnUse" spreadMethod="reflect">
+                  <stop offset="0" stop-color="#ff0000"/>
+                  <stop offset="1" stop-color="#0000ff" stop-opacity="0.5"/>
+                </linearGradient>
+              </defs>
+              <rect x="10" y="20" width="30" height="40" fill="#00ff00"
+                    stroke="black" stroke-width="2" fill-opacity="0.5"/>
+              <circle cx="50" cy="50" r="10" fill="url(#fade)"
+                      transform="translate(5 0)"/>
+            </svg>"##,
+    );
+
+    assert!(scene.warnings.is_empty(), "{:?}", scene.warnings);
+    assert_eq!(
+        scene.view_box,
+        ViewBox {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0,
+        }
+    );
+    let [SceneCommand::Fill {
+        brush: rect_fill,
+        transform: rect_transform,
+        ..
+    }, SceneCommand::Stroke { style, .. }, SceneCommand::Fill {
+        brush: circle_fill,
+        transform: circle_transform,
+        ..
+    }] = &scene.commands[..]
+    else {
+        panic!("unexpected commands: {:?}", scene.commands);
+    };
+
+    // Commands are in the view box's coordinates, not the document size's.
+    let identity = Transform {
+        xx: 1.0,
+        yx: 0.0,
+        xy: 0.0,
+        yy: 1.0,
+        dx: 0.0,
+        dy: 0.0,
+    };
+    assert_transform(*rect_transform, identity);
+    assert_transform(
+        *circle_transform,
+        Transform {
+            dx: 5.0,
+            ..identity
+        },
+    );
+    assert_eq!(
+        *rect_fill,
+        Brush::Solid {
+            color: Color {
+                r: 0,
+                g: 255,
+                b: 0,
+                a: 128,
+            },
+        }
+    );
+    assert_eq!(style.width, 2.0);
+    let Brush::LinearGradient {
+        x1, stops, extend, ..
+    } = circle_fill
+    else {
+        panic!("not a linear gradient: {circle_fill:?}");
+    };
+    assert_eq!(*x1, 10.0);
+    assert_eq!(*extend, Extend::Reflect);
+    assert_eq!(stops[1].color.a, 128);
+}
+
+#[test]
+fn groups_with_opacity_become_layers() {
+    let scene = import(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+             <clipPath id="clip"><rect width="50" height="50"/></clipPath>
+             <g opacity="0.5" style="mix-blend-mode: multiply">
+               <rect width="10" height="10"/>
+               <rect x="20" width="10" height="10"/>
+             </g>
+             <g clip-path="url(#clip)">
+               <circle cx="50" cy="50" r="40"/>
+             </g>
+           </svg>"#,
+    );
+
+    assert!(scene.warnings.is_empty(), "{:?}", scene.warnings);
+    let kinds: Vec<&str> = scene
+        .commands
+        .iter()
+        .map(|command| match command {
+            SceneCommand::PushLayer { .. } => "push",
+            SceneCommand::PopLayer => "pop",
+            _ => "draw",
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        ["push", "draw", "draw", "pop", "push", "draw", "pop"]
+    );
+    let SceneCommand::PushLayer { blend, alpha, .. } = &scene.commands[0] else {
+        unreachable!();
+    };
+    assert_eq!(blend.mix, Mix::Multiply);
+    assert_eq!(*alpha, 0.5);
+    let SceneCommand::PushLayer { blend, alpha, .. } = &scene.commands[4] else {
+        unreachable!();
+    };
+    assert_eq!(blend.mix, Mix::Clip);
+    assert_eq!(*alpha, 1.0);
+}
+
+#[test]
+fn focal_radii_become_start_radii() {
+    let scene = import(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+              <radialGradient id="user" gradientUnits="userSpaceOnUse"
+                              cx="50" cy="50" r="40" fr="10">
+                <stop offset="0" stop-color="#ff0000"/>
+                <stop offset="1" stop-color="#0000ff"/>
+              </radialGradient>
+              <radialGradient id="box" fr="20%">
+                <stop offset="0" stop-color="#ff0000"/>
+                <stop offset="1" stop-color="#0000ff"/>
+              </radialGradient>
+              <radialGradient id="inherited" href="#user" r="30"/>
+              <rect width="50" height="50" fill="url(#user)"/>
+              <rect x="50" width="50" height="50" fill="url(#box)"/>
+              <rect y="50" width="50" height="50" fill="url(#box)"/>
+              <rect x="50" y="50" width="50" height="50" fill="url(#inherited)"/>
+            </svg>"##,
+    );
+
+    assert!(scene.warnings.is_empty(), "{:?}", scene.warnings);
+    let start_radii: Vec<f64> = scene
+        .commands
+        .iter()
+        .map(|command| match command {
+            SceneCommand::Fill {
+                brush: Brush::RadialGradient { r0, .. },
+                ..
+            } => *r0,
+            _ => panic!("not a radial gradient fill: {command:?}"),
+        })
+        .collect();
+    // usvg copies the gradient used twice, the copy has the same radius.
+    assert_eq!(start_radii, [10.0, 0.2, 0.2, 10.0]);
+}
+
+#[test]
+fn clip_paths_unite_outlines_of_either_winding() {
+    let scene = import(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+             <clipPath id="clip">
+               <path d="M 0 0 H 60 V 60 H 0 Z"/>
+               <path d="M 40 40 V 100 H 100 V 40 Z"/>
+             </clipPath>
+             <rect width="100" height="100" fill="white" clip-path="url(#clip)"/>
+           </svg>"#,
+    );
+    let id = common::create_cpu_renderer("svg-clip");
+    let frame = render_scene(id, scene.commands, 100, 100, RenderOptions::default())
+        .block_on()
+        .unwrap()
+        .unwrap();
+    let pixel = |x: usize, y: usize| &frame.data[(y * 100 + x) * 4..][..4];
+
+    // Inside the first outline, inside both, and inside neither.
+    assert_eq!(pixel(20, 20), [255, 255, 255, 255]);
+    assert_eq!(pixel(50, 50), [255, 255, 255, 255]);
+    assert_eq!(pixel(20, 80), [0, 0, 0, 255]);
+}
+
+#[test]
+fn unsupported_features_are_warnings() {
+    let scene = import(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+             <filter id="blur"><feGaussianBlur stdDeviation="2"/></filter>
+             <pattern id="dots" width="4" height="4" patternUnits="userSpaceOnUse">
+               <circle cx="2" cy="2" r="1"/>
+             </pattern>
+             <rect width="10" height="10" filter="url(#blur)"/>
+             <rect width="10" height="10" fill="url(#dots)"/>
+             <text x="10" y="50">Hello</text>
+           </svg>"#,
+    );
+
+    for feature in ["text", "filters", "patterns"] {
+        assert!(
+            scene.warnings.iter().any(|w| w.starts_with(feature)),
+            "no warning about {feature} in {:?}",
+            scene.warnings
+        );
+    }
+    // The filtered rect is still drawn, without its filter.
+    assert!(scene
+        .commands
+        .iter()
+        .any(|command| matches!(command, SceneCommand::Fill { .. })));
+}
+
+#[test]
+fn invalid_documents_are_errors() {
+    assert!(matches!(
+        import_svg("<svg".to_string()),
+        Err(RendererError::InvalidSvg(_))
+    ));
+    assert!(matches!(
+        import_svg("not xml".to_string()),
+        Err(RendererError::InvalidSvg(_))
+    ));
+}
+
+fn assert_transform(actual: Transform, expected: Transform) {
+    let coefficients = |t: Transform| [t.xx, t.yx, t.xy, t.yy, t.dx, t.dy];
+    for (a, e) in coefficients(actual).iter().zip(coefficients(expected)) {
+        assert!((a - e).abs() < 1e-6, "{actual:?} isn't {expected:?}");
+    }
+}
@@ -0,0 +1,107 @@
+use pollster::FutureExt;
+use rust_lib_flutter_vello::api::error::RendererError;
+use rust_lib_flutter_vello::api::scene::identity_transform;
+use rust_lib_flutter_vello::api::scene::Color;
+use rust_lib_flutter_vello::api::scene::SceneCommand;
+use rust_lib_flutter_vello::api::simple::render_scene;
+use rust_lib_flutter_vello::api::simple::RenderOptions;
+use rust_lib_flutter_vello::api::text::layout_unshaped_text;
+use rust_lib_flutter_vello::api::text::register_font;
+use rust_lib_flutter_vello::api::text::release_font;
+
+mod common;
+
+const FONT: &[u8] = include_bytes!("fonts/DejaVuSansMono.ttf");
+
+#[test]
+fn layout_places_glyphs_by_advance_and_line() {
+    let font = register_font(FONT.to_vec()).unwrap();
+    let layout = layout_unshaped_text(font, "abc\nde".to_string(), 20.0).unwrap();
+
+    let ids: Vec<u32> = layout.glyphs.iter().map(|glyph| glyph.id).collect();
+    assert!(ids.iter().all(|&id| id != 0), "every letter has a glyph");
+    assert_eq!(
+        ids[0],
+        layout_unshaped_text(font, "a".to_string(), 20.0)
+            .unwrap()
+            .glyphs[0]
+            .id
+    );
+
+    // The font is monospaced.
+    let advance = layout.glyphs[1].x - layout.glyphs[0].x;
+    assert!(advance > 0.0);
+    assert_eq!(layout.glyphs[2].x, 2.0 * advance);
+    assert_eq!(layout.width, 3.0 * advance);
+
+    let first_line = layout.glyphs[0].y;
+    let second_line = layout.glyphs[3].y;
+    assert_eq!(first_line, layout.ascent);
+    assert_eq!(layout.glyphs[3].x, 0.0);
+    assert!(second_line >= first_line + layout.ascent + layout.descent);
+    assert_eq!(layout.height, second_line + layout.descent);
+
+    // Sizes scale the whole layout.
+    let doubled = layout_unshaped_text(font, "abc\nde".to_string(), 40.0).unwrap();
+    assert!((doubled.width - 2.0 * layout.width).abs() < 1e-3);
+}
+
+#[test]
+fn missing_characters_use_the_notdef_glyph() {
+    let font = register_font(FONT.to_vec()).unwrap();
+    let layout = layout_unshaped_text(font, "a\u{10FFFD}".to_string(), 20.0).unwrap();
+    assert_eq!(layout.glyphs[1].id, 0);
+}
+
+#[test]
+fn invalid_and_released_fonts_are_errors() {
+    assert!(matches!(
+        register_font(b"not a font".to_vec()),
+        Err(RendererError::InvalidFont(_))
+    ));
+
+    let font = register_font(FONT.to_vec()).unwrap();
+    release_font(font).unwrap();
+    assert_eq!(
+        layout_unshaped_text(font, "a".to_string(), 20.0),
+        Err(RendererError::FontNotFound(font))
+    );
+    assert_eq!(release_font(font), Err(RendererError::FontNotFound(font)));
+}
+
+#[test]
+fn glyph_ids_beyond_16_bits_are_errors() {
+    common::create_cpu_renderer("glyph-ids");
+    let font = register_font(FONT.to_vec()).unwrap();
+    let mut glyphs = layout_unshaped_text(font, "a".to_string(), 20.0)
+        .unwrap()
+        .glyphs;
+    // Would be glyph `a` again if it were cut to 16 bits.
+    glyphs[0].id += 1 << 16;
+    let invalid = glyphs[0].id;
+    let run = SceneCommand::GlyphRun {
+        font,
+        size: 20.0,
+        glyphs,
+        brush: Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255,
+        }
+        .into(),
+        transform: identity_transform(),
+    };
+    assert_eq!(
+        render_scene(
+            "glyph-ids".to_string(),
+            vec![run],
+            8,
+            8,
+            RenderOptions::default()
+        )
+        .block_on()
+        .map(|_| ()),
+        Err(RendererError::InvalidGlyph(invalid))
+    );
+}